@@ -41,6 +41,7 @@ class AuthNotifier extends Notifier<AuthState> {
       final config = await createWhitenoiseConfig(
         dataDir: dataDir,
         logsDir: logsDir,
+        proxyUrl: null,
       );
       await initializeWhitenoise(config: config);
 
@@ -10,6 +10,7 @@ import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/users.dart';
 import 'package:whitenoise/src/rust/api/utils.dart' as rust_utils;
 import 'package:whitenoise/utils/error_handling.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class CreateGroupNotifier extends StateNotifier<CreateGroupState> {
   final _logger = Logger('CreateGroupNotifier');
@@ -209,7 +210,7 @@ class CreateGroupNotifier extends StateNotifier<CreateGroupState> {
       try {
         final hasKeyPackage = await userHasKeyPackage(
           pubkey: userProfile.publicKey,
-          blockingDataSync: true,
+          syncPolicy: blockingRefreshSyncPolicy,
         );
 
         if (hasKeyPackage) {
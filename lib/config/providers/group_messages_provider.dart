@@ -9,6 +9,7 @@ import 'package:whitenoise/src/rust/api/messages.dart'
     show ChatMessage, fetchAggregatedMessagesForGroup;
 import 'package:whitenoise/utils/message_converter.dart';
 import 'package:whitenoise/utils/pubkey_utils.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class GroupMessagesState {
   final String groupId;
@@ -107,7 +108,7 @@ class GroupMessagesNotifier extends FamilyNotifier<GroupMessagesState, String> {
     // Use non-blocking fetch for message list performance
     final userFutures = pubkeys.map(
       (pubkey) => userProfileNotifier
-          .getUserProfile(pubkey, blockingDataSync: false)
+          .getUserProfile(pubkey, syncPolicy: backgroundRefreshSyncPolicy)
           .then((userProfile) => MapEntry(pubkey, userProfile)),
     );
     final usersProfileData = await Future.wait(userFutures);
@@ -19,6 +19,7 @@ import 'package:whitenoise/src/rust/api/utils.dart';
 import 'package:whitenoise/utils/error_handling.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 PubkeyFormatter _defaultPubkeyFormatter({String? pubkey}) => PubkeyFormatter(pubkey: pubkey);
 
@@ -455,7 +456,7 @@ class GroupsNotifier extends Notifier<GroupsState> {
       // Use non-blocking fetch for chat list performance
       final rustUser = await ref
           .read(userProfileProvider.notifier)
-          .getUser(pubkey, blockingDataSync: false);
+          .getUser(pubkey, syncPolicy: backgroundRefreshSyncPolicy);
       return domain_user.User.fromMetadata(rustUser.metadata, npub);
     } catch (e) {
       _logErrorSync('Failed to load metadata for pubkey $pubkey', e);
@@ -1360,6 +1361,7 @@ class GroupsNotifier extends Notifier<GroupsState> {
   Future<void> removeFromGroup({
     required String groupId,
     required List<String> membersNpubs,
+    String? reason,
   }) async {
     if (!_isAuthAvailable()) {
       return;
@@ -1381,6 +1383,7 @@ class GroupsNotifier extends Notifier<GroupsState> {
         pubkey: activePubkey,
         groupId: groupId,
         memberPubkeys: usersPubkeyHex,
+        reason: reason,
       );
 
       _logger.info(
@@ -1431,6 +1434,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
               lastMessageAt: timestamp,
               epoch: g.epoch,
               state: g.state,
+              disappearingMessagesSecs: g.disappearingMessagesSecs,
+              permissions: g.permissions,
             );
           }
           return group;
@@ -1468,6 +1473,8 @@ class GroupsNotifier extends Notifier<GroupsState> {
               lastMessageAt: g.lastMessageAt,
               epoch: g.epoch,
               state: g.state,
+              disappearingMessagesSecs: g.disappearingMessagesSecs,
+              permissions: g.permissions,
             );
           }
           return group;
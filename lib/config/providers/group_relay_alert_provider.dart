@@ -0,0 +1,13 @@
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:whitenoise/config/providers/active_pubkey_provider.dart';
+import 'package:whitenoise/src/rust/api/groups.dart';
+
+/// Alerts pushed by Rust when every relay of a group the active account administers has
+/// been unreachable for too long
+final groupRelayAlertProvider = StreamProvider<GroupRelayAlert>((ref) {
+  final activePubkey = ref.watch(activePubkeyProvider) ?? '';
+  if (activePubkey.isEmpty) {
+    return const Stream.empty();
+  }
+  return watchGroupRelayAlerts(accountPubkey: activePubkey);
+});
@@ -56,7 +56,20 @@ class MediaFileDownloadsNotifier extends Notifier<MediaFileDownloadsState> {
       required String originalFileHash,
     })?
     downloadMediaFn,
-  }) : _downloadMediaFn = downloadMediaFn ?? media_files_api.downloadChatMedia;
+  }) : _downloadMediaFn = downloadMediaFn ?? _downloadOnUserAction;
+
+  // Downloads are only started when the user opens the media, so they are never
+  // refused in low-bandwidth mode
+  static Future<MediaFile> _downloadOnUserAction({
+    required String accountPubkey,
+    required String groupId,
+    required String originalFileHash,
+  }) => media_files_api.downloadChatMedia(
+    accountPubkey: accountPubkey,
+    groupId: groupId,
+    originalFileHash: originalFileHash,
+    userInitiated: true,
+  );
 
   final Future<MediaFile> Function({
     required String accountPubkey,
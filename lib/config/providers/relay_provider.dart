@@ -74,6 +74,7 @@ class NormalRelaysNotifier extends Notifier<RelayState> {
       final relayUrls = await accountRelays(
         pubkey: activePubkey,
         relayType: nip65RelayType,
+        includeInfo: false,
       ).then((value) => value.map((relay) => relay.url).toList());
 
       // If no relays found, log this information
@@ -114,6 +115,13 @@ class NormalRelaysNotifier extends Notifier<RelayState> {
     }
   }
 
+  /// Returns the normalized URL, or throws when Rust rejects it for this relay list.
+  Future<String> validateRelay(String url) async {
+    final accountPubkey = ref.read(activePubkeyProvider) ?? '';
+    final relayType = await relayTypeNip65();
+    return validateRelayUrl(pubkey: accountPubkey, url: url, relayType: relayType);
+  }
+
   Future<void> addRelay(String url) async {
     try {
       final accountPubKey = ref.read(activePubkeyProvider) ?? '';
@@ -191,6 +199,7 @@ class InboxRelaysNotifier extends Notifier<RelayState> {
       final inboxRelayUrls = await accountRelays(
         pubkey: accountPubKey,
         relayType: inboxRelayType,
+        includeInfo: false,
       ).then((value) => value.map((relay) => relay.url).toList());
 
       _logger.info('InboxRelaysNotifier: Fetched ${inboxRelayUrls.length} relay URLs');
@@ -233,6 +242,13 @@ class InboxRelaysNotifier extends Notifier<RelayState> {
     }
   }
 
+  /// Returns the normalized URL, or throws when Rust rejects it for this relay list.
+  Future<String> validateRelay(String url) async {
+    final accountPubkey = ref.read(activePubkeyProvider) ?? '';
+    final relayType = await relayTypeInbox();
+    return validateRelayUrl(pubkey: accountPubkey, url: url, relayType: relayType);
+  }
+
   Future<void> addRelay(String url) async {
     try {
       final accountPubKey = ref.read(activePubkeyProvider) ?? '';
@@ -316,6 +332,7 @@ class KeyPackageRelaysNotifier extends Notifier<RelayState> {
       final relayUrls = await accountRelays(
         pubkey: activePubkey,
         relayType: keyPackageType,
+        includeInfo: false,
       ).then((value) => value.map((relay) => relay.url).toList());
 
       _logger.info('KeyPackageRelaysNotifier: Fetched ${relayUrls.length} relay URLs');
@@ -354,6 +371,13 @@ class KeyPackageRelaysNotifier extends Notifier<RelayState> {
     }
   }
 
+  /// Returns the normalized URL, or throws when Rust rejects it for this relay list.
+  Future<String> validateRelay(String url) async {
+    final accountPubkey = ref.read(activePubkeyProvider) ?? '';
+    final relayType = await relayTypeKeyPackage();
+    return validateRelayUrl(pubkey: accountPubkey, url: url, relayType: relayType);
+  }
+
   Future<void> addRelay(String url) async {
     try {
       final accountPubkey = ref.read(activePubkeyProvider) ?? '';
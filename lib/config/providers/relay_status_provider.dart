@@ -6,7 +6,7 @@ import 'package:whitenoise/config/providers/active_pubkey_provider.dart';
 import 'package:whitenoise/config/providers/auth_provider.dart';
 import 'package:whitenoise/models/relay_status.dart';
 import 'package:whitenoise/src/rust/api/accounts.dart';
-import 'package:whitenoise/src/rust/api/relays.dart';
+import 'package:whitenoise/src/rust/api/relays.dart' hide RelayStatus;
 
 // State for relay status management
 class RelayStatusState {
@@ -89,11 +89,12 @@ class RelayStatusNotifier extends Notifier<RelayStatusState> {
       final relayStatuses = await getAccountRelayStatuses(pubkey: activePubkey);
       _logger.info('RelayStatusNotifier: Fetched ${relayStatuses.length} relay statuses');
 
-      // Convert list of tuples to map
+      // Convert connection infos to map
       final statusMap = <String, RelayStatus>{};
-      for (final (url, status) in relayStatuses) {
-        statusMap[url] = RelayStatus.fromString(status);
-        _logger.info('RelayStatusNotifier: Relay $url has status: $status');
+      for (final connection in relayStatuses) {
+        final status = RelayStatus.fromString(connection.status.name);
+        statusMap[connection.url] = status;
+        _logger.info('RelayStatusNotifier: Relay ${connection.url} has status: $status');
       }
 
       // If no relay statuses found, log this information
@@ -144,16 +145,19 @@ class RelayStatusNotifier extends Notifier<RelayStatusState> {
           (await accountRelays(
             pubkey: accountPubKey,
             relayType: nip65Type,
+            includeInfo: false,
           )).map((r) => r.url).toList();
       final inboxUrls =
           (await accountRelays(
             pubkey: accountPubKey,
             relayType: inboxType,
+            includeInfo: false,
           )).map((r) => r.url).toList();
       final keyPackageUrls =
           (await accountRelays(
             pubkey: accountPubKey,
             relayType: keyPackageType,
+            includeInfo: false,
           )).map((r) => r.url).toList();
 
       // Check each relay type separately using URL strings
@@ -2,16 +2,17 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:whitenoise/domain/models/user_profile.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart' show FlutterMetadata;
 import 'package:whitenoise/src/rust/api/users.dart' as wn_users_api;
-import 'package:whitenoise/src/rust/api/users.dart' show User;
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy, User;
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class UserProfileNotifier extends Notifier<void> {
-  late final Future<User> Function({required String pubkey, required bool blockingDataSync})
+  late final Future<User> Function({required String pubkey, required SyncPolicy syncPolicy})
   _wnApiGetUser;
   late final UserProfile Function({required String pubkey, required FlutterMetadata metadata})
   _getUserProfileFromMetadata;
 
   UserProfileNotifier({
-    Future<User> Function({required String pubkey, required bool blockingDataSync})? wnApiGetUserFn,
+    Future<User> Function({required String pubkey, required SyncPolicy syncPolicy})? wnApiGetUserFn,
     UserProfile Function({required String pubkey, required FlutterMetadata metadata})?
     getUserProfileFromMetadataFn,
   }) {
@@ -22,14 +23,25 @@ class UserProfileNotifier extends Notifier<void> {
   @override
   void build() {}
 
-  Future<UserProfile> getUserProfile(String pubkey, {bool blockingDataSync = true}) async {
-    final user = await _wnApiGetUser(pubkey: pubkey, blockingDataSync: blockingDataSync);
+  /// Defaults to [backgroundRefreshSyncPolicy]; pass [blockingRefreshSyncPolicy] only for
+  /// lookups the user started.
+  Future<UserProfile> getUserProfile(
+    String pubkey, {
+    SyncPolicy? syncPolicy,
+  }) async {
+    final user = await _wnApiGetUser(
+      pubkey: pubkey,
+      syncPolicy: syncPolicy ?? backgroundRefreshSyncPolicy,
+    );
     final userProfile = _getUserProfileFromMetadata(pubkey: pubkey, metadata: user.metadata);
     return userProfile;
   }
 
-  Future<User> getUser(String pubkey, {bool blockingDataSync = true}) async =>
-      _wnApiGetUser(pubkey: pubkey, blockingDataSync: blockingDataSync);
+  Future<User> getUser(
+    String pubkey, {
+    SyncPolicy? syncPolicy,
+  }) async =>
+      _wnApiGetUser(pubkey: pubkey, syncPolicy: syncPolicy ?? backgroundRefreshSyncPolicy);
 }
 
 final userProfileProvider = NotifierProvider<UserProfileNotifier, void>(
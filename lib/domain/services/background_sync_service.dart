@@ -10,6 +10,7 @@ import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/users.dart';
 import 'package:whitenoise/src/rust/frb_generated.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 import 'package:workmanager/workmanager.dart';
 
 class BackgroundSyncService {
@@ -243,7 +244,7 @@ Future<int> _refreshMetadataForGroup({
     int refreshedCount = 0;
     for (final memberPubkey in memberPubkeys) {
       try {
-        await userMetadata(pubkey: memberPubkey, blockingDataSync: true);
+        await userMetadata(pubkey: memberPubkey, syncPolicy: backgroundRefreshSyncPolicy);
         refreshedCount++;
       } catch (e) {
         logger.warning('Refresh metadata for $memberPubkey: $e');
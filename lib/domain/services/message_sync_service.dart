@@ -10,6 +10,7 @@ import 'package:whitenoise/domain/services/notification_service.dart';
 import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy;
 import 'package:whitenoise/src/rust/api/welcomes.dart';
 
 /// Service responsible for message synchronization, filtering, and notifications.
@@ -118,7 +119,7 @@ class MessageSyncService {
     })?
     showNotificationFn,
     Future<int> Function({required String key})? getNotificationIdFn,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     if (!_validateNotificationParams(groupId: groupId, accountPubkey: accountPubkey)) {
@@ -220,7 +221,7 @@ class MessageSyncService {
     })?
     showNotificationFn,
     Future<int> Function({required String key})? getNotificationIdFn,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     final showNotificationFunc = showNotificationFn ?? NotificationService.showMessageNotification;
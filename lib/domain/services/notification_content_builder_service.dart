@@ -6,6 +6,7 @@ import 'package:whitenoise/src/rust/api/metadata.dart';
 import 'package:whitenoise/src/rust/api/users.dart';
 import 'package:whitenoise/src/rust/api/welcomes.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class NotificationContentBuilderService {
   static final _logger = Logger('NotificationContentBuilderService');
@@ -32,7 +33,7 @@ class NotificationContentBuilderService {
     Future<Group> Function({required String accountPubkey, required String groupId})? getGroupFn,
     Future<List<String>> Function({required String pubkey, required String groupId})?
     getGroupMembersFn,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     final groupDisplayName = await _getGroupDisplayName(
@@ -68,7 +69,7 @@ class NotificationContentBuilderService {
 
   Future<NotificationContent> buildMessageNotification({
     required ChatMessage message,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     final String senderName =
@@ -104,14 +105,14 @@ class NotificationContentBuilderService {
     required Welcome welcome,
     required String accountPubkey,
     required bool showReceiverAccountName,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     final bool isDM = welcome.groupName.isEmpty;
 
     final String welcomerName = await _getUserDisplayName(
       pubkey: welcome.welcomer,
-      blockingDataSync: true,
+      syncPolicy: backgroundRefreshSyncPolicy,
       getUserMetadataFn: getUserMetadataFn,
     );
 
@@ -187,7 +188,7 @@ class NotificationContentBuilderService {
     Future<Group> Function({required String accountPubkey, required String groupId})? getGroupFn,
     Future<List<String>> Function({required String pubkey, required String groupId})?
     getGroupMembersFn,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     try {
@@ -214,7 +215,7 @@ class NotificationContentBuilderService {
     String groupId, {
     Future<List<String>> Function({required String pubkey, required String groupId})?
     getGroupMembersFn,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     try {
@@ -239,15 +240,15 @@ class NotificationContentBuilderService {
 
   static Future<String> _getUserDisplayName({
     required String pubkey,
-    bool blockingDataSync = false,
-    Future<FlutterMetadata> Function({required String pubkey, bool blockingDataSync})?
+    SyncPolicy? syncPolicy,
+    Future<FlutterMetadata> Function({required String pubkey, SyncPolicy syncPolicy})?
     getUserMetadataFn,
   }) async {
     try {
       final getUserMetadataFunc = getUserMetadataFn ?? userMetadata;
       final metadata = await getUserMetadataFunc(
         pubkey: pubkey,
-        blockingDataSync: blockingDataSync,
+        syncPolicy: syncPolicy ?? backgroundRefreshSyncPolicy,
       );
       if (metadata.displayName?.isNotEmpty == true) {
         return metadata.displayName!;
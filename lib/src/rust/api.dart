@@ -6,6 +6,8 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import 'api/error.dart';
+import 'api/relays.dart';
+import 'api/welcomes.dart';
 import 'frb_generated.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
@@ -18,6 +20,7 @@ import 'frb_generated.dart';
 /// # Parameters
 /// * `data_dir` - Path string for data directory where app data will be stored
 /// * `logs_dir` - Path string for logs directory where log files will be written
+/// * `proxy_url` - Optional SOCKS5 proxy address, e.g. `socks5://127.0.0.1:9050` or `localhost:9050`
 ///
 /// # Returns
 /// A WhitenoiseConfig object ready for initialization
@@ -26,15 +29,18 @@ import 'frb_generated.dart';
 /// ```rust
 /// let config = create_whitenoise_config(
 ///     "/path/to/data".to_string(),
-///     "/path/to/logs".to_string()
+///     "/path/to/logs".to_string(),
+///     None
 /// );
 /// ```
 Future<WhitenoiseConfig> createWhitenoiseConfig({
   required String dataDir,
   required String logsDir,
+  String? proxyUrl,
 }) => RustLib.instance.api.crateApiCreateWhitenoiseConfig(
   dataDir: dataDir,
   logsDir: logsDir,
+  proxyUrl: proxyUrl,
 );
 
 Future<void> initializeWhitenoise({required WhitenoiseConfig config}) =>
@@ -47,6 +53,11 @@ Future<AppSettings> getAppSettings() => RustLib.instance.api.crateApiGetAppSetti
 Future<void> updateThemeMode({required ThemeMode themeMode}) =>
     RustLib.instance.api.crateApiUpdateThemeMode(themeMode: themeMode);
 
+Future<WelcomePolicy> getWelcomePolicy() => RustLib.instance.api.crateApiGetWelcomePolicy();
+
+Future<void> updateWelcomePolicy({required WelcomePolicy welcomePolicy}) =>
+    RustLib.instance.api.crateApiUpdateWelcomePolicy(welcomePolicy: welcomePolicy);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>>
 abstract class AppSettings implements RustOpaqueInterface {}
 
@@ -65,13 +76,17 @@ class WhitenoiseConfig {
   /// Path to the directory where log files will be written
   final String logsDir;
 
+  /// Optional SOCKS5 proxy (e.g. Tor) used from startup until changed in the app settings
+  final String? proxyUrl;
+
   const WhitenoiseConfig({
     required this.dataDir,
     required this.logsDir,
+    this.proxyUrl,
   });
 
   @override
-  int get hashCode => dataDir.hashCode ^ logsDir.hashCode;
+  int get hashCode => dataDir.hashCode ^ logsDir.hashCode ^ proxyUrl.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -79,5 +94,6 @@ class WhitenoiseConfig {
       other is WhitenoiseConfig &&
           runtimeType == other.runtimeType &&
           dataDir == other.dataDir &&
-          logsDir == other.logsDir;
+          logsDir == other.logsDir &&
+          proxyUrl == other.proxyUrl;
 }
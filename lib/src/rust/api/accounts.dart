@@ -11,7 +11,7 @@ import 'metadata.dart';
 import 'relays.dart';
 import 'users.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`, `from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `from`, `from`
 
 Future<List<Account>> getAccounts() => RustLib.instance.api.crateApiAccountsGetAccounts();
 
@@ -52,12 +52,18 @@ Future<String> uploadAccountProfilePicture({
   imageType: imageType,
 );
 
+/// Returns the account's relays of the given type.
+///
+/// With `include_info` set, each relay carries its (cached) NIP-11 document so the
+/// relay settings screen can warn about relays unsuitable for MLS messaging.
 Future<List<Relay>> accountRelays({
   required String pubkey,
   required RelayType relayType,
+  required bool includeInfo,
 }) => RustLib.instance.api.crateApiAccountsAccountRelays(
   pubkey: pubkey,
   relayType: relayType,
+  includeInfo: includeInfo,
 );
 
 Future<void> addAccountRelay({
@@ -70,6 +76,22 @@ Future<void> addAccountRelay({
   relayType: relayType,
 );
 
+/// Replaces the account's relay list of one type in a single step.
+///
+/// All URLs are validated up front, so an invalid entry leaves the list untouched.
+/// The database is updated in one transaction, one replaceable relay list event is
+/// published, and subscriptions are refreshed once. Nothing is published when the
+/// list is unchanged.
+Future<RelayListChange> setAccountRelays({
+  required String pubkey,
+  required RelayType relayType,
+  required List<String> urls,
+}) => RustLib.instance.api.crateApiAccountsSetAccountRelays(
+  pubkey: pubkey,
+  relayType: relayType,
+  urls: urls,
+);
+
 Future<void> removeAccountRelay({
   required String pubkey,
   required String url,
@@ -195,3 +217,25 @@ class FlutterEvent {
           tags == other.tags &&
           content == other.content;
 }
+
+/// Relays added and removed by [`set_account_relays`]
+class RelayListChange {
+  final List<String> added;
+  final List<String> removed;
+
+  const RelayListChange({
+    required this.added,
+    required this.removed,
+  });
+
+  @override
+  int get hashCode => added.hashCode ^ removed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayListChange &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          removed == other.removed;
+}
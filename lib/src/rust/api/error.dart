@@ -7,10 +7,11 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 import '../frb_generated.dart';
+import 'relays.dart';
 
 part 'error.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
 @freezed
 sealed class ApiError with _$ApiError implements FrbException {
@@ -38,6 +39,17 @@ sealed class ApiError with _$ApiError implements FrbException {
   const factory ApiError.nostrHex({
     required String message,
   }) = ApiError_NostrHex;
+
+  /// The group's permission settings do not allow the action
+  const factory ApiError.permissionDenied({
+    required String message,
+  }) = ApiError_PermissionDenied;
+
+  /// Relay URL rejected by validation, with the reason for Dart to branch on
+  const factory ApiError.invalidRelayUrl({
+    required InvalidRelayUrlReason reason,
+    required String message,
+  }) = ApiError_InvalidRelayUrl;
   const factory ApiError.other({
     required String message,
   }) = ApiError_Other;
@@ -27,6 +27,8 @@ mixin _$ApiError {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -38,6 +40,8 @@ mixin _$ApiError {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -49,6 +53,8 @@ mixin _$ApiError {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
@@ -61,6 +67,8 @@ mixin _$ApiError {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -72,6 +80,8 @@ mixin _$ApiError {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -83,6 +93,8 @@ mixin _$ApiError {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
@@ -210,6 +222,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return whitenoise(message);
@@ -225,6 +239,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return whitenoise?.call(message);
@@ -240,6 +256,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -259,6 +277,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return whitenoise(this);
@@ -274,6 +294,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return whitenoise?.call(this);
@@ -289,6 +311,8 @@ class _$ApiError_WhitenoiseImpl extends ApiError_Whitenoise {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -396,6 +420,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return invalidKey(message);
@@ -411,6 +437,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return invalidKey?.call(message);
@@ -426,6 +454,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -445,6 +475,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return invalidKey(this);
@@ -460,6 +492,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return invalidKey?.call(this);
@@ -475,6 +509,8 @@ class _$ApiError_InvalidKeyImpl extends ApiError_InvalidKey {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -582,6 +618,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return nostrUrl(message);
@@ -597,6 +635,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return nostrUrl?.call(message);
@@ -612,6 +652,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -631,6 +673,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return nostrUrl(this);
@@ -646,6 +690,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return nostrUrl?.call(this);
@@ -661,6 +707,8 @@ class _$ApiError_NostrUrlImpl extends ApiError_NostrUrl {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -768,6 +816,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return nostrTag(message);
@@ -783,6 +833,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return nostrTag?.call(message);
@@ -798,6 +850,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -817,6 +871,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return nostrTag(this);
@@ -832,6 +888,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return nostrTag?.call(this);
@@ -847,6 +905,8 @@ class _$ApiError_NostrTagImpl extends ApiError_NostrTag {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -954,6 +1014,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return nostrEvent(message);
@@ -969,6 +1031,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return nostrEvent?.call(message);
@@ -984,6 +1048,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -1003,6 +1069,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return nostrEvent(this);
@@ -1018,6 +1086,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return nostrEvent?.call(this);
@@ -1033,6 +1103,8 @@ class _$ApiError_NostrEventImpl extends ApiError_NostrEvent {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -1140,6 +1212,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return nostrParse(message);
@@ -1155,6 +1229,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return nostrParse?.call(message);
@@ -1170,6 +1246,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -1189,6 +1267,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return nostrParse(this);
@@ -1204,6 +1284,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return nostrParse?.call(this);
@@ -1219,6 +1301,8 @@ class _$ApiError_NostrParseImpl extends ApiError_NostrParse {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -1326,6 +1410,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return nostrHex(message);
@@ -1341,6 +1427,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return nostrHex?.call(message);
@@ -1356,6 +1444,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -1375,6 +1465,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return nostrHex(this);
@@ -1390,6 +1482,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return nostrHex?.call(this);
@@ -1405,6 +1499,8 @@ class _$ApiError_NostrHexImpl extends ApiError_NostrHex {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -1430,6 +1526,418 @@ abstract class ApiError_NostrHex extends ApiError {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$ApiError_PermissionDeniedImplCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory _$$ApiError_PermissionDeniedImplCopyWith(
+    _$ApiError_PermissionDeniedImpl value,
+    $Res Function(_$ApiError_PermissionDeniedImpl) then,
+  ) = __$$ApiError_PermissionDeniedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$ApiError_PermissionDeniedImplCopyWithImpl<$Res>
+    extends _$ApiErrorCopyWithImpl<$Res, _$ApiError_PermissionDeniedImpl>
+    implements _$$ApiError_PermissionDeniedImplCopyWith<$Res> {
+  __$$ApiError_PermissionDeniedImplCopyWithImpl(
+    _$ApiError_PermissionDeniedImpl _value,
+    $Res Function(_$ApiError_PermissionDeniedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? message = null}) {
+    return _then(
+      _$ApiError_PermissionDeniedImpl(
+        message:
+            null == message
+                ? _value.message
+                : message // ignore: cast_nullable_to_non_nullable
+                    as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ApiError_PermissionDeniedImpl extends ApiError_PermissionDenied {
+  const _$ApiError_PermissionDeniedImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'ApiError.permissionDenied(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ApiError_PermissionDeniedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ApiError_PermissionDeniedImplCopyWith<_$ApiError_PermissionDeniedImpl> get copyWith =>
+      __$$ApiError_PermissionDeniedImplCopyWithImpl<_$ApiError_PermissionDeniedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) whitenoise,
+    required TResult Function(String message) invalidKey,
+    required TResult Function(String message) nostrUrl,
+    required TResult Function(String message) nostrTag,
+    required TResult Function(String message) nostrEvent,
+    required TResult Function(String message) nostrParse,
+    required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
+    required TResult Function(String message) other,
+  }) {
+    return permissionDenied(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? whitenoise,
+    TResult? Function(String message)? invalidKey,
+    TResult? Function(String message)? nostrUrl,
+    TResult? Function(String message)? nostrTag,
+    TResult? Function(String message)? nostrEvent,
+    TResult? Function(String message)? nostrParse,
+    TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
+    TResult? Function(String message)? other,
+  }) {
+    return permissionDenied?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? whitenoise,
+    TResult Function(String message)? invalidKey,
+    TResult Function(String message)? nostrUrl,
+    TResult Function(String message)? nostrTag,
+    TResult Function(String message)? nostrEvent,
+    TResult Function(String message)? nostrParse,
+    TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ApiError_Whitenoise value) whitenoise,
+    required TResult Function(ApiError_InvalidKey value) invalidKey,
+    required TResult Function(ApiError_NostrUrl value) nostrUrl,
+    required TResult Function(ApiError_NostrTag value) nostrTag,
+    required TResult Function(ApiError_NostrEvent value) nostrEvent,
+    required TResult Function(ApiError_NostrParse value) nostrParse,
+    required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
+    required TResult Function(ApiError_Other value) other,
+  }) {
+    return permissionDenied(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ApiError_Whitenoise value)? whitenoise,
+    TResult? Function(ApiError_InvalidKey value)? invalidKey,
+    TResult? Function(ApiError_NostrUrl value)? nostrUrl,
+    TResult? Function(ApiError_NostrTag value)? nostrTag,
+    TResult? Function(ApiError_NostrEvent value)? nostrEvent,
+    TResult? Function(ApiError_NostrParse value)? nostrParse,
+    TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
+    TResult? Function(ApiError_Other value)? other,
+  }) {
+    return permissionDenied?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ApiError_Whitenoise value)? whitenoise,
+    TResult Function(ApiError_InvalidKey value)? invalidKey,
+    TResult Function(ApiError_NostrUrl value)? nostrUrl,
+    TResult Function(ApiError_NostrTag value)? nostrTag,
+    TResult Function(ApiError_NostrEvent value)? nostrEvent,
+    TResult Function(ApiError_NostrParse value)? nostrParse,
+    TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
+    TResult Function(ApiError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ApiError_PermissionDenied extends ApiError {
+  const factory ApiError_PermissionDenied({required final String message}) =
+      _$ApiError_PermissionDeniedImpl;
+  const ApiError_PermissionDenied._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ApiError_PermissionDeniedImplCopyWith<_$ApiError_PermissionDeniedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ApiError_InvalidRelayUrlImplCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory _$$ApiError_InvalidRelayUrlImplCopyWith(
+    _$ApiError_InvalidRelayUrlImpl value,
+    $Res Function(_$ApiError_InvalidRelayUrlImpl) then,
+  ) = __$$ApiError_InvalidRelayUrlImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({InvalidRelayUrlReason reason, String message});
+}
+
+/// @nodoc
+class __$$ApiError_InvalidRelayUrlImplCopyWithImpl<$Res>
+    extends _$ApiErrorCopyWithImpl<$Res, _$ApiError_InvalidRelayUrlImpl>
+    implements _$$ApiError_InvalidRelayUrlImplCopyWith<$Res> {
+  __$$ApiError_InvalidRelayUrlImplCopyWithImpl(
+    _$ApiError_InvalidRelayUrlImpl _value,
+    $Res Function(_$ApiError_InvalidRelayUrlImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? reason = null, Object? message = null}) {
+    return _then(
+      _$ApiError_InvalidRelayUrlImpl(
+        reason:
+            null == reason
+                ? _value.reason
+                : reason // ignore: cast_nullable_to_non_nullable
+                    as InvalidRelayUrlReason,
+        message:
+            null == message
+                ? _value.message
+                : message // ignore: cast_nullable_to_non_nullable
+                    as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ApiError_InvalidRelayUrlImpl extends ApiError_InvalidRelayUrl {
+  const _$ApiError_InvalidRelayUrlImpl({
+    required this.reason,
+    required this.message,
+  }) : super._();
+
+  @override
+  final InvalidRelayUrlReason reason;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'ApiError.invalidRelayUrl(reason: $reason, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ApiError_InvalidRelayUrlImpl &&
+            (identical(other.reason, reason) || other.reason == reason) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reason, message);
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ApiError_InvalidRelayUrlImplCopyWith<_$ApiError_InvalidRelayUrlImpl> get copyWith =>
+      __$$ApiError_InvalidRelayUrlImplCopyWithImpl<_$ApiError_InvalidRelayUrlImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) whitenoise,
+    required TResult Function(String message) invalidKey,
+    required TResult Function(String message) nostrUrl,
+    required TResult Function(String message) nostrTag,
+    required TResult Function(String message) nostrEvent,
+    required TResult Function(String message) nostrParse,
+    required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
+    required TResult Function(String message) other,
+  }) {
+    return invalidRelayUrl(reason, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? whitenoise,
+    TResult? Function(String message)? invalidKey,
+    TResult? Function(String message)? nostrUrl,
+    TResult? Function(String message)? nostrTag,
+    TResult? Function(String message)? nostrEvent,
+    TResult? Function(String message)? nostrParse,
+    TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
+    TResult? Function(String message)? other,
+  }) {
+    return invalidRelayUrl?.call(reason, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? whitenoise,
+    TResult Function(String message)? invalidKey,
+    TResult Function(String message)? nostrUrl,
+    TResult Function(String message)? nostrTag,
+    TResult Function(String message)? nostrEvent,
+    TResult Function(String message)? nostrParse,
+    TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidRelayUrl != null) {
+      return invalidRelayUrl(reason, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ApiError_Whitenoise value) whitenoise,
+    required TResult Function(ApiError_InvalidKey value) invalidKey,
+    required TResult Function(ApiError_NostrUrl value) nostrUrl,
+    required TResult Function(ApiError_NostrTag value) nostrTag,
+    required TResult Function(ApiError_NostrEvent value) nostrEvent,
+    required TResult Function(ApiError_NostrParse value) nostrParse,
+    required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
+    required TResult Function(ApiError_Other value) other,
+  }) {
+    return invalidRelayUrl(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ApiError_Whitenoise value)? whitenoise,
+    TResult? Function(ApiError_InvalidKey value)? invalidKey,
+    TResult? Function(ApiError_NostrUrl value)? nostrUrl,
+    TResult? Function(ApiError_NostrTag value)? nostrTag,
+    TResult? Function(ApiError_NostrEvent value)? nostrEvent,
+    TResult? Function(ApiError_NostrParse value)? nostrParse,
+    TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
+    TResult? Function(ApiError_Other value)? other,
+  }) {
+    return invalidRelayUrl?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ApiError_Whitenoise value)? whitenoise,
+    TResult Function(ApiError_InvalidKey value)? invalidKey,
+    TResult Function(ApiError_NostrUrl value)? nostrUrl,
+    TResult Function(ApiError_NostrTag value)? nostrTag,
+    TResult Function(ApiError_NostrEvent value)? nostrEvent,
+    TResult Function(ApiError_NostrParse value)? nostrParse,
+    TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
+    TResult Function(ApiError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidRelayUrl != null) {
+      return invalidRelayUrl(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ApiError_InvalidRelayUrl extends ApiError {
+  const factory ApiError_InvalidRelayUrl({
+    required final InvalidRelayUrlReason reason,
+    required final String message,
+  }) = _$ApiError_InvalidRelayUrlImpl;
+  const ApiError_InvalidRelayUrl._() : super._();
+
+  InvalidRelayUrlReason get reason;
+  @override
+  String get message;
+
+  /// Create a copy of ApiError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ApiError_InvalidRelayUrlImplCopyWith<_$ApiError_InvalidRelayUrlImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$ApiError_OtherImplCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
   factory _$$ApiError_OtherImplCopyWith(
@@ -1512,6 +2020,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     required TResult Function(String message) nostrEvent,
     required TResult Function(String message) nostrParse,
     required TResult Function(String message) nostrHex,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(InvalidRelayUrlReason reason, String message) invalidRelayUrl,
     required TResult Function(String message) other,
   }) {
     return other(message);
@@ -1527,6 +2037,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     TResult? Function(String message)? nostrEvent,
     TResult? Function(String message)? nostrParse,
     TResult? Function(String message)? nostrHex,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult? Function(String message)? other,
   }) {
     return other?.call(message);
@@ -1542,6 +2054,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     TResult Function(String message)? nostrEvent,
     TResult Function(String message)? nostrParse,
     TResult Function(String message)? nostrHex,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(InvalidRelayUrlReason reason, String message)? invalidRelayUrl,
     TResult Function(String message)? other,
     required TResult orElse(),
   }) {
@@ -1561,6 +2075,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     required TResult Function(ApiError_NostrEvent value) nostrEvent,
     required TResult Function(ApiError_NostrParse value) nostrParse,
     required TResult Function(ApiError_NostrHex value) nostrHex,
+    required TResult Function(ApiError_PermissionDenied value) permissionDenied,
+    required TResult Function(ApiError_InvalidRelayUrl value) invalidRelayUrl,
     required TResult Function(ApiError_Other value) other,
   }) {
     return other(this);
@@ -1576,6 +2092,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     TResult? Function(ApiError_NostrEvent value)? nostrEvent,
     TResult? Function(ApiError_NostrParse value)? nostrParse,
     TResult? Function(ApiError_NostrHex value)? nostrHex,
+    TResult? Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult? Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult? Function(ApiError_Other value)? other,
   }) {
     return other?.call(this);
@@ -1591,6 +2109,8 @@ class _$ApiError_OtherImpl extends ApiError_Other {
     TResult Function(ApiError_NostrEvent value)? nostrEvent,
     TResult Function(ApiError_NostrParse value)? nostrParse,
     TResult Function(ApiError_NostrHex value)? nostrHex,
+    TResult Function(ApiError_PermissionDenied value)? permissionDenied,
+    TResult Function(ApiError_InvalidRelayUrl value)? invalidRelayUrl,
     TResult Function(ApiError_Other value)? other,
     required TResult orElse(),
   }) {
@@ -8,8 +8,30 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import '../frb_generated.dart';
 import '../lib.dart';
 import 'error.dart';
+import 'metadata.dart';
+import 'relays.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
+// These functions are ignored because they are not marked as `pub`: `dm_lock`, `relay_health_for_group`, `replacement_relays`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+Future<GroupRelayHealth> groupRelayHealth({
+  required String accountPubkey,
+  required String groupId,
+}) => RustLib.instance.api.crateApiGroupsGroupRelayHealth(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+);
+
+/// Pushes a [`GroupRelayAlert`] for each group the account administers once all of the
+/// group's relays have been unhealthy for longer than the alert threshold.
+///
+/// Each outage is reported once; a group alerts again only after one of its relays has
+/// recovered. Health is rechecked every minute until Dart cancels the stream.
+Stream<GroupRelayAlert> watchGroupRelayAlerts({
+  required String accountPubkey,
+}) => RustLib.instance.api.crateApiGroupsWatchGroupRelayAlerts(
+  accountPubkey: accountPubkey,
+);
 
 Future<List<Group>> activeGroups({required String pubkey}) =>
     RustLib.instance.api.crateApiGroupsActiveGroups(pubkey: pubkey);
@@ -30,6 +52,19 @@ Future<List<String>> groupAdmins({
   groupId: groupId,
 );
 
+/// Returns the members of a group with their metadata, roles and join information.
+///
+/// Metadata is served from the local user cache; users that are missing or stale are
+/// refreshed in the background, so callers should re-fetch on metadata updates rather
+/// than block on the network here.
+Future<List<GroupMember>> groupMemberDetails({
+  required String pubkey,
+  required String groupId,
+}) => RustLib.instance.api.crateApiGroupsGroupMemberDetails(
+  pubkey: pubkey,
+  groupId: groupId,
+);
+
 Future<Group> createGroup({
   required String creatorPubkey,
   required List<String> memberPubkeys,
@@ -46,6 +81,20 @@ Future<Group> createGroup({
   groupType: groupType,
 );
 
+/// Returns the active direct message group between the account and `peer_pubkey`,
+/// creating one only when none exists.
+///
+/// A pending DM welcome from the peer is accepted and reused instead of creating a
+/// second group, so tapping "message" on a contact is idempotent. Concurrent calls for
+/// the same contact wait for each other, so they cannot both create a group.
+Future<Group> findOrCreateDm({
+  required String accountPubkey,
+  required String peerPubkey,
+}) => RustLib.instance.api.crateApiGroupsFindOrCreateDm(
+  accountPubkey: accountPubkey,
+  peerPubkey: peerPubkey,
+);
+
 Future<void> addMembersToGroup({
   required String pubkey,
   required String groupId,
@@ -56,14 +105,20 @@ Future<void> addMembersToGroup({
   memberPubkeys: memberPubkeys,
 );
 
+/// Removes members from a group.
+///
+/// The optional `reason` travels with the removal and is shown to every remaining
+/// member as a system timeline entry.
 Future<void> removeMembersFromGroup({
   required String pubkey,
   required String groupId,
   required List<String> memberPubkeys,
+  String? reason,
 }) => RustLib.instance.api.crateApiGroupsRemoveMembersFromGroup(
   pubkey: pubkey,
   groupId: groupId,
   memberPubkeys: memberPubkeys,
+  reason: reason,
 );
 
 Future<Group> getGroup({
@@ -119,6 +174,14 @@ class FlutterGroupDataUpdate {
   final U8Array32? imageHash;
   final U8Array12? imageNonce;
 
+  /// New disappearing messages timer in seconds, `Some(0)` turns it off
+  final BigInt? disappearingMessagesSecs;
+  final GroupPermissions? permissions;
+
+  /// With `Some(true)`, swaps the group's unhealthy relays for healthy NIP-65 relays of
+  /// its members; ignored when `relays` is set
+  final bool? replaceUnhealthyRelays;
+
   const FlutterGroupDataUpdate({
     this.name,
     this.description,
@@ -127,6 +190,9 @@ class FlutterGroupDataUpdate {
     this.imageKey,
     this.imageHash,
     this.imageNonce,
+    this.disappearingMessagesSecs,
+    this.permissions,
+    this.replaceUnhealthyRelays,
   });
 
   @override
@@ -137,7 +203,10 @@ class FlutterGroupDataUpdate {
       admins.hashCode ^
       imageKey.hashCode ^
       imageHash.hashCode ^
-      imageNonce.hashCode;
+      imageNonce.hashCode ^
+      disappearingMessagesSecs.hashCode ^
+      permissions.hashCode ^
+      replaceUnhealthyRelays.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -150,7 +219,10 @@ class FlutterGroupDataUpdate {
           admins == other.admins &&
           imageKey == other.imageKey &&
           imageHash == other.imageHash &&
-          imageNonce == other.imageNonce;
+          imageNonce == other.imageNonce &&
+          disappearingMessagesSecs == other.disappearingMessagesSecs &&
+          permissions == other.permissions &&
+          replaceUnhealthyRelays == other.replaceUnhealthyRelays;
 }
 
 class Group {
@@ -166,6 +238,10 @@ class Group {
   final BigInt epoch;
   final GroupState state;
 
+  /// Lifetime of new messages in seconds, `None` when disappearing messages are off
+  final BigInt? disappearingMessagesSecs;
+  final GroupPermissions permissions;
+
   const Group({
     required this.mlsGroupId,
     required this.nostrGroupId,
@@ -178,11 +254,17 @@ class Group {
     this.lastMessageAt,
     required this.epoch,
     required this.state,
+    this.disappearingMessagesSecs,
+    required this.permissions,
   });
 
   Future<GroupType> groupType({required String accountPubkey}) =>
       RustLib.instance.api.crateApiGroupsGroupGroupType(that: this, accountPubkey: accountPubkey);
 
+  /// Returns whether `pubkey` is one of the group's admins.
+  bool isAdmin({required String pubkey}) =>
+      RustLib.instance.api.crateApiGroupsGroupIsAdmin(that: this, pubkey: pubkey);
+
   Future<bool> isDirectMessageType({required String accountPubkey}) =>
       RustLib.instance.api.crateApiGroupsGroupIsDirectMessageType(
         that: this,
@@ -192,6 +274,10 @@ class Group {
   Future<bool> isGroupType({required String accountPubkey}) =>
       RustLib.instance.api.crateApiGroupsGroupIsGroupType(that: this, accountPubkey: accountPubkey);
 
+  /// Applies `group_data` to the group.
+  ///
+  /// With `replace_unhealthy_relays`, the new relay list is picked by
+  /// [`replacement_relays`] from the members' relays.
   Future<void> updateGroupData({
     required String accountPubkey,
     required FlutterGroupDataUpdate groupData,
@@ -213,7 +299,9 @@ class Group {
       lastMessageId.hashCode ^
       lastMessageAt.hashCode ^
       epoch.hashCode ^
-      state.hashCode;
+      state.hashCode ^
+      disappearingMessagesSecs.hashCode ^
+      permissions.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -230,7 +318,9 @@ class Group {
           lastMessageId == other.lastMessageId &&
           lastMessageAt == other.lastMessageAt &&
           epoch == other.epoch &&
-          state == other.state;
+          state == other.state &&
+          disappearingMessagesSecs == other.disappearingMessagesSecs &&
+          permissions == other.permissions;
 }
 
 class GroupInformation {
@@ -261,6 +351,143 @@ class GroupInformation {
           updatedAt == other.updatedAt;
 }
 
+/// A group member together with their profile, role and MLS leaf information.
+class GroupMember {
+  final String pubkey;
+  final FlutterMetadata metadata;
+  final bool isAdmin;
+  final bool isSelf;
+
+  /// Epoch of the commit that added the member, if known
+  final BigInt? addedAtEpoch;
+
+  /// Pubkey of the member who committed the add, if known
+  final String? addedBy;
+  final int? leafIndex;
+
+  const GroupMember({
+    required this.pubkey,
+    required this.metadata,
+    required this.isAdmin,
+    required this.isSelf,
+    this.addedAtEpoch,
+    this.addedBy,
+    this.leafIndex,
+  });
+
+  @override
+  int get hashCode =>
+      pubkey.hashCode ^
+      metadata.hashCode ^
+      isAdmin.hashCode ^
+      isSelf.hashCode ^
+      addedAtEpoch.hashCode ^
+      addedBy.hashCode ^
+      leafIndex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupMember &&
+          runtimeType == other.runtimeType &&
+          pubkey == other.pubkey &&
+          metadata == other.metadata &&
+          isAdmin == other.isAdmin &&
+          isSelf == other.isSelf &&
+          addedAtEpoch == other.addedAtEpoch &&
+          addedBy == other.addedBy &&
+          leafIndex == other.leafIndex;
+}
+
+/// Group-level permission settings carried in the group data extension.
+///
+/// These are enforced before sending, and every member's message aggregation drops
+/// incoming posts that broke the settings in effect when they were sent, so they hold
+/// regardless of which client sent them.
+class GroupPermissions {
+  /// Announcement channel: only admins may send messages
+  final bool adminsOnlyPost;
+
+  /// Only admins may add members
+  final bool adminsOnlyAddMembers;
+
+  /// Minimum seconds between two messages from the same non-admin member
+  final BigInt? slowModeSecs;
+
+  const GroupPermissions({
+    required this.adminsOnlyPost,
+    required this.adminsOnlyAddMembers,
+    this.slowModeSecs,
+  });
+
+  static Future<GroupPermissions> default_() =>
+      RustLib.instance.api.crateApiGroupsGroupPermissionsDefault();
+
+  @override
+  int get hashCode =>
+      adminsOnlyPost.hashCode ^ adminsOnlyAddMembers.hashCode ^ slowModeSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupPermissions &&
+          runtimeType == other.runtimeType &&
+          adminsOnlyPost == other.adminsOnlyPost &&
+          adminsOnlyAddMembers == other.adminsOnlyAddMembers &&
+          slowModeSecs == other.slowModeSecs;
+}
+
+/// Sent to a group's admins once all of its relays have been unhealthy for too long
+class GroupRelayAlert {
+  final String groupId;
+  final String groupName;
+
+  /// When the last of the group's relays turned unhealthy
+  final DateTime allUnhealthySince;
+
+  const GroupRelayAlert({
+    required this.groupId,
+    required this.groupName,
+    required this.allUnhealthySince,
+  });
+
+  @override
+  int get hashCode => groupId.hashCode ^ groupName.hashCode ^ allUnhealthySince.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupRelayAlert &&
+          runtimeType == other.runtimeType &&
+          groupId == other.groupId &&
+          groupName == other.groupName &&
+          allUnhealthySince == other.allUnhealthySince;
+}
+
+/// Health of a group's relays as seen by one account
+class GroupRelayHealth {
+  final List<RelayHealth> relays;
+
+  /// When the last of the group's relays turned unhealthy, `None` if any is healthy
+  final DateTime? allUnhealthySince;
+
+  const GroupRelayHealth({
+    required this.relays,
+    this.allUnhealthySince,
+  });
+
+  @override
+  int get hashCode => relays.hashCode ^ allUnhealthySince.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupRelayHealth &&
+          runtimeType == other.runtimeType &&
+          relays == other.relays &&
+          allUnhealthySince == other.allUnhealthySince;
+}
+
 enum GroupState {
   active,
   inactive,
@@ -0,0 +1,148 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'metadata.dart';
+import 'relays.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ensure_admin`, `from_whitenoise`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`
+
+/// Creates a shareable invite link for a group.
+///
+/// Only admins can create invite links. `expires_at` and `max_uses` are optional; an
+/// invite without them never expires and can be used any number of times.
+Future<InviteLink> createInviteLink({
+  required String accountPubkey,
+  required String groupId,
+  DateTime? expiresAt,
+  int? maxUses,
+}) => RustLib.instance.api.crateApiInvitesCreateInviteLink(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  expiresAt: expiresAt,
+  maxUses: maxUses,
+);
+
+/// Sends a join request for the group behind an invite token.
+///
+/// The request is encrypted to the group's admins and carries one of the account's key
+/// packages, so an approving admin can add the account without further lookups.
+Future<void> requestJoin({
+  required String accountPubkey,
+  required String invite,
+}) => RustLib.instance.api.crateApiInvitesRequestJoin(
+  accountPubkey: accountPubkey,
+  invite: invite,
+);
+
+Future<List<JoinRequest>> pendingJoinRequests({
+  required String accountPubkey,
+  required String groupId,
+}) => RustLib.instance.api.crateApiInvitesPendingJoinRequests(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+);
+
+/// Approves a join request, adding the requester to the group with their key package.
+///
+/// Only admins can approve or decline join requests.
+Future<void> approveJoinRequest({
+  required String accountPubkey,
+  required String groupId,
+  required String requestId,
+}) => RustLib.instance.api.crateApiInvitesApproveJoinRequest(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  requestId: requestId,
+);
+
+Future<void> declineJoinRequest({
+  required String accountPubkey,
+  required String groupId,
+  required String requestId,
+}) => RustLib.instance.api.crateApiInvitesDeclineJoinRequest(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  requestId: requestId,
+);
+
+class InviteLink {
+  /// Nostr-encoded token to share, e.g. as a link or QR code
+  final String token;
+  final String mlsGroupId;
+  final DateTime? expiresAt;
+  final int? maxUses;
+  final int uses;
+  final DateTime createdAt;
+
+  const InviteLink({
+    required this.token,
+    required this.mlsGroupId,
+    this.expiresAt,
+    this.maxUses,
+    required this.uses,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      token.hashCode ^
+      mlsGroupId.hashCode ^
+      expiresAt.hashCode ^
+      maxUses.hashCode ^
+      uses.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InviteLink &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          mlsGroupId == other.mlsGroupId &&
+          expiresAt == other.expiresAt &&
+          maxUses == other.maxUses &&
+          uses == other.uses &&
+          createdAt == other.createdAt;
+}
+
+class JoinRequest {
+  final String id;
+  final String mlsGroupId;
+  final String requesterPubkey;
+  final FlutterMetadata requesterMetadata;
+  final DateTime createdAt;
+
+  const JoinRequest({
+    required this.id,
+    required this.mlsGroupId,
+    required this.requesterPubkey,
+    required this.requesterMetadata,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      mlsGroupId.hashCode ^
+      requesterPubkey.hashCode ^
+      requesterMetadata.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JoinRequest &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          mlsGroupId == other.mlsGroupId &&
+          requesterPubkey == other.requesterPubkey &&
+          requesterMetadata == other.requesterMetadata &&
+          createdAt == other.createdAt;
+}
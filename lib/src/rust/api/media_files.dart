@@ -7,6 +7,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import '../frb_generated.dart';
 import 'error.dart';
+import 'relays.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`, `from`
 
@@ -20,14 +21,19 @@ Future<MediaFile> uploadChatMedia({
   filePath: filePath,
 );
 
+/// Downloads and decrypts a chat media file.
+///
+/// Automatic downloads (`user_initiated` is `false`) are refused in low-bandwidth mode.
 Future<MediaFile> downloadChatMedia({
   required String accountPubkey,
   required String groupId,
   required String originalFileHash,
+  required bool userInitiated,
 }) => RustLib.instance.api.crateApiMediaFilesDownloadChatMedia(
   accountPubkey: accountPubkey,
   groupId: groupId,
   originalFileHash: originalFileHash,
+  userInitiated: userInitiated,
 );
 
 class FileMetadata {
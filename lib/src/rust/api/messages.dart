@@ -4,12 +4,18 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 import '../frb_generated.dart';
 import 'error.dart';
+import 'groups.dart';
 import 'media_files.dart';
+import 'relays.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+part 'messages.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `drop_permission_violations`, `ensure_can_post`, `is_post`, `spawn_expired_message_purge`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
 Future<MessageWithTokens> sendMessageToGroup({
   required String pubkey,
@@ -33,6 +39,33 @@ Future<List<ChatMessage>> fetchAggregatedMessagesForGroup({
   groupId: groupId,
 );
 
+/// Deletes another member's message as a group admin.
+///
+/// Publishes a deletion that references the message and is marked as a moderation
+/// action. Every member's core aggregation accepts it only if the sender is an admin of
+/// the group, so the message is hidden for everyone.
+Future<void> moderateDeleteMessage({
+  required String pubkey,
+  required String groupId,
+  required String messageId,
+}) => RustLib.instance.api.crateApiMessagesModerateDeleteMessage(
+  pubkey: pubkey,
+  groupId: groupId,
+  messageId: messageId,
+);
+
+/// Deletes decrypted messages whose NIP-40 expiration has passed, together with their
+/// downloaded media files, from local storage for all groups of the account.
+///
+/// # Returns
+///
+/// The number of messages that were purged
+///
+/// Expired messages are also purged every minute in the background; call this to purge
+/// right away, e.g. when a chat is opened.
+Future<BigInt> purgeExpiredMessages({required String pubkey}) =>
+    RustLib.instance.api.crateApiMessagesPurgeExpiredMessages(pubkey: pubkey);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>>
 abstract class Tag implements RustOpaqueInterface {}
 
@@ -51,6 +84,9 @@ class ChatMessage {
   final List<MediaFile> mediaAttachments;
   final int kind;
 
+  /// Set for system timeline entries, which are rendered instead of `content`
+  final SystemEvent? systemEvent;
+
   const ChatMessage({
     required this.id,
     required this.pubkey,
@@ -64,6 +100,7 @@ class ChatMessage {
     required this.reactions,
     required this.mediaAttachments,
     required this.kind,
+    this.systemEvent,
   });
 
   @override
@@ -79,7 +116,8 @@ class ChatMessage {
       contentTokens.hashCode ^
       reactions.hashCode ^
       mediaAttachments.hashCode ^
-      kind.hashCode;
+      kind.hashCode ^
+      systemEvent.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -97,7 +135,8 @@ class ChatMessage {
           contentTokens == other.contentTokens &&
           reactions == other.reactions &&
           mediaAttachments == other.mediaAttachments &&
-          kind == other.kind;
+          kind == other.kind &&
+          systemEvent == other.systemEvent;
 }
 
 /// Flutter-compatible emoji reaction details
@@ -209,6 +248,25 @@ class SerializableToken {
           content == other.content;
 }
 
+@freezed
+sealed class SystemEvent with _$SystemEvent {
+  const SystemEvent._();
+
+  const factory SystemEvent.disappearingMessagesChanged({
+    required String changedBy,
+    BigInt? durationSecs,
+  }) = SystemEvent_DisappearingMessagesChanged;
+  const factory SystemEvent.membersRemoved({
+    required String removedBy,
+    required List<String> members,
+    String? reason,
+  }) = SystemEvent_MembersRemoved;
+  const factory SystemEvent.permissionsChanged({
+    required String changedBy,
+    required GroupPermissions permissions,
+  }) = SystemEvent_PermissionsChanged;
+}
+
 /// Flutter-compatible user reaction
 class UserReaction {
   final String user;
@@ -0,0 +1,613 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'messages.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$SystemEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String changedBy, BigInt? durationSecs) disappearingMessagesChanged,
+    required TResult Function(String removedBy, List<String> members, String? reason) membersRemoved,
+    required TResult Function(String changedBy, GroupPermissions permissions) permissionsChanged,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult? Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult? Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SystemEvent_DisappearingMessagesChanged value) disappearingMessagesChanged,
+    required TResult Function(SystemEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(SystemEvent_PermissionsChanged value) permissionsChanged,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult? Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SystemEventCopyWith<$Res> {
+  factory $SystemEventCopyWith(
+    SystemEvent value,
+    $Res Function(SystemEvent) then,
+  ) = _$SystemEventCopyWithImpl<$Res, SystemEvent>;
+}
+
+/// @nodoc
+class _$SystemEventCopyWithImpl<$Res, $Val extends SystemEvent>
+    implements $SystemEventCopyWith<$Res> {
+  _$SystemEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SystemEvent_DisappearingMessagesChangedImplCopyWith<$Res> {
+  factory _$$SystemEvent_DisappearingMessagesChangedImplCopyWith(
+    _$SystemEvent_DisappearingMessagesChangedImpl value,
+    $Res Function(_$SystemEvent_DisappearingMessagesChangedImpl) then,
+  ) = __$$SystemEvent_DisappearingMessagesChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String changedBy, BigInt? durationSecs});
+}
+
+/// @nodoc
+class __$$SystemEvent_DisappearingMessagesChangedImplCopyWithImpl<$Res>
+    extends _$SystemEventCopyWithImpl<$Res, _$SystemEvent_DisappearingMessagesChangedImpl>
+    implements _$$SystemEvent_DisappearingMessagesChangedImplCopyWith<$Res> {
+  __$$SystemEvent_DisappearingMessagesChangedImplCopyWithImpl(
+    _$SystemEvent_DisappearingMessagesChangedImpl _value,
+    $Res Function(_$SystemEvent_DisappearingMessagesChangedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? changedBy = null, Object? durationSecs = freezed}) {
+    return _then(
+      _$SystemEvent_DisappearingMessagesChangedImpl(
+        changedBy:
+            null == changedBy
+                ? _value.changedBy
+                : changedBy // ignore: cast_nullable_to_non_nullable
+                    as String,
+        durationSecs:
+            freezed == durationSecs
+                ? _value.durationSecs
+                : durationSecs // ignore: cast_nullable_to_non_nullable
+                    as BigInt?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SystemEvent_DisappearingMessagesChangedImpl
+    extends SystemEvent_DisappearingMessagesChanged {
+  const _$SystemEvent_DisappearingMessagesChangedImpl({
+    required this.changedBy,
+    this.durationSecs,
+  }) : super._();
+
+  @override
+  final String changedBy;
+  @override
+  final BigInt? durationSecs;
+
+  @override
+  String toString() {
+    return 'SystemEvent.disappearingMessagesChanged(changedBy: $changedBy, durationSecs: $durationSecs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SystemEvent_DisappearingMessagesChangedImpl &&
+            (identical(other.changedBy, changedBy) || other.changedBy == changedBy) &&
+            (identical(other.durationSecs, durationSecs) || other.durationSecs == durationSecs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, changedBy, durationSecs);
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SystemEvent_DisappearingMessagesChangedImplCopyWith<_$SystemEvent_DisappearingMessagesChangedImpl>
+  get copyWith =>
+      __$$SystemEvent_DisappearingMessagesChangedImplCopyWithImpl<_$SystemEvent_DisappearingMessagesChangedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String changedBy, BigInt? durationSecs) disappearingMessagesChanged,
+    required TResult Function(String removedBy, List<String> members, String? reason) membersRemoved,
+    required TResult Function(String changedBy, GroupPermissions permissions) permissionsChanged,
+  }) {
+    return disappearingMessagesChanged(changedBy, durationSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult? Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult? Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+  }) {
+    return disappearingMessagesChanged?.call(changedBy, durationSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (disappearingMessagesChanged != null) {
+      return disappearingMessagesChanged(changedBy, durationSecs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SystemEvent_DisappearingMessagesChanged value) disappearingMessagesChanged,
+    required TResult Function(SystemEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(SystemEvent_PermissionsChanged value) permissionsChanged,
+  }) {
+    return disappearingMessagesChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult? Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+  }) {
+    return disappearingMessagesChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (disappearingMessagesChanged != null) {
+      return disappearingMessagesChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SystemEvent_DisappearingMessagesChanged extends SystemEvent {
+  const factory SystemEvent_DisappearingMessagesChanged({
+    required final String changedBy,
+    final BigInt? durationSecs,
+  }) = _$SystemEvent_DisappearingMessagesChangedImpl;
+  const SystemEvent_DisappearingMessagesChanged._() : super._();
+
+  String get changedBy;
+  BigInt? get durationSecs;
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SystemEvent_DisappearingMessagesChangedImplCopyWith<_$SystemEvent_DisappearingMessagesChangedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SystemEvent_MembersRemovedImplCopyWith<$Res> {
+  factory _$$SystemEvent_MembersRemovedImplCopyWith(
+    _$SystemEvent_MembersRemovedImpl value,
+    $Res Function(_$SystemEvent_MembersRemovedImpl) then,
+  ) = __$$SystemEvent_MembersRemovedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String removedBy, List<String> members, String? reason});
+}
+
+/// @nodoc
+class __$$SystemEvent_MembersRemovedImplCopyWithImpl<$Res>
+    extends _$SystemEventCopyWithImpl<$Res, _$SystemEvent_MembersRemovedImpl>
+    implements _$$SystemEvent_MembersRemovedImplCopyWith<$Res> {
+  __$$SystemEvent_MembersRemovedImplCopyWithImpl(
+    _$SystemEvent_MembersRemovedImpl _value,
+    $Res Function(_$SystemEvent_MembersRemovedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? removedBy = null,
+    Object? members = null,
+    Object? reason = freezed,
+  }) {
+    return _then(
+      _$SystemEvent_MembersRemovedImpl(
+        removedBy:
+            null == removedBy
+                ? _value.removedBy
+                : removedBy // ignore: cast_nullable_to_non_nullable
+                    as String,
+        members:
+            null == members
+                ? _value._members
+                : members // ignore: cast_nullable_to_non_nullable
+                    as List<String>,
+        reason:
+            freezed == reason
+                ? _value.reason
+                : reason // ignore: cast_nullable_to_non_nullable
+                    as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SystemEvent_MembersRemovedImpl extends SystemEvent_MembersRemoved {
+  const _$SystemEvent_MembersRemovedImpl({
+    required this.removedBy,
+    required final List<String> members,
+    this.reason,
+  }) : _members = members, super._();
+
+  @override
+  final String removedBy;
+  final List<String> _members;
+  @override
+  List<String> get members {
+    if (_members is EqualUnmodifiableListView) return _members;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_members);
+  }
+
+  @override
+  final String? reason;
+
+  @override
+  String toString() {
+    return 'SystemEvent.membersRemoved(removedBy: $removedBy, members: $members, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SystemEvent_MembersRemovedImpl &&
+            (identical(other.removedBy, removedBy) || other.removedBy == removedBy) &&
+            const DeepCollectionEquality().equals(other._members, _members) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    removedBy,
+    const DeepCollectionEquality().hash(_members),
+    reason,
+  );
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SystemEvent_MembersRemovedImplCopyWith<_$SystemEvent_MembersRemovedImpl> get copyWith =>
+      __$$SystemEvent_MembersRemovedImplCopyWithImpl<_$SystemEvent_MembersRemovedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String changedBy, BigInt? durationSecs) disappearingMessagesChanged,
+    required TResult Function(String removedBy, List<String> members, String? reason) membersRemoved,
+    required TResult Function(String changedBy, GroupPermissions permissions) permissionsChanged,
+  }) {
+    return membersRemoved(removedBy, members, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult? Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult? Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+  }) {
+    return membersRemoved?.call(removedBy, members, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (membersRemoved != null) {
+      return membersRemoved(removedBy, members, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SystemEvent_DisappearingMessagesChanged value) disappearingMessagesChanged,
+    required TResult Function(SystemEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(SystemEvent_PermissionsChanged value) permissionsChanged,
+  }) {
+    return membersRemoved(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult? Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+  }) {
+    return membersRemoved?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (membersRemoved != null) {
+      return membersRemoved(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SystemEvent_MembersRemoved extends SystemEvent {
+  const factory SystemEvent_MembersRemoved({
+    required final String removedBy,
+    required final List<String> members,
+    final String? reason,
+  }) = _$SystemEvent_MembersRemovedImpl;
+  const SystemEvent_MembersRemoved._() : super._();
+
+  String get removedBy;
+  List<String> get members;
+  String? get reason;
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SystemEvent_MembersRemovedImplCopyWith<_$SystemEvent_MembersRemovedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SystemEvent_PermissionsChangedImplCopyWith<$Res> {
+  factory _$$SystemEvent_PermissionsChangedImplCopyWith(
+    _$SystemEvent_PermissionsChangedImpl value,
+    $Res Function(_$SystemEvent_PermissionsChangedImpl) then,
+  ) = __$$SystemEvent_PermissionsChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String changedBy, GroupPermissions permissions});
+}
+
+/// @nodoc
+class __$$SystemEvent_PermissionsChangedImplCopyWithImpl<$Res>
+    extends _$SystemEventCopyWithImpl<$Res, _$SystemEvent_PermissionsChangedImpl>
+    implements _$$SystemEvent_PermissionsChangedImplCopyWith<$Res> {
+  __$$SystemEvent_PermissionsChangedImplCopyWithImpl(
+    _$SystemEvent_PermissionsChangedImpl _value,
+    $Res Function(_$SystemEvent_PermissionsChangedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? changedBy = null, Object? permissions = null}) {
+    return _then(
+      _$SystemEvent_PermissionsChangedImpl(
+        changedBy:
+            null == changedBy
+                ? _value.changedBy
+                : changedBy // ignore: cast_nullable_to_non_nullable
+                    as String,
+        permissions:
+            null == permissions
+                ? _value.permissions
+                : permissions // ignore: cast_nullable_to_non_nullable
+                    as GroupPermissions,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SystemEvent_PermissionsChangedImpl extends SystemEvent_PermissionsChanged {
+  const _$SystemEvent_PermissionsChangedImpl({
+    required this.changedBy,
+    required this.permissions,
+  }) : super._();
+
+  @override
+  final String changedBy;
+  @override
+  final GroupPermissions permissions;
+
+  @override
+  String toString() {
+    return 'SystemEvent.permissionsChanged(changedBy: $changedBy, permissions: $permissions)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SystemEvent_PermissionsChangedImpl &&
+            (identical(other.changedBy, changedBy) || other.changedBy == changedBy) &&
+            (identical(other.permissions, permissions) || other.permissions == permissions));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, changedBy, permissions);
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SystemEvent_PermissionsChangedImplCopyWith<_$SystemEvent_PermissionsChangedImpl>
+  get copyWith =>
+      __$$SystemEvent_PermissionsChangedImplCopyWithImpl<_$SystemEvent_PermissionsChangedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String changedBy, BigInt? durationSecs) disappearingMessagesChanged,
+    required TResult Function(String removedBy, List<String> members, String? reason) membersRemoved,
+    required TResult Function(String changedBy, GroupPermissions permissions) permissionsChanged,
+  }) {
+    return permissionsChanged(changedBy, permissions);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult? Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult? Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+  }) {
+    return permissionsChanged?.call(changedBy, permissions);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String changedBy, BigInt? durationSecs)? disappearingMessagesChanged,
+    TResult Function(String removedBy, List<String> members, String? reason)? membersRemoved,
+    TResult Function(String changedBy, GroupPermissions permissions)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (permissionsChanged != null) {
+      return permissionsChanged(changedBy, permissions);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SystemEvent_DisappearingMessagesChanged value) disappearingMessagesChanged,
+    required TResult Function(SystemEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(SystemEvent_PermissionsChanged value) permissionsChanged,
+  }) {
+    return permissionsChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult? Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+  }) {
+    return permissionsChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SystemEvent_DisappearingMessagesChanged value)? disappearingMessagesChanged,
+    TResult Function(SystemEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(SystemEvent_PermissionsChanged value)? permissionsChanged,
+    required TResult orElse(),
+  }) {
+    if (permissionsChanged != null) {
+      return permissionsChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SystemEvent_PermissionsChanged extends SystemEvent {
+  const factory SystemEvent_PermissionsChanged({
+    required final String changedBy,
+    required final GroupPermissions permissions,
+  }) = _$SystemEvent_PermissionsChangedImpl;
+  const SystemEvent_PermissionsChanged._() : super._();
+
+  String get changedBy;
+  GroupPermissions get permissions;
+
+  /// Create a copy of SystemEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SystemEvent_PermissionsChangedImplCopyWith<_$SystemEvent_PermissionsChangedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
@@ -4,16 +4,19 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 import '../frb_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`, `from`, `from`, `from`
+part 'metadata.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `custom_from_flutter`, `custom_to_flutter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`
 
 /// Flutter-compatible representation of user metadata following Nostr protocol standards.
 ///
 /// This struct provides a bridge between the core library's `Metadata` type and Flutter's
-/// type system. The `custom` field is kept private to avoid flutter_rust_bridge
-/// auto-generation issues and is accessed through getter/setter methods.
+/// type system.
 ///
 /// # Nostr Metadata Fields
 /// Most fields correspond to standard Nostr metadata as defined in NIP-01 and related NIPs.
@@ -45,8 +48,8 @@ class FlutterMetadata {
   /// Lightning Network address in newer format
   final String? lud16;
 
-  /// Additional custom metadata fields
-  final Map<String, String> custom;
+  /// Additional custom metadata fields, keeping their JSON types
+  final Map<String, MetadataValue> custom;
 
   const FlutterMetadata({
     this.name,
@@ -90,3 +93,35 @@ class FlutterMetadata {
           lud16 == other.lud16 &&
           custom == other.custom;
 }
+
+@freezed
+sealed class MetadataValue with _$MetadataValue {
+  const MetadataValue._();
+
+  const factory MetadataValue.null_() = MetadataValue_Null;
+  const factory MetadataValue.bool(
+    bool field0,
+  ) = MetadataValue_Bool;
+
+  /// Integers that fit in an `i64`
+  const factory MetadataValue.integer(
+    PlatformInt64 field0,
+  ) = MetadataValue_Integer;
+
+  /// Integers above `i64::MAX`
+  const factory MetadataValue.unsignedInteger(
+    BigInt field0,
+  ) = MetadataValue_UnsignedInteger;
+  const factory MetadataValue.float(
+    double field0,
+  ) = MetadataValue_Float;
+  const factory MetadataValue.string(
+    String field0,
+  ) = MetadataValue_String;
+  const factory MetadataValue.array(
+    List<MetadataValue> field0,
+  ) = MetadataValue_Array;
+  const factory MetadataValue.object(
+    Map<String, MetadataValue> field0,
+  ) = MetadataValue_Object;
+}
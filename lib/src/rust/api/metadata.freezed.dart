@@ -0,0 +1,1541 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'metadata.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$MetadataValue {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $MetadataValueCopyWith<$Res> {
+  factory $MetadataValueCopyWith(
+    MetadataValue value,
+    $Res Function(MetadataValue) then,
+  ) = _$MetadataValueCopyWithImpl<$Res, MetadataValue>;
+}
+
+/// @nodoc
+class _$MetadataValueCopyWithImpl<$Res, $Val extends MetadataValue>
+    implements $MetadataValueCopyWith<$Res> {
+  _$MetadataValueCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_NullImplCopyWith<$Res> {
+  factory _$$MetadataValue_NullImplCopyWith(
+    _$MetadataValue_NullImpl value,
+    $Res Function(_$MetadataValue_NullImpl) then,
+  ) = __$$MetadataValue_NullImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$MetadataValue_NullImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_NullImpl>
+    implements _$$MetadataValue_NullImplCopyWith<$Res> {
+  __$$MetadataValue_NullImplCopyWithImpl(
+    _$MetadataValue_NullImpl _value,
+    $Res Function(_$MetadataValue_NullImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$MetadataValue_NullImpl extends MetadataValue_Null {
+  const _$MetadataValue_NullImpl() : super._();
+
+  @override
+  String toString() {
+    return 'MetadataValue.null_()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$MetadataValue_NullImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return null_();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return null_?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (null_ != null) {
+      return null_();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return null_(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return null_?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (null_ != null) {
+      return null_(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Null extends MetadataValue {
+  const factory MetadataValue_Null() = _$MetadataValue_NullImpl;
+  const MetadataValue_Null._() : super._();
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_BoolImplCopyWith<$Res> {
+  factory _$$MetadataValue_BoolImplCopyWith(
+    _$MetadataValue_BoolImpl value,
+    $Res Function(_$MetadataValue_BoolImpl) then,
+  ) = __$$MetadataValue_BoolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_BoolImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_BoolImpl>
+    implements _$$MetadataValue_BoolImplCopyWith<$Res> {
+  __$$MetadataValue_BoolImplCopyWithImpl(
+    _$MetadataValue_BoolImpl _value,
+    $Res Function(_$MetadataValue_BoolImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_BoolImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as bool,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_BoolImpl extends MetadataValue_Bool {
+  const _$MetadataValue_BoolImpl(this.field0) : super._();
+
+  @override
+  final bool field0;
+
+  @override
+  String toString() {
+    return 'MetadataValue.bool(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_BoolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_BoolImplCopyWith<_$MetadataValue_BoolImpl> get copyWith =>
+      __$$MetadataValue_BoolImplCopyWithImpl<_$MetadataValue_BoolImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return bool(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return bool?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return bool(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return bool?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Bool extends MetadataValue {
+  const factory MetadataValue_Bool(final bool field0) = _$MetadataValue_BoolImpl;
+  const MetadataValue_Bool._() : super._();
+
+  bool get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_BoolImplCopyWith<_$MetadataValue_BoolImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_IntegerImplCopyWith<$Res> {
+  factory _$$MetadataValue_IntegerImplCopyWith(
+    _$MetadataValue_IntegerImpl value,
+    $Res Function(_$MetadataValue_IntegerImpl) then,
+  ) = __$$MetadataValue_IntegerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PlatformInt64 field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_IntegerImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_IntegerImpl>
+    implements _$$MetadataValue_IntegerImplCopyWith<$Res> {
+  __$$MetadataValue_IntegerImplCopyWithImpl(
+    _$MetadataValue_IntegerImpl _value,
+    $Res Function(_$MetadataValue_IntegerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_IntegerImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as PlatformInt64,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_IntegerImpl extends MetadataValue_Integer {
+  const _$MetadataValue_IntegerImpl(this.field0) : super._();
+
+  @override
+  final PlatformInt64 field0;
+
+  @override
+  String toString() {
+    return 'MetadataValue.integer(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_IntegerImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_IntegerImplCopyWith<_$MetadataValue_IntegerImpl> get copyWith =>
+      __$$MetadataValue_IntegerImplCopyWithImpl<_$MetadataValue_IntegerImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return integer(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return integer?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (integer != null) {
+      return integer(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return integer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return integer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (integer != null) {
+      return integer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Integer extends MetadataValue {
+  const factory MetadataValue_Integer(final PlatformInt64 field0) = _$MetadataValue_IntegerImpl;
+  const MetadataValue_Integer._() : super._();
+
+  PlatformInt64 get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_IntegerImplCopyWith<_$MetadataValue_IntegerImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_UnsignedIntegerImplCopyWith<$Res> {
+  factory _$$MetadataValue_UnsignedIntegerImplCopyWith(
+    _$MetadataValue_UnsignedIntegerImpl value,
+    $Res Function(_$MetadataValue_UnsignedIntegerImpl) then,
+  ) = __$$MetadataValue_UnsignedIntegerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_UnsignedIntegerImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_UnsignedIntegerImpl>
+    implements _$$MetadataValue_UnsignedIntegerImplCopyWith<$Res> {
+  __$$MetadataValue_UnsignedIntegerImplCopyWithImpl(
+    _$MetadataValue_UnsignedIntegerImpl _value,
+    $Res Function(_$MetadataValue_UnsignedIntegerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_UnsignedIntegerImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_UnsignedIntegerImpl extends MetadataValue_UnsignedInteger {
+  const _$MetadataValue_UnsignedIntegerImpl(this.field0) : super._();
+
+  @override
+  final BigInt field0;
+
+  @override
+  String toString() {
+    return 'MetadataValue.unsignedInteger(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_UnsignedIntegerImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_UnsignedIntegerImplCopyWith<_$MetadataValue_UnsignedIntegerImpl> get copyWith =>
+      __$$MetadataValue_UnsignedIntegerImplCopyWithImpl<_$MetadataValue_UnsignedIntegerImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return unsignedInteger(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return unsignedInteger?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (unsignedInteger != null) {
+      return unsignedInteger(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return unsignedInteger(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return unsignedInteger?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (unsignedInteger != null) {
+      return unsignedInteger(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_UnsignedInteger extends MetadataValue {
+  const factory MetadataValue_UnsignedInteger(final BigInt field0) =
+      _$MetadataValue_UnsignedIntegerImpl;
+  const MetadataValue_UnsignedInteger._() : super._();
+
+  BigInt get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_UnsignedIntegerImplCopyWith<_$MetadataValue_UnsignedIntegerImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_FloatImplCopyWith<$Res> {
+  factory _$$MetadataValue_FloatImplCopyWith(
+    _$MetadataValue_FloatImpl value,
+    $Res Function(_$MetadataValue_FloatImpl) then,
+  ) = __$$MetadataValue_FloatImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({double field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_FloatImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_FloatImpl>
+    implements _$$MetadataValue_FloatImplCopyWith<$Res> {
+  __$$MetadataValue_FloatImplCopyWithImpl(
+    _$MetadataValue_FloatImpl _value,
+    $Res Function(_$MetadataValue_FloatImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_FloatImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as double,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_FloatImpl extends MetadataValue_Float {
+  const _$MetadataValue_FloatImpl(this.field0) : super._();
+
+  @override
+  final double field0;
+
+  @override
+  String toString() {
+    return 'MetadataValue.float(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_FloatImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_FloatImplCopyWith<_$MetadataValue_FloatImpl> get copyWith =>
+      __$$MetadataValue_FloatImplCopyWithImpl<_$MetadataValue_FloatImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return float(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return float?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (float != null) {
+      return float(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return float(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return float?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (float != null) {
+      return float(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Float extends MetadataValue {
+  const factory MetadataValue_Float(final double field0) = _$MetadataValue_FloatImpl;
+  const MetadataValue_Float._() : super._();
+
+  double get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_FloatImplCopyWith<_$MetadataValue_FloatImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_StringImplCopyWith<$Res> {
+  factory _$$MetadataValue_StringImplCopyWith(
+    _$MetadataValue_StringImpl value,
+    $Res Function(_$MetadataValue_StringImpl) then,
+  ) = __$$MetadataValue_StringImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_StringImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_StringImpl>
+    implements _$$MetadataValue_StringImplCopyWith<$Res> {
+  __$$MetadataValue_StringImplCopyWithImpl(
+    _$MetadataValue_StringImpl _value,
+    $Res Function(_$MetadataValue_StringImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_StringImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_StringImpl extends MetadataValue_String {
+  const _$MetadataValue_StringImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'MetadataValue.string(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_StringImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_StringImplCopyWith<_$MetadataValue_StringImpl> get copyWith =>
+      __$$MetadataValue_StringImplCopyWithImpl<_$MetadataValue_StringImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return string(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return string?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (string != null) {
+      return string(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return string(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return string?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (string != null) {
+      return string(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_String extends MetadataValue {
+  const factory MetadataValue_String(final String field0) = _$MetadataValue_StringImpl;
+  const MetadataValue_String._() : super._();
+
+  String get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_StringImplCopyWith<_$MetadataValue_StringImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_ArrayImplCopyWith<$Res> {
+  factory _$$MetadataValue_ArrayImplCopyWith(
+    _$MetadataValue_ArrayImpl value,
+    $Res Function(_$MetadataValue_ArrayImpl) then,
+  ) = __$$MetadataValue_ArrayImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<MetadataValue> field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_ArrayImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_ArrayImpl>
+    implements _$$MetadataValue_ArrayImplCopyWith<$Res> {
+  __$$MetadataValue_ArrayImplCopyWithImpl(
+    _$MetadataValue_ArrayImpl _value,
+    $Res Function(_$MetadataValue_ArrayImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_ArrayImpl(
+        null == field0
+            ? _value._field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as List<MetadataValue>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_ArrayImpl extends MetadataValue_Array {
+  const _$MetadataValue_ArrayImpl(final List<MetadataValue> field0) : _field0 = field0, super._();
+
+  final List<MetadataValue> _field0;
+  @override
+  List<MetadataValue> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'MetadataValue.array(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_ArrayImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_ArrayImplCopyWith<_$MetadataValue_ArrayImpl> get copyWith =>
+      __$$MetadataValue_ArrayImplCopyWithImpl<_$MetadataValue_ArrayImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return array(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return array?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (array != null) {
+      return array(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return array(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return array?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (array != null) {
+      return array(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Array extends MetadataValue {
+  const factory MetadataValue_Array(final List<MetadataValue> field0) = _$MetadataValue_ArrayImpl;
+  const MetadataValue_Array._() : super._();
+
+  List<MetadataValue> get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_ArrayImplCopyWith<_$MetadataValue_ArrayImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MetadataValue_ObjectImplCopyWith<$Res> {
+  factory _$$MetadataValue_ObjectImplCopyWith(
+    _$MetadataValue_ObjectImpl value,
+    $Res Function(_$MetadataValue_ObjectImpl) then,
+  ) = __$$MetadataValue_ObjectImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Map<String, MetadataValue> field0});
+}
+
+/// @nodoc
+class __$$MetadataValue_ObjectImplCopyWithImpl<$Res>
+    extends _$MetadataValueCopyWithImpl<$Res, _$MetadataValue_ObjectImpl>
+    implements _$$MetadataValue_ObjectImplCopyWith<$Res> {
+  __$$MetadataValue_ObjectImplCopyWithImpl(
+    _$MetadataValue_ObjectImpl _value,
+    $Res Function(_$MetadataValue_ObjectImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$MetadataValue_ObjectImpl(
+        null == field0
+            ? _value._field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                as Map<String, MetadataValue>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MetadataValue_ObjectImpl extends MetadataValue_Object {
+  const _$MetadataValue_ObjectImpl(final Map<String, MetadataValue> field0)
+    : _field0 = field0, super._();
+
+  final Map<String, MetadataValue> _field0;
+  @override
+  Map<String, MetadataValue> get field0 {
+    if (_field0 is EqualUnmodifiableMapView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableMapView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'MetadataValue.object(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MetadataValue_ObjectImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MetadataValue_ObjectImplCopyWith<_$MetadataValue_ObjectImpl> get copyWith =>
+      __$$MetadataValue_ObjectImplCopyWithImpl<_$MetadataValue_ObjectImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(PlatformInt64 field0) integer,
+    required TResult Function(BigInt field0) unsignedInteger,
+    required TResult Function(double field0) float,
+    required TResult Function(String field0) string,
+    required TResult Function(List<MetadataValue> field0) array,
+    required TResult Function(Map<String, MetadataValue> field0) object,
+  }) {
+    return object(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(PlatformInt64 field0)? integer,
+    TResult? Function(BigInt field0)? unsignedInteger,
+    TResult? Function(double field0)? float,
+    TResult? Function(String field0)? string,
+    TResult? Function(List<MetadataValue> field0)? array,
+    TResult? Function(Map<String, MetadataValue> field0)? object,
+  }) {
+    return object?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(PlatformInt64 field0)? integer,
+    TResult Function(BigInt field0)? unsignedInteger,
+    TResult Function(double field0)? float,
+    TResult Function(String field0)? string,
+    TResult Function(List<MetadataValue> field0)? array,
+    TResult Function(Map<String, MetadataValue> field0)? object,
+    required TResult orElse(),
+  }) {
+    if (object != null) {
+      return object(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MetadataValue_Null value) null_,
+    required TResult Function(MetadataValue_Bool value) bool,
+    required TResult Function(MetadataValue_Integer value) integer,
+    required TResult Function(MetadataValue_UnsignedInteger value) unsignedInteger,
+    required TResult Function(MetadataValue_Float value) float,
+    required TResult Function(MetadataValue_String value) string,
+    required TResult Function(MetadataValue_Array value) array,
+    required TResult Function(MetadataValue_Object value) object,
+  }) {
+    return object(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MetadataValue_Null value)? null_,
+    TResult? Function(MetadataValue_Bool value)? bool,
+    TResult? Function(MetadataValue_Integer value)? integer,
+    TResult? Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult? Function(MetadataValue_Float value)? float,
+    TResult? Function(MetadataValue_String value)? string,
+    TResult? Function(MetadataValue_Array value)? array,
+    TResult? Function(MetadataValue_Object value)? object,
+  }) {
+    return object?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MetadataValue_Null value)? null_,
+    TResult Function(MetadataValue_Bool value)? bool,
+    TResult Function(MetadataValue_Integer value)? integer,
+    TResult Function(MetadataValue_UnsignedInteger value)? unsignedInteger,
+    TResult Function(MetadataValue_Float value)? float,
+    TResult Function(MetadataValue_String value)? string,
+    TResult Function(MetadataValue_Array value)? array,
+    TResult Function(MetadataValue_Object value)? object,
+    required TResult orElse(),
+  }) {
+    if (object != null) {
+      return object(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MetadataValue_Object extends MetadataValue {
+  const factory MetadataValue_Object(final Map<String, MetadataValue> field0) =
+      _$MetadataValue_ObjectImpl;
+  const MetadataValue_Object._() : super._();
+
+  Map<String, MetadataValue> get field0;
+
+  /// Create a copy of MetadataValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MetadataValue_ObjectImplCopyWith<_$MetadataValue_ObjectImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'relays.dart';
+
+// These functions are ignored because they are not marked as `pub`: `low_bandwidth_mode`
+
+/// Disconnects all relay pools of all accounts.
+///
+/// Calling this while already paused is a no-op.
+Future<void> pauseNetwork() => RustLib.instance.api.crateApiNetworkPauseNetwork();
+
+/// Reconnects all relay pools and catches up subscriptions from the last-seen
+/// timestamp of each relay.
+///
+/// Calling this while not paused is a no-op.
+Future<void> resumeNetwork() => RustLib.instance.api.crateApiNetworkResumeNetwork();
+
+Future<bool> isNetworkPaused() => RustLib.instance.api.crateApiNetworkIsNetworkPaused();
+
+/// Returns whether low-bandwidth mode is on.
+///
+/// In low-bandwidth mode, cached user data is served as is instead of being refreshed
+/// from relays, and `download_chat_media` only downloads on an explicit user action.
+Future<bool> isLowBandwidthMode() => RustLib.instance.api.crateApiNetworkIsLowBandwidthMode();
+
+Future<void> updateLowBandwidthMode({required bool enabled}) =>
+    RustLib.instance.api.crateApiNetworkUpdateLowBandwidthMode(enabled: enabled);
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'relays.dart';
+
+// These functions are ignored because they are not marked as `pub`: `active_proxy`, `into_whitenoise`, `onion_relays_allowed`, `parse_proxy_address`, `proxy_host_port`, `set_startup_proxy`, `startup_proxy`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+Future<ProxySettings?> getProxySettings() => RustLib.instance.api.crateApiProxyGetProxySettings();
+
+/// Sets or, with `None`, clears the proxy and reconnects all relays accordingly.
+///
+/// This replaces the proxy Whitenoise was initialized with as well.
+Future<void> updateProxySettings({ProxySettings? proxySettings}) =>
+    RustLib.instance.api.crateApiProxyUpdateProxySettings(proxySettings: proxySettings);
+
+class ProxySettings {
+  /// Proxy address such as `socks5://127.0.0.1:9050` or `127.0.0.1:9050`
+  final String proxyUrl;
+
+  /// Relays that connect directly instead of through the proxy
+  final List<String> bypassRelays;
+
+  const ProxySettings({
+    required this.proxyUrl,
+    required this.bypassRelays,
+  });
+
+  @override
+  int get hashCode => proxyUrl.hashCode ^ bypassRelays.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxySettings &&
+          runtimeType == other.runtimeType &&
+          proxyUrl == other.proxyUrl &&
+          bypassRelays == other.bypassRelays;
+}
@@ -9,7 +9,28 @@ import '../frb_generated.dart';
 import 'accounts.dart';
 import 'error.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+// These functions are ignored because they are not marked as `pub`: `description`, `is_private_host`, `is_private_ipv4`, `normalize_relay_url`, `relays_with_info`, `validated_relay_url`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+/// Returns the NIP-11 information document of a relay.
+///
+/// Documents are cached and only fetched again from the relay once they are older
+/// than a day.
+Future<RelayInfo> relayInfo({required String url}) =>
+    RustLib.instance.api.crateApiRelaysRelayInfo(url: url);
+
+/// Validates relay input and returns the normalized URL to store.
+///
+/// Rejections are reported as [`ApiError::InvalidRelayUrl`] with a typed reason.
+Future<String> validateRelayUrl({
+  required String pubkey,
+  required String url,
+  required RelayType relayType,
+}) => RustLib.instance.api.crateApiRelaysValidateRelayUrl(
+  pubkey: pubkey,
+  url: url,
+  relayType: relayType,
+);
 
 Future<RelayType> relayTypeNip65() => RustLib.instance.api.crateApiRelaysRelayTypeNip65();
 
@@ -17,10 +38,19 @@ Future<RelayType> relayTypeInbox() => RustLib.instance.api.crateApiRelaysRelayTy
 
 Future<RelayType> relayTypeKeyPackage() => RustLib.instance.api.crateApiRelaysRelayTypeKeyPackage();
 
-Future<List<(String, String)>> getAccountRelayStatuses({
+Future<List<RelayConnectionInfo>> getAccountRelayStatuses({
   required String pubkey,
 }) => RustLib.instance.api.crateApiRelaysGetAccountRelayStatuses(pubkey: pubkey);
 
+Future<RelayHealth> relayHealth({required String url}) =>
+    RustLib.instance.api.crateApiRelaysRelayHealth(url: url);
+
+/// Returns the health of every relay the account is connected to.
+///
+/// Relays whose health can't be read are left out rather than failing the whole list.
+Future<List<RelayHealth>> accountRelayHealth({required String pubkey}) =>
+    RustLib.instance.api.crateApiRelaysAccountRelayHealth(pubkey: pubkey);
+
 /// Ensures all subscriptions (global and all accounts) are operational.
 ///
 /// This method is designed for periodic background tasks that need to ensure
@@ -43,19 +73,72 @@ Future<List<(String, String)>> getAccountRelayStatuses({
 Future<void> ensureAllSubscriptions() =>
     RustLib.instance.api.crateApiRelaysEnsureAllSubscriptions();
 
+Future<RelayAuthPolicy> relayAuthPolicy({
+  required String pubkey,
+  required String url,
+}) => RustLib.instance.api.crateApiRelaysRelayAuthPolicy(
+  pubkey: pubkey,
+  url: url,
+);
+
+/// Sets how the account answers NIP-42 AUTH challenges from one relay.
+///
+/// Challenges are always signed with the key of the account whose connection received
+/// them, never with another logged-in account.
+Future<void> setRelayAuthPolicy({
+  required String pubkey,
+  required String url,
+  required RelayAuthPolicy policy,
+}) => RustLib.instance.api.crateApiRelaysSetRelayAuthPolicy(
+  pubkey: pubkey,
+  url: url,
+  policy: policy,
+);
+
+/// Returns the relays whose AUTH challenge is waiting for the user under the `Ask` policy.
+Future<List<String>> pendingRelayAuthRequests({required String pubkey}) =>
+    RustLib.instance.api.crateApiRelaysPendingRelayAuthRequests(pubkey: pubkey);
+
+/// Answers a pending AUTH challenge. With `remember` set, the decision becomes the
+/// relay's policy for this account.
+Future<void> respondToRelayAuth({
+  required String pubkey,
+  required String url,
+  required bool approve,
+  required bool remember,
+}) => RustLib.instance.api.crateApiRelaysRespondToRelayAuth(
+  pubkey: pubkey,
+  url: url,
+  approve: approve,
+  remember: remember,
+);
+
+/// Why a relay URL was rejected by [`validate_relay_url`]
+enum InvalidRelayUrlReason {
+  malformed,
+  badScheme,
+  privateAddress,
+  onionWithoutProxy,
+  duplicate,
+}
+
 class Relay {
   final String url;
   final DateTime createdAt;
   final DateTime updatedAt;
 
+  /// NIP-11 information, only set when explicitly requested
+  final RelayInfo? info;
+
   const Relay({
     required this.url,
     required this.createdAt,
     required this.updatedAt,
+    this.info,
   });
 
   @override
-  int get hashCode => url.hashCode ^ createdAt.hashCode ^ updatedAt.hashCode;
+  int get hashCode => url.hashCode ^ createdAt.hashCode ^ updatedAt.hashCode ^ info.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -64,5 +147,210 @@ class Relay {
           runtimeType == other.runtimeType &&
           url == other.url &&
           createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
+          updatedAt == other.updatedAt &&
+          info == other.info;
+}
+
+/// Whether an account answers NIP-42 AUTH challenges from a relay
+enum RelayAuthPolicy {
+  always,
+  ask,
+  never,
+}
+
+/// NIP-42 authentication state of a relay connection
+enum RelayAuthState {
+  notRequired,
+  required_,
+  authenticated,
+  failed,
+}
+
+/// Connection state and statistics of one relay in an account's relay pool
+class RelayConnectionInfo {
+  final String url;
+  final RelayStatus status;
+  final DateTime? lastConnectedAt;
+
+  /// Connection attempts that did not succeed since the relay was added
+  final BigInt failedAttempts;
+  final String? lastError;
+
+  /// Round-trip latency in milliseconds, if it has been measured
+  final BigInt? latencyMs;
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+  final RelayAuthState authState;
+
+  const RelayConnectionInfo({
+    required this.url,
+    required this.status,
+    this.lastConnectedAt,
+    required this.failedAttempts,
+    this.lastError,
+    this.latencyMs,
+    required this.bytesSent,
+    required this.bytesReceived,
+    required this.authState,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      status.hashCode ^
+      lastConnectedAt.hashCode ^
+      failedAttempts.hashCode ^
+      lastError.hashCode ^
+      latencyMs.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      authState.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayConnectionInfo &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          status == other.status &&
+          lastConnectedAt == other.lastConnectedAt &&
+          failedAttempts == other.failedAttempts &&
+          lastError == other.lastError &&
+          latencyMs == other.latencyMs &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          authState == other.authState;
+}
+
+/// Health of a relay, tracked from publish and query outcomes across all accounts
+class RelayHealth {
+  final String url;
+
+  /// Combined score between 0.0 (unusable) and 1.0, weighting success rate over latency
+  final double score;
+  final double successRate;
+
+  /// Average round-trip latency in milliseconds, if it has been measured
+  final BigInt? latencyMs;
+  final bool isHealthy;
+
+  /// When the relay last turned unhealthy, `None` while healthy
+  final DateTime? unhealthySince;
+
+  const RelayHealth({
+    required this.url,
+    required this.score,
+    required this.successRate,
+    this.latencyMs,
+    required this.isHealthy,
+    this.unhealthySince,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      score.hashCode ^
+      successRate.hashCode ^
+      latencyMs.hashCode ^
+      isHealthy.hashCode ^
+      unhealthySince.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayHealth &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          score == other.score &&
+          successRate == other.successRate &&
+          latencyMs == other.latencyMs &&
+          isHealthy == other.isHealthy &&
+          unhealthySince == other.unhealthySince;
+}
+
+/// Flutter-compatible NIP-11 relay information document
+class RelayInfo {
+  final String url;
+  final String? name;
+  final String? description;
+  final String? software;
+  final String? version;
+  final Uint16List supportedNips;
+  final PlatformInt64? maxMessageLength;
+  final bool authRequired;
+  final bool paymentRequired;
+  final bool restrictedWrites;
+  final String? icon;
+  final List<RelayWarning> warnings;
+  final DateTime fetchedAt;
+
+  const RelayInfo({
+    required this.url,
+    this.name,
+    this.description,
+    this.software,
+    this.version,
+    required this.supportedNips,
+    this.maxMessageLength,
+    required this.authRequired,
+    required this.paymentRequired,
+    required this.restrictedWrites,
+    this.icon,
+    required this.warnings,
+    required this.fetchedAt,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      name.hashCode ^
+      description.hashCode ^
+      software.hashCode ^
+      version.hashCode ^
+      supportedNips.hashCode ^
+      maxMessageLength.hashCode ^
+      authRequired.hashCode ^
+      paymentRequired.hashCode ^
+      restrictedWrites.hashCode ^
+      icon.hashCode ^
+      warnings.hashCode ^
+      fetchedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayInfo &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          name == other.name &&
+          description == other.description &&
+          software == other.software &&
+          version == other.version &&
+          supportedNips == other.supportedNips &&
+          maxMessageLength == other.maxMessageLength &&
+          authRequired == other.authRequired &&
+          paymentRequired == other.paymentRequired &&
+          restrictedWrites == other.restrictedWrites &&
+          icon == other.icon &&
+          warnings == other.warnings &&
+          fetchedAt == other.fetchedAt;
+}
+
+enum RelayStatus {
+  initialized,
+  pending,
+  connecting,
+  connected,
+  disconnected,
+  terminated,
+  banned,
+  sleeping,
+}
+
+/// Reasons a relay may not work for MLS messaging, derived from its NIP-11 document
+enum RelayWarning {
+  authRequired,
+  paymentRequired,
+  restrictedWrites,
+  messageLengthTooSmall,
 }
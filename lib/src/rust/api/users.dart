@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 import '../frb_generated.dart';
 import 'accounts.dart';
@@ -11,57 +12,178 @@ import 'error.dart';
 import 'metadata.dart';
 import 'relays.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+part 'users.freezed.dart';
 
+// These functions are ignored because they are not marked as `pub`: `effective_sync_policy`, `fetch_user_via_outbox`, `find_cached_user`, `find_user_with_policy`, `is_fresh`, `is_nip05_identifier`, `match_field`, `match_user`, `merge_relays`, `merge_users`, `parse_unique_pubkeys`, `partition_cached_users`, `rank_matches`, `resolve_user_reference`, `write_relays`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `partial_cmp`
+
+/// Returns a user, creating it if unknown.
+///
+/// `pubkey` may be a hex key, npub, nprofile or nevent; relay hints from the latter two
+/// are stored for the user. The returned user reports when and from which relays it was
+/// last fetched, so the UI can show staleness.
 Future<User> getUser({
   required String pubkey,
-  required bool blockingDataSync,
+  required SyncPolicy syncPolicy,
 }) => RustLib.instance.api.crateApiUsersGetUser(
   pubkey: pubkey,
-  blockingDataSync: blockingDataSync,
+  syncPolicy: syncPolicy,
 );
 
 Future<FlutterMetadata> userMetadata({
   required String pubkey,
-  required bool blockingDataSync,
+  required SyncPolicy syncPolicy,
 }) => RustLib.instance.api.crateApiUsersUserMetadata(
   pubkey: pubkey,
-  blockingDataSync: blockingDataSync,
+  syncPolicy: syncPolicy,
 );
 
 Future<List<Relay>> userRelays({
   required String pubkey,
   required RelayType relayType,
-  required bool blockingDataSync,
+  required SyncPolicy syncPolicy,
 }) => RustLib.instance.api.crateApiUsersUserRelays(
   pubkey: pubkey,
   relayType: relayType,
-  blockingDataSync: blockingDataSync,
+  syncPolicy: syncPolicy,
 );
 
 Future<bool> userHasKeyPackage({
   required String pubkey,
-  required bool blockingDataSync,
+  required SyncPolicy syncPolicy,
 }) => RustLib.instance.api.crateApiUsersUserHasKeyPackage(
   pubkey: pubkey,
-  blockingDataSync: blockingDataSync,
+  syncPolicy: syncPolicy,
+);
+
+/// Checks the user's NIP-05 identifier again, ignoring the cached result.
+///
+/// Use this when the user asks to re-check, e.g. after the domain was unreachable.
+Future<User> verifyUserNip05({required String pubkey}) =>
+    RustLib.instance.api.crateApiUsersVerifyUserNip05(pubkey: pubkey);
+
+/// Returns many users in one call, in the order of `pubkeys`.
+///
+/// Users that need fetching under `sync_policy` are fetched together with a single
+/// multi-author relay filter instead of one query per user. With
+/// `StaleWhileRevalidate`, cached users are returned right away and unknown users are
+/// left out until the background fetch stores them; use [`watch_users`] to receive them.
+/// With `RefreshWithTimeout`, a fetch that times out keeps running in the background.
+Future<List<User>> getUsers({
+  required List<String> pubkeys,
+  required SyncPolicy syncPolicy,
+}) => RustLib.instance.api.crateApiUsersGetUsers(
+  pubkeys: pubkeys,
+  syncPolicy: syncPolicy,
+);
+
+/// Streams users as their data becomes available.
+///
+/// Cached users are sent immediately; users that need fetching under `sync_policy` are
+/// fetched with a single multi-author filter and sent again as their metadata arrives.
+/// The stream closes once the fetch completes.
+Stream<User> watchUsers({
+  required List<String> pubkeys,
+  required SyncPolicy syncPolicy,
+}) => RustLib.instance.api.crateApiUsersWatchUsers(
+  pubkeys: pubkeys,
+  syncPolicy: syncPolicy,
+);
+
+/// Searches for users by pubkey, NIP-05 identifier, name or display name.
+///
+/// Pubkeys, npubs and nprofiles return that user directly. A `name@domain` query is
+/// resolved through the domain's `.well-known/nostr.json` by the same core lookup that
+/// verifies NIP-05 identifiers, sharing its cache, so it finds users that are not cached
+/// yet. All other queries are matched fuzzily against the cached names,
+/// display names and NIP-05 identifiers. With `search_relays`, the account's NIP-50
+/// search relays are queried as well; if that fails, the local results are returned.
+///
+/// Results are ranked by how they matched, with users the account follows first within
+/// each rank.
+Future<List<UserSearchResult>> searchUsers({
+  required String accountPubkey,
+  required String query,
+  required bool searchRelays,
+}) => RustLib.instance.api.crateApiUsersSearchUsers(
+  accountPubkey: accountPubkey,
+  query: query,
+  searchRelays: searchRelays,
 );
 
+/// Result of checking a user's NIP-05 identifier against the domain's
+/// `.well-known/nostr.json`.
+///
+/// The core caches the result and checks again once it is older than a day, or right
+/// away when the user's metadata changes.
+enum Nip05Status {
+  unverified,
+  verified,
+  mismatch,
+  unreachable,
+}
+
+@freezed
+sealed class SyncPolicy with _$SyncPolicy {
+  const SyncPolicy._();
+
+  /// Only return cached data, never touch the network; fails for unknown users
+  const factory SyncPolicy.cacheOnly() = SyncPolicy_CacheOnly;
+
+  /// Return cached data right away and refresh in the background if it is older than
+  /// `max_age_secs` (or the user is unknown)
+  const factory SyncPolicy.staleWhileRevalidate({
+    required BigInt maxAgeSecs,
+  }) = SyncPolicy_StaleWhileRevalidate;
+
+  /// Always fetch from relays before returning
+  const factory SyncPolicy.forceRefresh() = SyncPolicy_ForceRefresh;
+
+  /// Fetch from relays, but return cached data if that takes longer than `timeout_ms`
+  const factory SyncPolicy.refreshWithTimeout({
+    required BigInt timeoutMs,
+  }) = SyncPolicy_RefreshWithTimeout;
+}
+
 class User {
   final String pubkey;
   final FlutterMetadata metadata;
   final DateTime createdAt;
   final DateTime updatedAt;
 
+  /// When the user's data was last fetched from relays, `None` if never
+  final DateTime? lastFetchedAt;
+
+  /// Relays the last fetch got the user's data from
+  final List<String> fetchedFromRelays;
+
+  /// Whether `metadata.nip05` points back to this user
+  final Nip05Status nip05Status;
+
+  /// When `nip05_status` was last checked, `None` if never
+  final DateTime? nip05CheckedAt;
+
   const User({
     required this.pubkey,
     required this.metadata,
     required this.createdAt,
     required this.updatedAt,
+    this.lastFetchedAt,
+    required this.fetchedFromRelays,
+    required this.nip05Status,
+    this.nip05CheckedAt,
   });
 
   @override
-  int get hashCode => pubkey.hashCode ^ metadata.hashCode ^ createdAt.hashCode ^ updatedAt.hashCode;
+  int get hashCode =>
+      pubkey.hashCode ^
+      metadata.hashCode ^
+      createdAt.hashCode ^
+      updatedAt.hashCode ^
+      lastFetchedAt.hashCode ^
+      fetchedFromRelays.hashCode ^
+      nip05Status.hashCode ^
+      nip05CheckedAt.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -71,5 +193,54 @@ class User {
           pubkey == other.pubkey &&
           metadata == other.metadata &&
           createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
+          updatedAt == other.updatedAt &&
+          lastFetchedAt == other.lastFetchedAt &&
+          fetchedFromRelays == other.fetchedFromRelays &&
+          nip05Status == other.nip05Status &&
+          nip05CheckedAt == other.nip05CheckedAt;
+}
+
+/// How a [`search_users`] result matched the query
+enum UserSearchMatch {
+  /// The query was the user's pubkey, npub or nprofile
+  pubkey,
+
+  /// The query was a NIP-05 identifier that resolved to the user
+  nip05,
+
+  /// A cached name, display name or NIP-05 starts with the query
+  prefix,
+
+  /// A cached name, display name or NIP-05 contains the query
+  substring,
+
+  /// The query's characters appear in order in a cached name, display name or NIP-05
+  fuzzy,
+
+  /// Returned by a NIP-50 search relay
+  relay,
+}
+
+class UserSearchResult {
+  final User user;
+  final UserSearchMatch matchedBy;
+  final bool isFollow;
+
+  const UserSearchResult({
+    required this.user,
+    required this.matchedBy,
+    required this.isFollow,
+  });
+
+  @override
+  int get hashCode => user.hashCode ^ matchedBy.hashCode ^ isFollow.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserSearchResult &&
+          runtimeType == other.runtimeType &&
+          user == other.user &&
+          matchedBy == other.matchedBy &&
+          isFollow == other.isFollow;
 }
@@ -0,0 +1,648 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'users.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$SyncPolicy {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() cacheOnly,
+    required TResult Function(BigInt maxAgeSecs) staleWhileRevalidate,
+    required TResult Function() forceRefresh,
+    required TResult Function(BigInt timeoutMs) refreshWithTimeout,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? cacheOnly,
+    TResult? Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult? Function()? forceRefresh,
+    TResult? Function(BigInt timeoutMs)? refreshWithTimeout,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? cacheOnly,
+    TResult Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult Function()? forceRefresh,
+    TResult Function(BigInt timeoutMs)? refreshWithTimeout,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncPolicy_CacheOnly value) cacheOnly,
+    required TResult Function(SyncPolicy_StaleWhileRevalidate value) staleWhileRevalidate,
+    required TResult Function(SyncPolicy_ForceRefresh value) forceRefresh,
+    required TResult Function(SyncPolicy_RefreshWithTimeout value) refreshWithTimeout,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult? Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult? Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult? Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SyncPolicyCopyWith<$Res> {
+  factory $SyncPolicyCopyWith(
+    SyncPolicy value,
+    $Res Function(SyncPolicy) then,
+  ) = _$SyncPolicyCopyWithImpl<$Res, SyncPolicy>;
+}
+
+/// @nodoc
+class _$SyncPolicyCopyWithImpl<$Res, $Val extends SyncPolicy> implements $SyncPolicyCopyWith<$Res> {
+  _$SyncPolicyCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SyncPolicy_CacheOnlyImplCopyWith<$Res> {
+  factory _$$SyncPolicy_CacheOnlyImplCopyWith(
+    _$SyncPolicy_CacheOnlyImpl value,
+    $Res Function(_$SyncPolicy_CacheOnlyImpl) then,
+  ) = __$$SyncPolicy_CacheOnlyImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SyncPolicy_CacheOnlyImplCopyWithImpl<$Res>
+    extends _$SyncPolicyCopyWithImpl<$Res, _$SyncPolicy_CacheOnlyImpl>
+    implements _$$SyncPolicy_CacheOnlyImplCopyWith<$Res> {
+  __$$SyncPolicy_CacheOnlyImplCopyWithImpl(
+    _$SyncPolicy_CacheOnlyImpl _value,
+    $Res Function(_$SyncPolicy_CacheOnlyImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SyncPolicy_CacheOnlyImpl extends SyncPolicy_CacheOnly {
+  const _$SyncPolicy_CacheOnlyImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SyncPolicy.cacheOnly()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$SyncPolicy_CacheOnlyImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() cacheOnly,
+    required TResult Function(BigInt maxAgeSecs) staleWhileRevalidate,
+    required TResult Function() forceRefresh,
+    required TResult Function(BigInt timeoutMs) refreshWithTimeout,
+  }) {
+    return cacheOnly();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? cacheOnly,
+    TResult? Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult? Function()? forceRefresh,
+    TResult? Function(BigInt timeoutMs)? refreshWithTimeout,
+  }) {
+    return cacheOnly?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? cacheOnly,
+    TResult Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult Function()? forceRefresh,
+    TResult Function(BigInt timeoutMs)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (cacheOnly != null) {
+      return cacheOnly();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncPolicy_CacheOnly value) cacheOnly,
+    required TResult Function(SyncPolicy_StaleWhileRevalidate value) staleWhileRevalidate,
+    required TResult Function(SyncPolicy_ForceRefresh value) forceRefresh,
+    required TResult Function(SyncPolicy_RefreshWithTimeout value) refreshWithTimeout,
+  }) {
+    return cacheOnly(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult? Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult? Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult? Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+  }) {
+    return cacheOnly?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (cacheOnly != null) {
+      return cacheOnly(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncPolicy_CacheOnly extends SyncPolicy {
+  const factory SyncPolicy_CacheOnly() = _$SyncPolicy_CacheOnlyImpl;
+  const SyncPolicy_CacheOnly._() : super._();
+}
+
+/// @nodoc
+abstract class _$$SyncPolicy_StaleWhileRevalidateImplCopyWith<$Res> {
+  factory _$$SyncPolicy_StaleWhileRevalidateImplCopyWith(
+    _$SyncPolicy_StaleWhileRevalidateImpl value,
+    $Res Function(_$SyncPolicy_StaleWhileRevalidateImpl) then,
+  ) = __$$SyncPolicy_StaleWhileRevalidateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt maxAgeSecs});
+}
+
+/// @nodoc
+class __$$SyncPolicy_StaleWhileRevalidateImplCopyWithImpl<$Res>
+    extends _$SyncPolicyCopyWithImpl<$Res, _$SyncPolicy_StaleWhileRevalidateImpl>
+    implements _$$SyncPolicy_StaleWhileRevalidateImplCopyWith<$Res> {
+  __$$SyncPolicy_StaleWhileRevalidateImplCopyWithImpl(
+    _$SyncPolicy_StaleWhileRevalidateImpl _value,
+    $Res Function(_$SyncPolicy_StaleWhileRevalidateImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? maxAgeSecs = null}) {
+    return _then(
+      _$SyncPolicy_StaleWhileRevalidateImpl(
+        maxAgeSecs:
+            null == maxAgeSecs
+                ? _value.maxAgeSecs
+                : maxAgeSecs // ignore: cast_nullable_to_non_nullable
+                    as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SyncPolicy_StaleWhileRevalidateImpl extends SyncPolicy_StaleWhileRevalidate {
+  const _$SyncPolicy_StaleWhileRevalidateImpl({required this.maxAgeSecs}) : super._();
+
+  @override
+  final BigInt maxAgeSecs;
+
+  @override
+  String toString() {
+    return 'SyncPolicy.staleWhileRevalidate(maxAgeSecs: $maxAgeSecs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncPolicy_StaleWhileRevalidateImpl &&
+            (identical(other.maxAgeSecs, maxAgeSecs) || other.maxAgeSecs == maxAgeSecs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, maxAgeSecs);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncPolicy_StaleWhileRevalidateImplCopyWith<_$SyncPolicy_StaleWhileRevalidateImpl>
+  get copyWith =>
+      __$$SyncPolicy_StaleWhileRevalidateImplCopyWithImpl<_$SyncPolicy_StaleWhileRevalidateImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() cacheOnly,
+    required TResult Function(BigInt maxAgeSecs) staleWhileRevalidate,
+    required TResult Function() forceRefresh,
+    required TResult Function(BigInt timeoutMs) refreshWithTimeout,
+  }) {
+    return staleWhileRevalidate(maxAgeSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? cacheOnly,
+    TResult? Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult? Function()? forceRefresh,
+    TResult? Function(BigInt timeoutMs)? refreshWithTimeout,
+  }) {
+    return staleWhileRevalidate?.call(maxAgeSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? cacheOnly,
+    TResult Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult Function()? forceRefresh,
+    TResult Function(BigInt timeoutMs)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (staleWhileRevalidate != null) {
+      return staleWhileRevalidate(maxAgeSecs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncPolicy_CacheOnly value) cacheOnly,
+    required TResult Function(SyncPolicy_StaleWhileRevalidate value) staleWhileRevalidate,
+    required TResult Function(SyncPolicy_ForceRefresh value) forceRefresh,
+    required TResult Function(SyncPolicy_RefreshWithTimeout value) refreshWithTimeout,
+  }) {
+    return staleWhileRevalidate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult? Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult? Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult? Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+  }) {
+    return staleWhileRevalidate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (staleWhileRevalidate != null) {
+      return staleWhileRevalidate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncPolicy_StaleWhileRevalidate extends SyncPolicy {
+  const factory SyncPolicy_StaleWhileRevalidate({
+    required final BigInt maxAgeSecs,
+  }) = _$SyncPolicy_StaleWhileRevalidateImpl;
+  const SyncPolicy_StaleWhileRevalidate._() : super._();
+
+  BigInt get maxAgeSecs;
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncPolicy_StaleWhileRevalidateImplCopyWith<_$SyncPolicy_StaleWhileRevalidateImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncPolicy_ForceRefreshImplCopyWith<$Res> {
+  factory _$$SyncPolicy_ForceRefreshImplCopyWith(
+    _$SyncPolicy_ForceRefreshImpl value,
+    $Res Function(_$SyncPolicy_ForceRefreshImpl) then,
+  ) = __$$SyncPolicy_ForceRefreshImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SyncPolicy_ForceRefreshImplCopyWithImpl<$Res>
+    extends _$SyncPolicyCopyWithImpl<$Res, _$SyncPolicy_ForceRefreshImpl>
+    implements _$$SyncPolicy_ForceRefreshImplCopyWith<$Res> {
+  __$$SyncPolicy_ForceRefreshImplCopyWithImpl(
+    _$SyncPolicy_ForceRefreshImpl _value,
+    $Res Function(_$SyncPolicy_ForceRefreshImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SyncPolicy_ForceRefreshImpl extends SyncPolicy_ForceRefresh {
+  const _$SyncPolicy_ForceRefreshImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SyncPolicy.forceRefresh()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$SyncPolicy_ForceRefreshImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() cacheOnly,
+    required TResult Function(BigInt maxAgeSecs) staleWhileRevalidate,
+    required TResult Function() forceRefresh,
+    required TResult Function(BigInt timeoutMs) refreshWithTimeout,
+  }) {
+    return forceRefresh();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? cacheOnly,
+    TResult? Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult? Function()? forceRefresh,
+    TResult? Function(BigInt timeoutMs)? refreshWithTimeout,
+  }) {
+    return forceRefresh?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? cacheOnly,
+    TResult Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult Function()? forceRefresh,
+    TResult Function(BigInt timeoutMs)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (forceRefresh != null) {
+      return forceRefresh();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncPolicy_CacheOnly value) cacheOnly,
+    required TResult Function(SyncPolicy_StaleWhileRevalidate value) staleWhileRevalidate,
+    required TResult Function(SyncPolicy_ForceRefresh value) forceRefresh,
+    required TResult Function(SyncPolicy_RefreshWithTimeout value) refreshWithTimeout,
+  }) {
+    return forceRefresh(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult? Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult? Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult? Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+  }) {
+    return forceRefresh?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (forceRefresh != null) {
+      return forceRefresh(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncPolicy_ForceRefresh extends SyncPolicy {
+  const factory SyncPolicy_ForceRefresh() = _$SyncPolicy_ForceRefreshImpl;
+  const SyncPolicy_ForceRefresh._() : super._();
+}
+
+/// @nodoc
+abstract class _$$SyncPolicy_RefreshWithTimeoutImplCopyWith<$Res> {
+  factory _$$SyncPolicy_RefreshWithTimeoutImplCopyWith(
+    _$SyncPolicy_RefreshWithTimeoutImpl value,
+    $Res Function(_$SyncPolicy_RefreshWithTimeoutImpl) then,
+  ) = __$$SyncPolicy_RefreshWithTimeoutImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt timeoutMs});
+}
+
+/// @nodoc
+class __$$SyncPolicy_RefreshWithTimeoutImplCopyWithImpl<$Res>
+    extends _$SyncPolicyCopyWithImpl<$Res, _$SyncPolicy_RefreshWithTimeoutImpl>
+    implements _$$SyncPolicy_RefreshWithTimeoutImplCopyWith<$Res> {
+  __$$SyncPolicy_RefreshWithTimeoutImplCopyWithImpl(
+    _$SyncPolicy_RefreshWithTimeoutImpl _value,
+    $Res Function(_$SyncPolicy_RefreshWithTimeoutImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? timeoutMs = null}) {
+    return _then(
+      _$SyncPolicy_RefreshWithTimeoutImpl(
+        timeoutMs:
+            null == timeoutMs
+                ? _value.timeoutMs
+                : timeoutMs // ignore: cast_nullable_to_non_nullable
+                    as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SyncPolicy_RefreshWithTimeoutImpl extends SyncPolicy_RefreshWithTimeout {
+  const _$SyncPolicy_RefreshWithTimeoutImpl({required this.timeoutMs}) : super._();
+
+  @override
+  final BigInt timeoutMs;
+
+  @override
+  String toString() {
+    return 'SyncPolicy.refreshWithTimeout(timeoutMs: $timeoutMs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncPolicy_RefreshWithTimeoutImpl &&
+            (identical(other.timeoutMs, timeoutMs) || other.timeoutMs == timeoutMs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, timeoutMs);
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncPolicy_RefreshWithTimeoutImplCopyWith<_$SyncPolicy_RefreshWithTimeoutImpl> get copyWith =>
+      __$$SyncPolicy_RefreshWithTimeoutImplCopyWithImpl<_$SyncPolicy_RefreshWithTimeoutImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() cacheOnly,
+    required TResult Function(BigInt maxAgeSecs) staleWhileRevalidate,
+    required TResult Function() forceRefresh,
+    required TResult Function(BigInt timeoutMs) refreshWithTimeout,
+  }) {
+    return refreshWithTimeout(timeoutMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? cacheOnly,
+    TResult? Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult? Function()? forceRefresh,
+    TResult? Function(BigInt timeoutMs)? refreshWithTimeout,
+  }) {
+    return refreshWithTimeout?.call(timeoutMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? cacheOnly,
+    TResult Function(BigInt maxAgeSecs)? staleWhileRevalidate,
+    TResult Function()? forceRefresh,
+    TResult Function(BigInt timeoutMs)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (refreshWithTimeout != null) {
+      return refreshWithTimeout(timeoutMs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncPolicy_CacheOnly value) cacheOnly,
+    required TResult Function(SyncPolicy_StaleWhileRevalidate value) staleWhileRevalidate,
+    required TResult Function(SyncPolicy_ForceRefresh value) forceRefresh,
+    required TResult Function(SyncPolicy_RefreshWithTimeout value) refreshWithTimeout,
+  }) {
+    return refreshWithTimeout(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult? Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult? Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult? Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+  }) {
+    return refreshWithTimeout?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncPolicy_CacheOnly value)? cacheOnly,
+    TResult Function(SyncPolicy_StaleWhileRevalidate value)? staleWhileRevalidate,
+    TResult Function(SyncPolicy_ForceRefresh value)? forceRefresh,
+    TResult Function(SyncPolicy_RefreshWithTimeout value)? refreshWithTimeout,
+    required TResult orElse(),
+  }) {
+    if (refreshWithTimeout != null) {
+      return refreshWithTimeout(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncPolicy_RefreshWithTimeout extends SyncPolicy {
+  const factory SyncPolicy_RefreshWithTimeout({
+    required final BigInt timeoutMs,
+  }) = _$SyncPolicy_RefreshWithTimeoutImpl;
+  const SyncPolicy_RefreshWithTimeout._() : super._();
+
+  BigInt get timeoutMs;
+
+  /// Create a copy of SyncPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncPolicy_RefreshWithTimeoutImplCopyWith<_$SyncPolicy_RefreshWithTimeoutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -8,6 +8,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import '../frb_generated.dart';
 import 'error.dart';
 import 'messages.dart';
+import 'relays.dart';
+
+// These functions are ignored because they are not marked as `pub`: `parse_pubkey_with_hints`
 
 String npubFromHexPubkey({required String hexPubkey}) =>
     RustLib.instance.api.crateApiUtilsNpubFromHexPubkey(hexPubkey: hexPubkey);
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'relays.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_key_changes`, `compute_fingerprint`, `digest_digits`, `leaf_digest`, `record_fingerprint`, `safety_number`, `spawn_key_change_monitor`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Returns the safety number of a group member and their persisted verification state.
+///
+/// If the member's fingerprint in this group changed since it was last seen, the stored
+/// flag is reset and the member is reported as [`VerificationState::KeyChanged`] until
+/// verified again.
+Future<MemberFingerprint> memberFingerprint({
+  required String accountPubkey,
+  required String groupId,
+  required String memberPubkey,
+}) => RustLib.instance.api.crateApiVerificationMemberFingerprint(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  memberPubkey: memberPubkey,
+);
+
+/// Marks a contact as verified or unverified against their current fingerprint in the
+/// group.
+///
+/// Clears any pending key-change flag for the contact.
+Future<void> setMemberVerified({
+  required String accountPubkey,
+  required String groupId,
+  required String memberPubkey,
+  required bool verified,
+}) => RustLib.instance.api.crateApiVerificationSetMemberVerified(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  memberPubkey: memberPubkey,
+  verified: verified,
+);
+
+/// Checks a scanned QR payload against the member's current fingerprint.
+///
+/// Returns `true` and marks the member as verified when the payload matches.
+Future<bool> verifyMemberQrPayload({
+  required String accountPubkey,
+  required String groupId,
+  required String memberPubkey,
+  required String scannedPayload,
+}) => RustLib.instance.api.crateApiVerificationVerifyMemberQrPayload(
+  accountPubkey: accountPubkey,
+  groupId: groupId,
+  memberPubkey: memberPubkey,
+  scannedPayload: scannedPayload,
+);
+
+class MemberFingerprint {
+  final String memberPubkey;
+
+  /// Hex digest of the member's leaf credential and signature key
+  final String fingerprint;
+
+  /// 60 digits in groups of five, identical on both members' devices
+  final String safetyNumber;
+
+  /// Payload to render as a QR code and scan from the other member's device
+  final String qrPayload;
+  final VerificationState verificationState;
+  final DateTime? verifiedAt;
+
+  const MemberFingerprint({
+    required this.memberPubkey,
+    required this.fingerprint,
+    required this.safetyNumber,
+    required this.qrPayload,
+    required this.verificationState,
+    this.verifiedAt,
+  });
+
+  @override
+  int get hashCode =>
+      memberPubkey.hashCode ^
+      fingerprint.hashCode ^
+      safetyNumber.hashCode ^
+      qrPayload.hashCode ^
+      verificationState.hashCode ^
+      verifiedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemberFingerprint &&
+          runtimeType == other.runtimeType &&
+          memberPubkey == other.memberPubkey &&
+          fingerprint == other.fingerprint &&
+          safetyNumber == other.safetyNumber &&
+          qrPayload == other.qrPayload &&
+          verificationState == other.verificationState &&
+          verifiedAt == other.verifiedAt;
+}
+
+enum VerificationState {
+  unverified,
+  verified,
+  keyChanged,
+}
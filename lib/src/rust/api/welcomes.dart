@@ -7,12 +7,32 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import '../frb_generated.dart';
 import 'error.dart';
+import 'groups.dart';
+import 'metadata.dart';
+import 'relays.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`, `from`, `from`
+// These functions are ignored because they are not marked as `pub`: `auto_accept_welcomes`, `batch_welcome_action`, `is_direct_message_welcome`, `partition_pending_welcomes`, `should_auto_accept`, `spawn_welcome_auto_accept`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
 
+Future<WelcomePreview> welcomePreview({
+  required String pubkey,
+  required String welcomeEventId,
+}) => RustLib.instance.api.crateApiWelcomesWelcomePreview(
+  pubkey: pubkey,
+  welcomeEventId: welcomeEventId,
+);
+
+/// Returns pending welcomes that match the account's welcome policy.
+///
+/// Welcomes that don't match are only available through [`welcome_requests`], which
+/// also keeps them out of notifications built from this list.
 Future<List<Welcome>> pendingWelcomes({required String pubkey}) =>
     RustLib.instance.api.crateApiWelcomesPendingWelcomes(pubkey: pubkey);
 
+/// Returns pending welcomes that don't match the account's welcome policy.
+Future<List<Welcome>> welcomeRequests({required String pubkey}) =>
+    RustLib.instance.api.crateApiWelcomesWelcomeRequests(pubkey: pubkey);
+
 Future<Welcome> findWelcomeByEventId({
   required String pubkey,
   required String welcomeEventId,
@@ -37,6 +57,91 @@ Future<void> declineWelcome({
   welcomeEventId: welcomeEventId,
 );
 
+/// Returns all welcomes of the account, newest first, optionally limited to one state.
+Future<List<Welcome>> allWelcomes({
+  required String pubkey,
+  WelcomeState? stateFilter,
+}) => RustLib.instance.api.crateApiWelcomesAllWelcomes(
+  pubkey: pubkey,
+  stateFilter: stateFilter,
+);
+
+/// Hides a welcome without declining it, so no leave is published to the group.
+Future<void> ignoreWelcome({
+  required String pubkey,
+  required String welcomeEventId,
+}) => RustLib.instance.api.crateApiWelcomesIgnoreWelcome(
+  pubkey: pubkey,
+  welcomeEventId: welcomeEventId,
+);
+
+/// Blocks a welcomer for the account.
+///
+/// Every pending welcome from `welcomer_pubkey` is declined, and welcomes received from
+/// them later are ignored as soon as they are processed. A welcome that fails to
+/// decline does not stop the others.
+Future<BlockWelcomerResult> blockWelcomer({
+  required String pubkey,
+  required String welcomerPubkey,
+}) => RustLib.instance.api.crateApiWelcomesBlockWelcomer(
+  pubkey: pubkey,
+  welcomerPubkey: welcomerPubkey,
+);
+
+Future<void> unblockWelcomer({
+  required String pubkey,
+  required String welcomerPubkey,
+}) => RustLib.instance.api.crateApiWelcomesUnblockWelcomer(
+  pubkey: pubkey,
+  welcomerPubkey: welcomerPubkey,
+);
+
+Future<List<String>> blockedWelcomers({required String pubkey}) =>
+    RustLib.instance.api.crateApiWelcomesBlockedWelcomers(pubkey: pubkey);
+
+/// Accepts several welcomes at once; a failure for one id does not stop the others.
+Future<List<WelcomeActionResult>> acceptWelcomes({
+  required String pubkey,
+  required List<String> welcomeEventIds,
+}) => RustLib.instance.api.crateApiWelcomesAcceptWelcomes(
+  pubkey: pubkey,
+  welcomeEventIds: welcomeEventIds,
+);
+
+/// Declines several welcomes at once; a failure for one id does not stop the others.
+Future<List<WelcomeActionResult>> declineWelcomes({
+  required String pubkey,
+  required List<String> welcomeEventIds,
+}) => RustLib.instance.api.crateApiWelcomesDeclineWelcomes(
+  pubkey: pubkey,
+  welcomeEventIds: welcomeEventIds,
+);
+
+/// Outcome of [`block_welcomer`]
+class BlockWelcomerResult {
+  /// Number of pending welcomes from the welcomer that were declined
+  final int declined;
+
+  /// Pending welcomes that could not be declined and stay pending, with their errors
+  final List<WelcomeActionResult> failed;
+
+  const BlockWelcomerResult({
+    required this.declined,
+    required this.failed,
+  });
+
+  @override
+  int get hashCode => declined.hashCode ^ failed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockWelcomerResult &&
+          runtimeType == other.runtimeType &&
+          declined == other.declined &&
+          failed == other.failed;
+}
+
 /// Converts a GroupId to a hex string representation.
 ///
 /// This function provides a consistent way to convert MLS group IDs to strings
@@ -106,6 +211,112 @@ class Welcome {
           createdAt == other.createdAt;
 }
 
+/// Outcome of a batch welcome action for a single welcome
+class WelcomeActionResult {
+  final String welcomeEventId;
+  final bool success;
+  final String? errorMessage;
+
+  const WelcomeActionResult({
+    required this.welcomeEventId,
+    required this.success,
+    this.errorMessage,
+  });
+
+  @override
+  int get hashCode => welcomeEventId.hashCode ^ success.hashCode ^ errorMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomeActionResult &&
+          runtimeType == other.runtimeType &&
+          welcomeEventId == other.welcomeEventId &&
+          success == other.success &&
+          errorMessage == other.errorMessage;
+}
+
+/// Anti-spam policy for incoming welcomes, stored in the app settings
+class WelcomePolicy {
+  final WelcomeSenderPolicy senderPolicy;
+
+  /// Welcomes to groups with more members than this go to the requests bucket
+  final int? maxMemberCount;
+
+  /// Accept welcomes from pubkeys the account follows as soon as they are processed
+  final bool autoAcceptFromFollows;
+
+  const WelcomePolicy({
+    required this.senderPolicy,
+    this.maxMemberCount,
+    required this.autoAcceptFromFollows,
+  });
+
+  @override
+  int get hashCode =>
+      senderPolicy.hashCode ^ maxMemberCount.hashCode ^ autoAcceptFromFollows.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomePolicy &&
+          runtimeType == other.runtimeType &&
+          senderPolicy == other.senderPolicy &&
+          maxMemberCount == other.maxMemberCount &&
+          autoAcceptFromFollows == other.autoAcceptFromFollows;
+}
+
+/// Everything the invite screen shows before the user accepts or declines a welcome.
+class WelcomePreview {
+  final Welcome welcome;
+  final FlutterMetadata welcomerMetadata;
+  final bool isFollowingWelcomer;
+
+  /// Local path of the decrypted group image, if the group has one
+  final String? groupImagePath;
+
+  /// Members listed in the ratchet tree carried by the welcome
+  final List<String> memberPubkeys;
+  final GroupType groupType;
+
+  const WelcomePreview({
+    required this.welcome,
+    required this.welcomerMetadata,
+    required this.isFollowingWelcomer,
+    this.groupImagePath,
+    required this.memberPubkeys,
+    required this.groupType,
+  });
+
+  @override
+  int get hashCode =>
+      welcome.hashCode ^
+      welcomerMetadata.hashCode ^
+      isFollowingWelcomer.hashCode ^
+      groupImagePath.hashCode ^
+      memberPubkeys.hashCode ^
+      groupType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomePreview &&
+          runtimeType == other.runtimeType &&
+          welcome == other.welcome &&
+          welcomerMetadata == other.welcomerMetadata &&
+          isFollowingWelcomer == other.isFollowingWelcomer &&
+          groupImagePath == other.groupImagePath &&
+          memberPubkeys == other.memberPubkeys &&
+          groupType == other.groupType;
+}
+
+/// Who may add the account to a group without the welcome landing in the requests bucket
+enum WelcomeSenderPolicy {
+  anyone,
+  follows,
+  followsOfFollows,
+}
+
 enum WelcomeState {
   pending,
   accepted,
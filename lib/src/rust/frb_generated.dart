@@ -12,12 +12,16 @@ import 'api.dart';
 import 'api/accounts.dart';
 import 'api/error.dart';
 import 'api/groups.dart';
+import 'api/invites.dart';
 import 'api/media_files.dart';
 import 'api/messages.dart';
 import 'api/metadata.dart';
+import 'api/network.dart';
+import 'api/proxy.dart';
 import 'api/relays.dart';
 import 'api/users.dart';
 import 'api/utils.dart';
+import 'api/verification.dart';
 import 'api/welcomes.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
@@ -78,7 +82,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -481785978;
+  int get rustContentHash => -1718174739;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'rust_lib_whitenoise',
@@ -93,6 +97,11 @@ abstract class RustLibApi extends BaseApi {
     required String welcomeEventId,
   });
 
+  Future<List<WelcomeActionResult>> crateApiWelcomesAcceptWelcomes({
+    required String pubkey,
+    required List<String> welcomeEventIds,
+  });
+
   Future<List<User>> crateApiAccountsAccountFollows({required String pubkey});
 
   Future<FlutterEvent?> crateApiAccountsAccountKeyPackage({
@@ -107,9 +116,14 @@ abstract class RustLibApi extends BaseApi {
     required String pubkey,
   });
 
+  Future<List<RelayHealth>> crateApiRelaysAccountRelayHealth({
+    required String pubkey,
+  });
+
   Future<List<Relay>> crateApiAccountsAccountRelays({
     required String pubkey,
     required RelayType relayType,
+    required bool includeInfo,
   });
 
   Future<List<Group>> crateApiGroupsActiveGroups({required String pubkey});
@@ -126,10 +140,30 @@ abstract class RustLibApi extends BaseApi {
     required List<String> memberPubkeys,
   });
 
+  Future<List<Welcome>> crateApiWelcomesAllWelcomes({
+    required String pubkey,
+    WelcomeState? stateFilter,
+  });
+
   Future<String> crateApiErrorApiErrorErrorType({required ApiError that});
 
   Future<String> crateApiErrorApiErrorMessageText({required ApiError that});
 
+  Future<void> crateApiInvitesApproveJoinRequest({
+    required String accountPubkey,
+    required String groupId,
+    required String requestId,
+  });
+
+  Future<BlockWelcomerResult> crateApiWelcomesBlockWelcomer({
+    required String pubkey,
+    required String welcomerPubkey,
+  });
+
+  Future<List<String>> crateApiWelcomesBlockedWelcomers({
+    required String pubkey,
+  });
+
   Future<Group> crateApiGroupsCreateGroup({
     required String creatorPubkey,
     required List<String> memberPubkeys,
@@ -141,9 +175,23 @@ abstract class RustLibApi extends BaseApi {
 
   Future<Account> crateApiAccountsCreateIdentity();
 
+  Future<InviteLink> crateApiInvitesCreateInviteLink({
+    required String accountPubkey,
+    required String groupId,
+    DateTime? expiresAt,
+    int? maxUses,
+  });
+
   Future<WhitenoiseConfig> crateApiCreateWhitenoiseConfig({
     required String dataDir,
     required String logsDir,
+    String? proxyUrl,
+  });
+
+  Future<void> crateApiInvitesDeclineJoinRequest({
+    required String accountPubkey,
+    required String groupId,
+    required String requestId,
   });
 
   Future<void> crateApiWelcomesDeclineWelcome({
@@ -151,6 +199,11 @@ abstract class RustLibApi extends BaseApi {
     required String welcomeEventId,
   });
 
+  Future<List<WelcomeActionResult>> crateApiWelcomesDeclineWelcomes({
+    required String pubkey,
+    required List<String> welcomeEventIds,
+  });
+
   Future<bool> crateApiAccountsDeleteAccountKeyPackage({
     required String accountPubkey,
     required String keyPackageId,
@@ -166,6 +219,7 @@ abstract class RustLibApi extends BaseApi {
     required String accountPubkey,
     required String groupId,
     required String originalFileHash,
+    required bool userInitiated,
   });
 
   Future<void> crateApiRelaysEnsureAllSubscriptions();
@@ -177,6 +231,11 @@ abstract class RustLibApi extends BaseApi {
     required String groupId,
   });
 
+  Future<Group> crateApiGroupsFindOrCreateDm({
+    required String accountPubkey,
+    required String peerPubkey,
+  });
+
   Future<Welcome> crateApiWelcomesFindWelcomeByEventId({
     required String pubkey,
     required String welcomeEventId,
@@ -189,7 +248,7 @@ abstract class RustLibApi extends BaseApi {
 
   Future<Account> crateApiAccountsGetAccount({required String pubkey});
 
-  Future<List<(String, String)>> crateApiRelaysGetAccountRelayStatuses({
+  Future<List<RelayConnectionInfo>> crateApiRelaysGetAccountRelayStatuses({
     required String pubkey,
   });
 
@@ -219,11 +278,20 @@ abstract class RustLibApi extends BaseApi {
     required List<String> groupIds,
   });
 
+  Future<ProxySettings?> crateApiProxyGetProxySettings();
+
   Future<User> crateApiUsersGetUser({
     required String pubkey,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
+  });
+
+  Future<List<User>> crateApiUsersGetUsers({
+    required List<String> pubkeys,
+    required SyncPolicy syncPolicy,
   });
 
+  Future<WelcomePolicy> crateApiGetWelcomePolicy();
+
   Future<List<String>> crateApiGroupsGroupAdmins({
     required String pubkey,
     required String groupId,
@@ -238,6 +306,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiUtilsGroupIdToString({required GroupId groupId});
 
+  bool crateApiGroupsGroupIsAdmin({
+    required Group that,
+    required String pubkey,
+  });
+
   Future<bool> crateApiGroupsGroupIsDirectMessageType({
     required Group that,
     required String accountPubkey,
@@ -248,11 +321,23 @@ abstract class RustLibApi extends BaseApi {
     required String accountPubkey,
   });
 
+  Future<List<GroupMember>> crateApiGroupsGroupMemberDetails({
+    required String pubkey,
+    required String groupId,
+  });
+
   Future<List<String>> crateApiGroupsGroupMembers({
     required String pubkey,
     required String groupId,
   });
 
+  Future<GroupPermissions> crateApiGroupsGroupPermissionsDefault();
+
+  Future<GroupRelayHealth> crateApiGroupsGroupRelayHealth({
+    required String accountPubkey,
+    required String groupId,
+  });
+
   Future<void> crateApiGroupsGroupUpdateGroupData({
     required Group that,
     required String accountPubkey,
@@ -261,14 +346,46 @@ abstract class RustLibApi extends BaseApi {
 
   String crateApiUtilsHexPubkeyFromNpub({required String npub});
 
+  Future<void> crateApiWelcomesIgnoreWelcome({
+    required String pubkey,
+    required String welcomeEventId,
+  });
+
   Future<void> crateApiInitializeWhitenoise({required WhitenoiseConfig config});
 
+  Future<bool> crateApiNetworkIsLowBandwidthMode();
+
+  Future<bool> crateApiNetworkIsNetworkPaused();
+
   Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey});
 
   Future<void> crateApiAccountsLogout({required String pubkey});
 
+  Future<MemberFingerprint> crateApiVerificationMemberFingerprint({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+  });
+
+  Future<void> crateApiMessagesModerateDeleteMessage({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+  });
+
   String crateApiUtilsNpubFromHexPubkey({required String hexPubkey});
 
+  Future<void> crateApiNetworkPauseNetwork();
+
+  Future<List<JoinRequest>> crateApiInvitesPendingJoinRequests({
+    required String accountPubkey,
+    required String groupId,
+  });
+
+  Future<List<String>> crateApiRelaysPendingRelayAuthRequests({
+    required String pubkey,
+  });
+
   Future<List<Welcome>> crateApiWelcomesPendingWelcomes({
     required String pubkey,
   });
@@ -277,6 +394,17 @@ abstract class RustLibApi extends BaseApi {
     required String accountPubkey,
   });
 
+  Future<BigInt> crateApiMessagesPurgeExpiredMessages({required String pubkey});
+
+  Future<RelayAuthPolicy> crateApiRelaysRelayAuthPolicy({
+    required String pubkey,
+    required String url,
+  });
+
+  Future<RelayHealth> crateApiRelaysRelayHealth({required String url});
+
+  Future<RelayInfo> crateApiRelaysRelayInfo({required String url});
+
   Future<RelayType> crateApiRelaysRelayTypeInbox();
 
   Future<RelayType> crateApiRelaysRelayTypeKeyPackage();
@@ -295,6 +423,27 @@ abstract class RustLibApi extends BaseApi {
     required String pubkey,
     required String groupId,
     required List<String> memberPubkeys,
+    String? reason,
+  });
+
+  Future<void> crateApiInvitesRequestJoin({
+    required String accountPubkey,
+    required String invite,
+  });
+
+  Future<void> crateApiRelaysRespondToRelayAuth({
+    required String pubkey,
+    required String url,
+    required bool approve,
+    required bool remember,
+  });
+
+  Future<void> crateApiNetworkResumeNetwork();
+
+  Future<List<UserSearchResult>> crateApiUsersSearchUsers({
+    required String accountPubkey,
+    required String query,
+    required bool searchRelays,
   });
 
   Future<MessageWithTokens> crateApiMessagesSendMessageToGroup({
@@ -305,10 +454,34 @@ abstract class RustLibApi extends BaseApi {
     List<Tag>? tags,
   });
 
+  Future<RelayListChange> crateApiAccountsSetAccountRelays({
+    required String pubkey,
+    required RelayType relayType,
+    required List<String> urls,
+  });
+
+  Future<void> crateApiVerificationSetMemberVerified({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+    required bool verified,
+  });
+
+  Future<void> crateApiRelaysSetRelayAuthPolicy({
+    required String pubkey,
+    required String url,
+    required RelayAuthPolicy policy,
+  });
+
   Future<String> crateApiUtilsStringFromRelayUrl({required RelayUrl relayUrl});
 
   Future<Tag> crateApiUtilsTagFromVec({required List<String> vec});
 
+  Future<void> crateApiWelcomesUnblockWelcomer({
+    required String pubkey,
+    required String welcomerPubkey,
+  });
+
   Future<void> crateApiAccountsUnfollowUser({
     required String accountPubkey,
     required String userToUnfollowPubkey,
@@ -319,8 +492,16 @@ abstract class RustLibApi extends BaseApi {
     required FlutterMetadata metadata,
   });
 
+  Future<void> crateApiNetworkUpdateLowBandwidthMode({required bool enabled});
+
+  Future<void> crateApiProxyUpdateProxySettings({ProxySettings? proxySettings});
+
   Future<void> crateApiUpdateThemeMode({required ThemeMode themeMode});
 
+  Future<void> crateApiUpdateWelcomePolicy({
+    required WelcomePolicy welcomePolicy,
+  });
+
   Future<String> crateApiAccountsUploadAccountProfilePicture({
     required String pubkey,
     required String serverUrl,
@@ -343,18 +524,51 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiUsersUserHasKeyPackage({
     required String pubkey,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
   });
 
   Future<FlutterMetadata> crateApiUsersUserMetadata({
     required String pubkey,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
   });
 
   Future<List<Relay>> crateApiUsersUserRelays({
     required String pubkey,
     required RelayType relayType,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
+  });
+
+  Future<String> crateApiRelaysValidateRelayUrl({
+    required String pubkey,
+    required String url,
+    required RelayType relayType,
+  });
+
+  Future<bool> crateApiVerificationVerifyMemberQrPayload({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+    required String scannedPayload,
+  });
+
+  Future<User> crateApiUsersVerifyUserNip05({required String pubkey});
+
+  Stream<GroupRelayAlert> crateApiGroupsWatchGroupRelayAlerts({
+    required String accountPubkey,
+  });
+
+  Stream<User> crateApiUsersWatchUsers({
+    required List<String> pubkeys,
+    required SyncPolicy syncPolicy,
+  });
+
+  Future<WelcomePreview> crateApiWelcomesWelcomePreview({
+    required String pubkey,
+    required String welcomeEventId,
+  });
+
+  Future<List<Welcome>> crateApiWelcomesWelcomeRequests({
+    required String pubkey,
   });
 
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AppSettings;
@@ -437,12 +651,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<List<User>> crateApiAccountsAccountFollows({required String pubkey}) {
+  Future<List<WelcomeActionResult>> crateApiWelcomesAcceptWelcomes({
+    required String pubkey,
+    required List<String> welcomeEventIds,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
+          sse_encode_list_String(welcomeEventIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -450,6 +668,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome_action_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesAcceptWelcomesConstMeta,
+        argValues: [pubkey, welcomeEventIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesAcceptWelcomesConstMeta => const TaskConstMeta(
+    debugName: 'accept_welcomes',
+    argNames: ['pubkey', 'welcomeEventIds'],
+  );
+
+  @override
+  Future<List<User>> crateApiAccountsAccountFollows({required String pubkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_user,
           decodeErrorData: sse_decode_api_error,
@@ -478,7 +726,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 4,
             port: port_,
           );
         },
@@ -510,7 +758,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 5,
             port: port_,
           );
         },
@@ -542,7 +790,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 6,
             port: port_,
           );
         },
@@ -562,10 +810,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey'],
   );
 
+  @override
+  Future<List<RelayHealth>> crateApiRelaysAccountRelayHealth({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_relay_health,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysAccountRelayHealthConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysAccountRelayHealthConstMeta => const TaskConstMeta(
+    debugName: 'account_relay_health',
+    argNames: ['pubkey'],
+  );
+
   @override
   Future<List<Relay>> crateApiAccountsAccountRelays({
     required String pubkey,
     required RelayType relayType,
+    required bool includeInfo,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -576,10 +857,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             relayType,
             serializer,
           );
+          sse_encode_bool(includeInfo, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 8,
             port: port_,
           );
         },
@@ -588,7 +870,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiAccountsAccountRelaysConstMeta,
-        argValues: [pubkey, relayType],
+        argValues: [pubkey, relayType, includeInfo],
         apiImpl: this,
       ),
     );
@@ -596,7 +878,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiAccountsAccountRelaysConstMeta => const TaskConstMeta(
     debugName: 'account_relays',
-    argNames: ['pubkey', 'relayType'],
+    argNames: ['pubkey', 'relayType', 'includeInfo'],
   );
 
   @override
@@ -609,7 +891,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 9,
             port: port_,
           );
         },
@@ -648,7 +930,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 10,
             port: port_,
           );
         },
@@ -684,7 +966,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 11,
             port: port_,
           );
         },
@@ -704,6 +986,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'groupId', 'memberPubkeys'],
   );
 
+  @override
+  Future<List<Welcome>> crateApiWelcomesAllWelcomes({
+    required String pubkey,
+    WelcomeState? stateFilter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_opt_box_autoadd_welcome_state(stateFilter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesAllWelcomesConstMeta,
+        argValues: [pubkey, stateFilter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesAllWelcomesConstMeta => const TaskConstMeta(
+    debugName: 'all_welcomes',
+    argNames: ['pubkey', 'stateFilter'],
+  );
+
   @override
   Future<String> crateApiErrorApiErrorErrorType({required ApiError that}) {
     return handler.executeNormal(
@@ -714,7 +1030,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 13,
             port: port_,
           );
         },
@@ -744,7 +1060,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 14,
             port: port_,
           );
         },
@@ -765,41 +1081,143 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<Group> crateApiGroupsCreateGroup({
-    required String creatorPubkey,
-    required List<String> memberPubkeys,
-    required List<String> adminPubkeys,
-    required String groupName,
-    required String groupDescription,
-    required GroupType groupType,
+  Future<void> crateApiInvitesApproveJoinRequest({
+    required String accountPubkey,
+    required String groupId,
+    required String requestId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(creatorPubkey, serializer);
-          sse_encode_list_String(memberPubkeys, serializer);
-          sse_encode_list_String(adminPubkeys, serializer);
-          sse_encode_String(groupName, serializer);
-          sse_encode_String(groupDescription, serializer);
-          sse_encode_group_type(groupType, serializer);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(requestId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 15,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_group,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiGroupsCreateGroupConstMeta,
-        argValues: [
-          creatorPubkey,
-          memberPubkeys,
-          adminPubkeys,
-          groupName,
+        constMeta: kCrateApiInvitesApproveJoinRequestConstMeta,
+        argValues: [accountPubkey, groupId, requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInvitesApproveJoinRequestConstMeta => const TaskConstMeta(
+    debugName: 'approve_join_request',
+    argNames: ['accountPubkey', 'groupId', 'requestId'],
+  );
+
+  @override
+  Future<BlockWelcomerResult> crateApiWelcomesBlockWelcomer({
+    required String pubkey,
+    required String welcomerPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(welcomerPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_block_welcomer_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesBlockWelcomerConstMeta,
+        argValues: [pubkey, welcomerPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesBlockWelcomerConstMeta => const TaskConstMeta(
+    debugName: 'block_welcomer',
+    argNames: ['pubkey', 'welcomerPubkey'],
+  );
+
+  @override
+  Future<List<String>> crateApiWelcomesBlockedWelcomers({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesBlockedWelcomersConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesBlockedWelcomersConstMeta => const TaskConstMeta(
+    debugName: 'blocked_welcomers',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<Group> crateApiGroupsCreateGroup({
+    required String creatorPubkey,
+    required List<String> memberPubkeys,
+    required List<String> adminPubkeys,
+    required String groupName,
+    required String groupDescription,
+    required GroupType groupType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(creatorPubkey, serializer);
+          sse_encode_list_String(memberPubkeys, serializer);
+          sse_encode_list_String(adminPubkeys, serializer);
+          sse_encode_String(groupName, serializer);
+          sse_encode_String(groupDescription, serializer);
+          sse_encode_group_type(groupType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiGroupsCreateGroupConstMeta,
+        argValues: [
+          creatorPubkey,
+          memberPubkeys,
+          adminPubkeys,
+          groupName,
           groupDescription,
           groupType,
         ],
@@ -829,7 +1247,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 19,
             port: port_,
           );
         },
@@ -849,10 +1267,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: [],
   );
 
+  @override
+  Future<InviteLink> crateApiInvitesCreateInviteLink({
+    required String accountPubkey,
+    required String groupId,
+    DateTime? expiresAt,
+    int? maxUses,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_opt_box_autoadd_Chrono_Utc(expiresAt, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxUses, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_invite_link,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiInvitesCreateInviteLinkConstMeta,
+        argValues: [accountPubkey, groupId, expiresAt, maxUses],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInvitesCreateInviteLinkConstMeta => const TaskConstMeta(
+    debugName: 'create_invite_link',
+    argNames: ['accountPubkey', 'groupId', 'expiresAt', 'maxUses'],
+  );
+
   @override
   Future<WhitenoiseConfig> crateApiCreateWhitenoiseConfig({
     required String dataDir,
     required String logsDir,
+    String? proxyUrl,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -860,10 +1317,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(dataDir, serializer);
           sse_encode_String(logsDir, serializer);
+          sse_encode_opt_String(proxyUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 21,
             port: port_,
           );
         },
@@ -872,7 +1330,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiCreateWhitenoiseConfigConstMeta,
-        argValues: [dataDir, logsDir],
+        argValues: [dataDir, logsDir, proxyUrl],
         apiImpl: this,
       ),
     );
@@ -880,7 +1338,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiCreateWhitenoiseConfigConstMeta => const TaskConstMeta(
     debugName: 'create_whitenoise_config',
-    argNames: ['dataDir', 'logsDir'],
+    argNames: ['dataDir', 'logsDir', 'proxyUrl'],
+  );
+
+  @override
+  Future<void> crateApiInvitesDeclineJoinRequest({
+    required String accountPubkey,
+    required String groupId,
+    required String requestId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(requestId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiInvitesDeclineJoinRequestConstMeta,
+        argValues: [accountPubkey, groupId, requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInvitesDeclineJoinRequestConstMeta => const TaskConstMeta(
+    debugName: 'decline_join_request',
+    argNames: ['accountPubkey', 'groupId', 'requestId'],
   );
 
   @override
@@ -897,7 +1391,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 23,
             port: port_,
           );
         },
@@ -917,6 +1411,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'welcomeEventId'],
   );
 
+  @override
+  Future<List<WelcomeActionResult>> crateApiWelcomesDeclineWelcomes({
+    required String pubkey,
+    required List<String> welcomeEventIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_list_String(welcomeEventIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome_action_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesDeclineWelcomesConstMeta,
+        argValues: [pubkey, welcomeEventIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesDeclineWelcomesConstMeta => const TaskConstMeta(
+    debugName: 'decline_welcomes',
+    argNames: ['pubkey', 'welcomeEventIds'],
+  );
+
   @override
   Future<bool> crateApiAccountsDeleteAccountKeyPackage({
     required String accountPubkey,
@@ -931,7 +1459,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 25,
             port: port_,
           );
         },
@@ -963,7 +1491,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 26,
             port: port_,
           );
         },
@@ -992,7 +1520,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 27,
             port: port_,
           );
         },
@@ -1017,6 +1545,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String accountPubkey,
     required String groupId,
     required String originalFileHash,
+    required bool userInitiated,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1025,10 +1554,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(accountPubkey, serializer);
           sse_encode_String(groupId, serializer);
           sse_encode_String(originalFileHash, serializer);
+          sse_encode_bool(userInitiated, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 28,
             port: port_,
           );
         },
@@ -1037,7 +1567,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiMediaFilesDownloadChatMediaConstMeta,
-        argValues: [accountPubkey, groupId, originalFileHash],
+        argValues: [accountPubkey, groupId, originalFileHash, userInitiated],
         apiImpl: this,
       ),
     );
@@ -1045,7 +1575,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiMediaFilesDownloadChatMediaConstMeta => const TaskConstMeta(
     debugName: 'download_chat_media',
-    argNames: ['accountPubkey', 'groupId', 'originalFileHash'],
+    argNames: ['accountPubkey', 'groupId', 'originalFileHash', 'userInitiated'],
   );
 
   @override
@@ -1057,7 +1587,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 29,
             port: port_,
           );
         },
@@ -1087,7 +1617,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 30,
             port: port_,
           );
         },
@@ -1121,7 +1651,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 31,
             port: port_,
           );
         },
@@ -1142,6 +1672,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['pubkey', 'groupId'],
       );
 
+  @override
+  Future<Group> crateApiGroupsFindOrCreateDm({
+    required String accountPubkey,
+    required String peerPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(peerPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiGroupsFindOrCreateDmConstMeta,
+        argValues: [accountPubkey, peerPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupsFindOrCreateDmConstMeta => const TaskConstMeta(
+    debugName: 'find_or_create_dm',
+    argNames: ['accountPubkey', 'peerPubkey'],
+  );
+
   @override
   Future<Welcome> crateApiWelcomesFindWelcomeByEventId({
     required String pubkey,
@@ -1156,7 +1720,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 33,
             port: port_,
           );
         },
@@ -1190,7 +1754,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 34,
             port: port_,
           );
         },
@@ -1220,7 +1784,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 35,
             port: port_,
           );
         },
@@ -1241,7 +1805,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<List<(String, String)>> crateApiRelaysGetAccountRelayStatuses({
+  Future<List<RelayConnectionInfo>> crateApiRelaysGetAccountRelayStatuses({
     required String pubkey,
   }) {
     return handler.executeNormal(
@@ -1252,12 +1816,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_record_string_string,
+          decodeSuccessData: sse_decode_list_relay_connection_info,
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiRelaysGetAccountRelayStatusesConstMeta,
@@ -1281,7 +1845,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 37,
             port: port_,
           );
         },
@@ -1310,7 +1874,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 38,
             port: port_,
           );
         },
@@ -1340,7 +1904,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 39,
             port: port_,
           );
         },
@@ -1374,7 +1938,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 40,
             port: port_,
           );
         },
@@ -1408,7 +1972,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 41,
             port: port_,
           );
         },
@@ -1442,7 +2006,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 42,
             port: port_,
           );
         },
@@ -1476,7 +2040,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 43,
             port: port_,
           );
         },
@@ -1497,41 +2061,133 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<User> crateApiUsersGetUser({
-    required String pubkey,
-    required bool blockingDataSync,
-  }) {
+  Future<ProxySettings?> crateApiProxyGetProxySettings() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkey, serializer);
-          sse_encode_bool(blockingDataSync, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 44,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_user,
+          decodeSuccessData: sse_decode_opt_box_autoadd_proxy_settings,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiUsersGetUserConstMeta,
-        argValues: [pubkey, blockingDataSync],
+        constMeta: kCrateApiProxyGetProxySettingsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUsersGetUserConstMeta => const TaskConstMeta(
-    debugName: 'get_user',
-    argNames: ['pubkey', 'blockingDataSync'],
+  TaskConstMeta get kCrateApiProxyGetProxySettingsConstMeta => const TaskConstMeta(
+    debugName: 'get_proxy_settings',
+    argNames: [],
   );
 
   @override
-  Future<List<String>> crateApiGroupsGroupAdmins({
+  Future<User> crateApiUsersGetUser({
+    required String pubkey,
+    required SyncPolicy syncPolicy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUsersGetUserConstMeta,
+        argValues: [pubkey, syncPolicy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUsersGetUserConstMeta => const TaskConstMeta(
+    debugName: 'get_user',
+    argNames: ['pubkey', 'syncPolicy'],
+  );
+
+  @override
+  Future<List<User>> crateApiUsersGetUsers({
+    required List<String> pubkeys,
+    required SyncPolicy syncPolicy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(pubkeys, serializer);
+          sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_user,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUsersGetUsersConstMeta,
+        argValues: [pubkeys, syncPolicy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUsersGetUsersConstMeta => const TaskConstMeta(
+    debugName: 'get_users',
+    argNames: ['pubkeys', 'syncPolicy'],
+  );
+
+  @override
+  Future<WelcomePolicy> crateApiGetWelcomePolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_welcome_policy,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiGetWelcomePolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWelcomePolicyConstMeta => const TaskConstMeta(
+    debugName: 'get_welcome_policy',
+    argNames: [],
+  );
+
+  @override
+  Future<List<String>> crateApiGroupsGroupAdmins({
     required String pubkey,
     required String groupId,
   }) {
@@ -1544,7 +2200,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 48,
             port: port_,
           );
         },
@@ -1578,7 +2234,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 49,
             port: port_,
           );
         },
@@ -1608,7 +2264,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 50,
             port: port_,
           );
         },
@@ -1642,7 +2298,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 51,
             port: port_,
           );
         },
@@ -1662,6 +2318,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['groupId'],
   );
 
+  @override
+  bool crateApiGroupsGroupIsAdmin({
+    required Group that,
+    required String pubkey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_group(that, serializer);
+          sse_encode_String(pubkey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGroupsGroupIsAdminConstMeta,
+        argValues: [that, pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupsGroupIsAdminConstMeta => const TaskConstMeta(
+    debugName: 'group_is_admin',
+    argNames: ['that', 'pubkey'],
+  );
+
   @override
   Future<bool> crateApiGroupsGroupIsDirectMessageType({
     required Group that,
@@ -1676,7 +2361,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 53,
             port: port_,
           );
         },
@@ -1710,7 +2395,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 54,
             port: port_,
           );
         },
@@ -1730,6 +2415,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['that', 'accountPubkey'],
   );
 
+  @override
+  Future<List<GroupMember>> crateApiGroupsGroupMemberDetails({
+    required String pubkey,
+    required String groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_group_member,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiGroupsGroupMemberDetailsConstMeta,
+        argValues: [pubkey, groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupsGroupMemberDetailsConstMeta => const TaskConstMeta(
+    debugName: 'group_member_details',
+    argNames: ['pubkey', 'groupId'],
+  );
+
   @override
   Future<List<String>> crateApiGroupsGroupMembers({
     required String pubkey,
@@ -1744,7 +2463,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 56,
             port: port_,
           );
         },
@@ -1764,6 +2483,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'groupId'],
   );
 
+  @override
+  Future<GroupPermissions> crateApiGroupsGroupPermissionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_permissions,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGroupsGroupPermissionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupsGroupPermissionsDefaultConstMeta => const TaskConstMeta(
+    debugName: 'group_permissions_default',
+    argNames: [],
+  );
+
+  @override
+  Future<GroupRelayHealth> crateApiGroupsGroupRelayHealth({
+    required String accountPubkey,
+    required String groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_relay_health,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiGroupsGroupRelayHealthConstMeta,
+        argValues: [accountPubkey, groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupsGroupRelayHealthConstMeta => const TaskConstMeta(
+    debugName: 'group_relay_health',
+    argNames: ['accountPubkey', 'groupId'],
+  );
+
   @override
   Future<void> crateApiGroupsGroupUpdateGroupData({
     required Group that,
@@ -1783,7 +2565,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 59,
             port: port_,
           );
         },
@@ -1810,7 +2592,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(npub, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1828,6 +2610,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['npub'],
   );
 
+  @override
+  Future<void> crateApiWelcomesIgnoreWelcome({
+    required String pubkey,
+    required String welcomeEventId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(welcomeEventId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesIgnoreWelcomeConstMeta,
+        argValues: [pubkey, welcomeEventId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesIgnoreWelcomeConstMeta => const TaskConstMeta(
+    debugName: 'ignore_welcome',
+    argNames: ['pubkey', 'welcomeEventId'],
+  );
+
   @override
   Future<void> crateApiInitializeWhitenoise({
     required WhitenoiseConfig config,
@@ -1840,7 +2656,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 62,
             port: port_,
           );
         },
@@ -1861,63 +2677,193 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey}) {
+  Future<bool> crateApiNetworkIsLowBandwidthMode() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nsecOrHexPrivkey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 63,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiAccountsLoginConstMeta,
-        argValues: [nsecOrHexPrivkey],
+        constMeta: kCrateApiNetworkIsLowBandwidthModeConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsLoginConstMeta => const TaskConstMeta(
-    debugName: 'login',
-    argNames: ['nsecOrHexPrivkey'],
+  TaskConstMeta get kCrateApiNetworkIsLowBandwidthModeConstMeta => const TaskConstMeta(
+    debugName: 'is_low_bandwidth_mode',
+    argNames: [],
   );
 
   @override
-  Future<void> crateApiAccountsLogout({required String pubkey}) {
+  Future<bool> crateApiNetworkIsNetworkPaused() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 64,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiAccountsLogoutConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiNetworkIsNetworkPausedConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsLogoutConstMeta => const TaskConstMeta(
-    debugName: 'logout',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiNetworkIsNetworkPausedConstMeta => const TaskConstMeta(
+    debugName: 'is_network_paused',
+    argNames: [],
+  );
+
+  @override
+  Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nsecOrHexPrivkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_account,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAccountsLoginConstMeta,
+        argValues: [nsecOrHexPrivkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountsLoginConstMeta => const TaskConstMeta(
+    debugName: 'login',
+    argNames: ['nsecOrHexPrivkey'],
+  );
+
+  @override
+  Future<void> crateApiAccountsLogout({required String pubkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAccountsLogoutConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountsLogoutConstMeta => const TaskConstMeta(
+    debugName: 'logout',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<MemberFingerprint> crateApiVerificationMemberFingerprint({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(memberPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_member_fingerprint,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVerificationMemberFingerprintConstMeta,
+        argValues: [accountPubkey, groupId, memberPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVerificationMemberFingerprintConstMeta => const TaskConstMeta(
+    debugName: 'member_fingerprint',
+    argNames: ['accountPubkey', 'groupId', 'memberPubkey'],
+  );
+
+  @override
+  Future<void> crateApiMessagesModerateDeleteMessage({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMessagesModerateDeleteMessageConstMeta,
+        argValues: [pubkey, groupId, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesModerateDeleteMessageConstMeta => const TaskConstMeta(
+    debugName: 'moderate_delete_message',
+    argNames: ['pubkey', 'groupId', 'messageId'],
   );
 
   @override
@@ -1927,69 +2873,522 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(hexPubkey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUtilsNpubFromHexPubkeyConstMeta,
+        argValues: [hexPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsNpubFromHexPubkeyConstMeta => const TaskConstMeta(
+    debugName: 'npub_from_hex_pubkey',
+    argNames: ['hexPubkey'],
+  );
+
+  @override
+  Future<void> crateApiNetworkPauseNetwork() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNetworkPauseNetworkConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkPauseNetworkConstMeta => const TaskConstMeta(
+    debugName: 'pause_network',
+    argNames: [],
+  );
+
+  @override
+  Future<List<JoinRequest>> crateApiInvitesPendingJoinRequests({
+    required String accountPubkey,
+    required String groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_join_request,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiInvitesPendingJoinRequestsConstMeta,
+        argValues: [accountPubkey, groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInvitesPendingJoinRequestsConstMeta => const TaskConstMeta(
+    debugName: 'pending_join_requests',
+    argNames: ['accountPubkey', 'groupId'],
+  );
+
+  @override
+  Future<List<String>> crateApiRelaysPendingRelayAuthRequests({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysPendingRelayAuthRequestsConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysPendingRelayAuthRequestsConstMeta => const TaskConstMeta(
+    debugName: 'pending_relay_auth_requests',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<List<Welcome>> crateApiWelcomesPendingWelcomes({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesPendingWelcomesConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesPendingWelcomesConstMeta => const TaskConstMeta(
+    debugName: 'pending_welcomes',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<void> crateApiAccountsPublishAccountKeyPackage({
+    required String accountPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAccountsPublishAccountKeyPackageConstMeta,
+        argValues: [accountPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountsPublishAccountKeyPackageConstMeta => const TaskConstMeta(
+    debugName: 'publish_account_key_package',
+    argNames: ['accountPubkey'],
+  );
+
+  @override
+  Future<BigInt> crateApiMessagesPurgeExpiredMessages({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMessagesPurgeExpiredMessagesConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagesPurgeExpiredMessagesConstMeta => const TaskConstMeta(
+    debugName: 'purge_expired_messages',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Future<RelayAuthPolicy> crateApiRelaysRelayAuthPolicy({
+    required String pubkey,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_relay_auth_policy,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysRelayAuthPolicyConstMeta,
+        argValues: [pubkey, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayAuthPolicyConstMeta => const TaskConstMeta(
+    debugName: 'relay_auth_policy',
+    argNames: ['pubkey', 'url'],
+  );
+
+  @override
+  Future<RelayHealth> crateApiRelaysRelayHealth({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_relay_health,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysRelayHealthConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayHealthConstMeta => const TaskConstMeta(
+    debugName: 'relay_health',
+    argNames: ['url'],
+  );
+
+  @override
+  Future<RelayInfo> crateApiRelaysRelayInfo({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_relay_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysRelayInfoConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayInfoConstMeta => const TaskConstMeta(
+    debugName: 'relay_info',
+    argNames: ['url'],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeInbox() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeInboxConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeInboxConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_inbox',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeKeyPackage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeKeyPackageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeKeyPackageConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_key_package',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayType> crateApiRelaysRelayTypeNip65() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRelaysRelayTypeNip65ConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysRelayTypeNip65ConstMeta => const TaskConstMeta(
+    debugName: 'relay_type_nip65',
+    argNames: [],
+  );
+
+  @override
+  Future<RelayUrl> crateApiUtilsRelayUrlFromString({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUtilsRelayUrlFromStringConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUtilsRelayUrlFromStringConstMeta => const TaskConstMeta(
+    debugName: 'relay_url_from_string',
+    argNames: ['url'],
+  );
+
+  @override
+  Future<void> crateApiAccountsRemoveAccountRelay({
+    required String pubkey,
+    required String url,
+    required RelayType relayType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType(
+            relayType,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiUtilsNpubFromHexPubkeyConstMeta,
-        argValues: [hexPubkey],
+        constMeta: kCrateApiAccountsRemoveAccountRelayConstMeta,
+        argValues: [pubkey, url, relayType],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsNpubFromHexPubkeyConstMeta => const TaskConstMeta(
-    debugName: 'npub_from_hex_pubkey',
-    argNames: ['hexPubkey'],
+  TaskConstMeta get kCrateApiAccountsRemoveAccountRelayConstMeta => const TaskConstMeta(
+    debugName: 'remove_account_relay',
+    argNames: ['pubkey', 'url', 'relayType'],
   );
 
   @override
-  Future<List<Welcome>> crateApiWelcomesPendingWelcomes({
+  Future<void> crateApiGroupsRemoveMembersFromGroup({
     required String pubkey,
+    required String groupId,
+    required List<String> memberPubkeys,
+    String? reason,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_list_String(memberPubkeys, serializer);
+          sse_encode_opt_String(reason, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 84,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_welcome,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiWelcomesPendingWelcomesConstMeta,
-        argValues: [pubkey],
+        constMeta: kCrateApiGroupsRemoveMembersFromGroupConstMeta,
+        argValues: [pubkey, groupId, memberPubkeys, reason],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWelcomesPendingWelcomesConstMeta => const TaskConstMeta(
-    debugName: 'pending_welcomes',
-    argNames: ['pubkey'],
+  TaskConstMeta get kCrateApiGroupsRemoveMembersFromGroupConstMeta => const TaskConstMeta(
+    debugName: 'remove_members_from_group',
+    argNames: ['pubkey', 'groupId', 'memberPubkeys', 'reason'],
   );
 
   @override
-  Future<void> crateApiAccountsPublishAccountKeyPackage({
+  Future<void> crateApiInvitesRequestJoin({
     required String accountPubkey,
+    required String invite,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(invite, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 85,
             port: port_,
           );
         },
@@ -1997,50 +3396,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiAccountsPublishAccountKeyPackageConstMeta,
-        argValues: [accountPubkey],
+        constMeta: kCrateApiInvitesRequestJoinConstMeta,
+        argValues: [accountPubkey, invite],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsPublishAccountKeyPackageConstMeta => const TaskConstMeta(
-    debugName: 'publish_account_key_package',
-    argNames: ['accountPubkey'],
+  TaskConstMeta get kCrateApiInvitesRequestJoinConstMeta => const TaskConstMeta(
+    debugName: 'request_join',
+    argNames: ['accountPubkey', 'invite'],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeInbox() {
+  Future<void> crateApiRelaysRespondToRelayAuth({
+    required String pubkey,
+    required String url,
+    required bool approve,
+    required bool remember,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_bool(approve, serializer);
+          sse_encode_bool(remember, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 86,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiRelaysRelayTypeInboxConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRelaysRespondToRelayAuthConstMeta,
+        argValues: [pubkey, url, approve, remember],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeInboxConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_inbox',
-    argNames: [],
+  TaskConstMeta get kCrateApiRelaysRespondToRelayAuthConstMeta => const TaskConstMeta(
+    debugName: 'respond_to_relay_auth',
+    argNames: ['pubkey', 'url', 'approve', 'remember'],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeKeyPackage() {
+  Future<void> crateApiNetworkResumeNetwork() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2048,144 +3455,163 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 87,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiRelaysRelayTypeKeyPackageConstMeta,
+        constMeta: kCrateApiNetworkResumeNetworkConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeKeyPackageConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_key_package',
+  TaskConstMeta get kCrateApiNetworkResumeNetworkConstMeta => const TaskConstMeta(
+    debugName: 'resume_network',
     argNames: [],
   );
 
   @override
-  Future<RelayType> crateApiRelaysRelayTypeNip65() {
+  Future<List<UserSearchResult>> crateApiUsersSearchUsers({
+    required String accountPubkey,
+    required String query,
+    required bool searchRelays,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_bool(searchRelays, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 88,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_user_search_result,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiRelaysRelayTypeNip65ConstMeta,
-        argValues: [],
+        constMeta: kCrateApiUsersSearchUsersConstMeta,
+        argValues: [accountPubkey, query, searchRelays],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelaysRelayTypeNip65ConstMeta => const TaskConstMeta(
-    debugName: 'relay_type_nip65',
-    argNames: [],
+  TaskConstMeta get kCrateApiUsersSearchUsersConstMeta => const TaskConstMeta(
+    debugName: 'search_users',
+    argNames: ['accountPubkey', 'query', 'searchRelays'],
   );
 
   @override
-  Future<RelayUrl> crateApiUtilsRelayUrlFromString({required String url}) {
+  Future<MessageWithTokens> crateApiMessagesSendMessageToGroup({
+    required String pubkey,
+    required String groupId,
+    required String message,
+    required int kind,
+    List<Tag>? tags,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(message, serializer);
+          sse_encode_u_16(kind, serializer);
+          sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
+            tags,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 89,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayUrl,
+          decodeSuccessData: sse_decode_message_with_tokens,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiUtilsRelayUrlFromStringConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiMessagesSendMessageToGroupConstMeta,
+        argValues: [pubkey, groupId, message, kind, tags],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUtilsRelayUrlFromStringConstMeta => const TaskConstMeta(
-    debugName: 'relay_url_from_string',
-    argNames: ['url'],
+  TaskConstMeta get kCrateApiMessagesSendMessageToGroupConstMeta => const TaskConstMeta(
+    debugName: 'send_message_to_group',
+    argNames: ['pubkey', 'groupId', 'message', 'kind', 'tags'],
   );
 
   @override
-  Future<void> crateApiAccountsRemoveAccountRelay({
+  Future<RelayListChange> crateApiAccountsSetAccountRelays({
     required String pubkey,
-    required String url,
     required RelayType relayType,
+    required List<String> urls,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
-          sse_encode_String(url, serializer);
           sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType(
             relayType,
             serializer,
           );
+          sse_encode_list_String(urls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 90,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_relay_list_change,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiAccountsRemoveAccountRelayConstMeta,
-        argValues: [pubkey, url, relayType],
+        constMeta: kCrateApiAccountsSetAccountRelaysConstMeta,
+        argValues: [pubkey, relayType, urls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountsRemoveAccountRelayConstMeta => const TaskConstMeta(
-    debugName: 'remove_account_relay',
-    argNames: ['pubkey', 'url', 'relayType'],
+  TaskConstMeta get kCrateApiAccountsSetAccountRelaysConstMeta => const TaskConstMeta(
+    debugName: 'set_account_relays',
+    argNames: ['pubkey', 'relayType', 'urls'],
   );
 
   @override
-  Future<void> crateApiGroupsRemoveMembersFromGroup({
-    required String pubkey,
+  Future<void> crateApiVerificationSetMemberVerified({
+    required String accountPubkey,
     required String groupId,
-    required List<String> memberPubkeys,
+    required String memberPubkey,
+    required bool verified,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkey, serializer);
+          sse_encode_String(accountPubkey, serializer);
           sse_encode_String(groupId, serializer);
-          sse_encode_list_String(memberPubkeys, serializer);
+          sse_encode_String(memberPubkey, serializer);
+          sse_encode_bool(verified, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 91,
             port: port_,
           );
         },
@@ -2193,59 +3619,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiGroupsRemoveMembersFromGroupConstMeta,
-        argValues: [pubkey, groupId, memberPubkeys],
+        constMeta: kCrateApiVerificationSetMemberVerifiedConstMeta,
+        argValues: [accountPubkey, groupId, memberPubkey, verified],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupsRemoveMembersFromGroupConstMeta => const TaskConstMeta(
-    debugName: 'remove_members_from_group',
-    argNames: ['pubkey', 'groupId', 'memberPubkeys'],
+  TaskConstMeta get kCrateApiVerificationSetMemberVerifiedConstMeta => const TaskConstMeta(
+    debugName: 'set_member_verified',
+    argNames: ['accountPubkey', 'groupId', 'memberPubkey', 'verified'],
   );
 
   @override
-  Future<MessageWithTokens> crateApiMessagesSendMessageToGroup({
+  Future<void> crateApiRelaysSetRelayAuthPolicy({
     required String pubkey,
-    required String groupId,
-    required String message,
-    required int kind,
-    List<Tag>? tags,
+    required String url,
+    required RelayAuthPolicy policy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
-          sse_encode_String(groupId, serializer);
-          sse_encode_String(message, serializer);
-          sse_encode_u_16(kind, serializer);
-          sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
-            tags,
-            serializer,
-          );
+          sse_encode_String(url, serializer);
+          sse_encode_relay_auth_policy(policy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 92,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_message_with_tokens,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiMessagesSendMessageToGroupConstMeta,
-        argValues: [pubkey, groupId, message, kind, tags],
+        constMeta: kCrateApiRelaysSetRelayAuthPolicyConstMeta,
+        argValues: [pubkey, url, policy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessagesSendMessageToGroupConstMeta => const TaskConstMeta(
-    debugName: 'send_message_to_group',
-    argNames: ['pubkey', 'groupId', 'message', 'kind', 'tags'],
+  TaskConstMeta get kCrateApiRelaysSetRelayAuthPolicyConstMeta => const TaskConstMeta(
+    debugName: 'set_relay_auth_policy',
+    argNames: ['pubkey', 'url', 'policy'],
   );
 
   @override
@@ -2261,7 +3680,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 93,
             port: port_,
           );
         },
@@ -2291,7 +3710,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 94,
             port: port_,
           );
         },
@@ -2312,6 +3731,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['vec'],
   );
 
+  @override
+  Future<void> crateApiWelcomesUnblockWelcomer({
+    required String pubkey,
+    required String welcomerPubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(welcomerPubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesUnblockWelcomerConstMeta,
+        argValues: [pubkey, welcomerPubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesUnblockWelcomerConstMeta => const TaskConstMeta(
+    debugName: 'unblock_welcomer',
+    argNames: ['pubkey', 'welcomerPubkey'],
+  );
+
   @override
   Future<void> crateApiAccountsUnfollowUser({
     required String accountPubkey,
@@ -2326,7 +3779,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 96,
             port: port_,
           );
         },
@@ -2360,7 +3813,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 97,
             port: port_,
           );
         },
@@ -2380,6 +3833,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['pubkey', 'metadata'],
   );
 
+  @override
+  Future<void> crateApiNetworkUpdateLowBandwidthMode({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNetworkUpdateLowBandwidthModeConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkUpdateLowBandwidthModeConstMeta => const TaskConstMeta(
+    debugName: 'update_low_bandwidth_mode',
+    argNames: ['enabled'],
+  );
+
+  @override
+  Future<void> crateApiProxyUpdateProxySettings({
+    ProxySettings? proxySettings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_proxy_settings(proxySettings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiProxyUpdateProxySettingsConstMeta,
+        argValues: [proxySettings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyUpdateProxySettingsConstMeta => const TaskConstMeta(
+    debugName: 'update_proxy_settings',
+    argNames: ['proxySettings'],
+  );
+
   @override
   Future<void> crateApiUpdateThemeMode({required ThemeMode themeMode}) {
     return handler.executeNormal(
@@ -2393,7 +3908,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUpdateThemeModeConstMeta,
+        argValues: [themeMode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateThemeModeConstMeta => const TaskConstMeta(
+    debugName: 'update_theme_mode',
+    argNames: ['themeMode'],
+  );
+
+  @override
+  Future<void> crateApiUpdateWelcomePolicy({
+    required WelcomePolicy welcomePolicy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_welcome_policy(welcomePolicy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
             port: port_,
           );
         },
@@ -2401,16 +3948,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiUpdateThemeModeConstMeta,
-        argValues: [themeMode],
+        constMeta: kCrateApiUpdateWelcomePolicyConstMeta,
+        argValues: [welcomePolicy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUpdateThemeModeConstMeta => const TaskConstMeta(
-    debugName: 'update_theme_mode',
-    argNames: ['themeMode'],
+  TaskConstMeta get kCrateApiUpdateWelcomePolicyConstMeta => const TaskConstMeta(
+    debugName: 'update_welcome_policy',
+    argNames: ['welcomePolicy'],
   );
 
   @override
@@ -2431,7 +3978,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 102,
             port: port_,
           );
         },
@@ -2467,7 +4014,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 103,
             port: port_,
           );
         },
@@ -2505,7 +4052,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 104,
             port: port_,
           );
         },
@@ -2528,18 +4075,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @override
   Future<bool> crateApiUsersUserHasKeyPackage({
     required String pubkey,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
-          sse_encode_bool(blockingDataSync, serializer);
+          sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 105,
             port: port_,
           );
         },
@@ -2548,7 +4095,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiUsersUserHasKeyPackageConstMeta,
-        argValues: [pubkey, blockingDataSync],
+        argValues: [pubkey, syncPolicy],
         apiImpl: this,
       ),
     );
@@ -2556,24 +4103,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiUsersUserHasKeyPackageConstMeta => const TaskConstMeta(
     debugName: 'user_has_key_package',
-    argNames: ['pubkey', 'blockingDataSync'],
+    argNames: ['pubkey', 'syncPolicy'],
   );
 
   @override
   Future<FlutterMetadata> crateApiUsersUserMetadata({
     required String pubkey,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(pubkey, serializer);
-          sse_encode_bool(blockingDataSync, serializer);
+          sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 106,
             port: port_,
           );
         },
@@ -2582,7 +4129,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiUsersUserMetadataConstMeta,
-        argValues: [pubkey, blockingDataSync],
+        argValues: [pubkey, syncPolicy],
         apiImpl: this,
       ),
     );
@@ -2590,14 +4137,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiUsersUserMetadataConstMeta => const TaskConstMeta(
     debugName: 'user_metadata',
-    argNames: ['pubkey', 'blockingDataSync'],
+    argNames: ['pubkey', 'syncPolicy'],
   );
 
   @override
   Future<List<Relay>> crateApiUsersUserRelays({
     required String pubkey,
     required RelayType relayType,
-    required bool blockingDataSync,
+    required SyncPolicy syncPolicy,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -2608,11 +4155,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             relayType,
             serializer,
           );
-          sse_encode_bool(blockingDataSync, serializer);
+          sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 107,
             port: port_,
           );
         },
@@ -2621,7 +4168,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiUsersUserRelaysConstMeta,
-        argValues: [pubkey, relayType, blockingDataSync],
+        argValues: [pubkey, relayType, syncPolicy],
         apiImpl: this,
       ),
     );
@@ -2629,7 +4176,261 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiUsersUserRelaysConstMeta => const TaskConstMeta(
     debugName: 'user_relays',
-    argNames: ['pubkey', 'relayType', 'blockingDataSync'],
+    argNames: ['pubkey', 'relayType', 'syncPolicy'],
+  );
+
+  @override
+  Future<String> crateApiRelaysValidateRelayUrl({
+    required String pubkey,
+    required String url,
+    required RelayType relayType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRelayType(
+            relayType,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRelaysValidateRelayUrlConstMeta,
+        argValues: [pubkey, url, relayType],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaysValidateRelayUrlConstMeta => const TaskConstMeta(
+    debugName: 'validate_relay_url',
+    argNames: ['pubkey', 'url', 'relayType'],
+  );
+
+  @override
+  Future<bool> crateApiVerificationVerifyMemberQrPayload({
+    required String accountPubkey,
+    required String groupId,
+    required String memberPubkey,
+    required String scannedPayload,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(accountPubkey, serializer);
+          sse_encode_String(groupId, serializer);
+          sse_encode_String(memberPubkey, serializer);
+          sse_encode_String(scannedPayload, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVerificationVerifyMemberQrPayloadConstMeta,
+        argValues: [accountPubkey, groupId, memberPubkey, scannedPayload],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVerificationVerifyMemberQrPayloadConstMeta => const TaskConstMeta(
+    debugName: 'verify_member_qr_payload',
+    argNames: [
+      'accountPubkey',
+      'groupId',
+      'memberPubkey',
+      'scannedPayload',
+    ],
+  );
+
+  @override
+  Future<User> crateApiUsersVerifyUserNip05({required String pubkey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUsersVerifyUserNip05ConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUsersVerifyUserNip05ConstMeta => const TaskConstMeta(
+    debugName: 'verify_user_nip05',
+    argNames: ['pubkey'],
+  );
+
+  @override
+  Stream<GroupRelayAlert> crateApiGroupsWatchGroupRelayAlerts({
+    required String accountPubkey,
+  }) {
+    final sink = RustStreamSink<GroupRelayAlert>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(accountPubkey, serializer);
+            sse_encode_StreamSink_group_relay_alert_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 111,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiGroupsWatchGroupRelayAlertsConstMeta,
+          argValues: [accountPubkey, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGroupsWatchGroupRelayAlertsConstMeta => const TaskConstMeta(
+    debugName: 'watch_group_relay_alerts',
+    argNames: ['accountPubkey', 'sink'],
+  );
+
+  @override
+  Stream<User> crateApiUsersWatchUsers({
+    required List<String> pubkeys,
+    required SyncPolicy syncPolicy,
+  }) {
+    final sink = RustStreamSink<User>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(pubkeys, serializer);
+            sse_encode_box_autoadd_sync_policy(syncPolicy, serializer);
+            sse_encode_StreamSink_user_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 112,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiUsersWatchUsersConstMeta,
+          argValues: [pubkeys, syncPolicy, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiUsersWatchUsersConstMeta => const TaskConstMeta(
+    debugName: 'watch_users',
+    argNames: ['pubkeys', 'syncPolicy', 'sink'],
+  );
+
+  @override
+  Future<WelcomePreview> crateApiWelcomesWelcomePreview({
+    required String pubkey,
+    required String welcomeEventId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          sse_encode_String(welcomeEventId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_welcome_preview,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesWelcomePreviewConstMeta,
+        argValues: [pubkey, welcomeEventId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesWelcomePreviewConstMeta => const TaskConstMeta(
+    debugName: 'welcome_preview',
+    argNames: ['pubkey', 'welcomeEventId'],
+  );
+
+  @override
+  Future<List<Welcome>> crateApiWelcomesWelcomeRequests({
+    required String pubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWelcomesWelcomeRequestsConstMeta,
+        argValues: [pubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWelcomesWelcomeRequestsConstMeta => const TaskConstMeta(
+    debugName: 'welcome_requests',
+    argNames: ['pubkey'],
   );
 
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AppSettings =>
@@ -2668,6 +4469,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ThemeMode =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerThemeMode;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
   @protected
   AppSettings
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -2745,10 +4552,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+  Map<String, MetadataValue> dco_decode_Map_String_metadata_value_None(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return Map.fromEntries(
-      dco_decode_list_record_string_string(
+      dco_decode_list_record_string_metadata_value(
         raw,
       ).map((e) => MapEntry(e.$1, e.$2)),
     );
@@ -2802,6 +4611,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ThemeModeImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RustStreamSink<GroupRelayAlert> dco_decode_StreamSink_group_relay_alert_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<User> dco_decode_StreamSink_user_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2854,6 +4677,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           message: dco_decode_String(raw[1]),
         );
       case 7:
+        return ApiError_PermissionDenied(
+          message: dco_decode_String(raw[1]),
+        );
+      case 8:
+        return ApiError_InvalidRelayUrl(
+          reason: dco_decode_invalid_relay_url_reason(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 9:
         return ApiError_Other(
           message: dco_decode_String(raw[1]),
         );
@@ -2862,6 +4694,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  BlockWelcomerResult dco_decode_block_welcomer_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BlockWelcomerResult(
+      declined: dco_decode_u_32(arr[0]),
+      failed: dco_decode_list_welcome_action_result(arr[1]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2880,6 +4723,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_api_error(raw);
   }
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
   @protected
   FileMetadata dco_decode_box_autoadd_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2893,23 +4742,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  FlutterGroupDataUpdate dco_decode_box_autoadd_flutter_group_data_update(
-    dynamic raw,
-  ) {
+  FlutterGroupDataUpdate dco_decode_box_autoadd_flutter_group_data_update(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_flutter_group_data_update(raw);
+  }
+
+  @protected
+  FlutterMetadata dco_decode_box_autoadd_flutter_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_flutter_metadata(raw);
+  }
+
+  @protected
+  Group dco_decode_box_autoadd_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group(raw);
+  }
+
+  @protected
+  GroupPermissions dco_decode_box_autoadd_group_permissions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_permissions(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_settings(raw);
+  }
+
+  @protected
+  RelayInfo dco_decode_box_autoadd_relay_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_relay_info(raw);
+  }
+
+  @protected
+  SyncPolicy dco_decode_box_autoadd_sync_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_policy(raw);
+  }
+
+  @protected
+  SystemEvent dco_decode_box_autoadd_system_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_system_event(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_group_data_update(raw);
+    return dco_decode_u_64(raw);
   }
 
   @protected
-  FlutterMetadata dco_decode_box_autoadd_flutter_metadata(dynamic raw) {
+  WelcomePolicy dco_decode_box_autoadd_welcome_policy(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_metadata(raw);
+    return dco_decode_welcome_policy(raw);
   }
 
   @protected
-  Group dco_decode_box_autoadd_group(dynamic raw) {
+  WelcomeState dco_decode_box_autoadd_welcome_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_group(raw);
+    return dco_decode_welcome_state(raw);
   }
 
   @protected
@@ -2922,7 +4831,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ChatMessage dco_decode_chat_message(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return ChatMessage(
       id: dco_decode_String(arr[0]),
       pubkey: dco_decode_String(arr[1]),
@@ -2936,6 +4845,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       reactions: dco_decode_reaction_summary(arr[9]),
       mediaAttachments: dco_decode_list_media_file(arr[10]),
       kind: dco_decode_u_16(arr[11]),
+      systemEvent: dco_decode_opt_box_autoadd_system_event(arr[12]),
     );
   }
 
@@ -2951,6 +4861,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
   @protected
   FileMetadata dco_decode_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2982,7 +4898,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FlutterGroupDataUpdate dco_decode_flutter_group_data_update(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
     return FlutterGroupDataUpdate(
       name: dco_decode_opt_String(arr[0]),
       description: dco_decode_opt_String(arr[1]),
@@ -2991,6 +4907,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       imageKey: dco_decode_opt_u_8_array_32(arr[4]),
       imageHash: dco_decode_opt_u_8_array_32(arr[5]),
       imageNonce: dco_decode_opt_u_8_array_12(arr[6]),
+      disappearingMessagesSecs: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      permissions: dco_decode_opt_box_autoadd_group_permissions(arr[8]),
+      replaceUnhealthyRelays: dco_decode_opt_box_autoadd_bool(arr[9]),
     );
   }
 
@@ -3009,7 +4928,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       nip05: dco_decode_opt_String(arr[6]),
       lud06: dco_decode_opt_String(arr[7]),
       lud16: dco_decode_opt_String(arr[8]),
-      custom: dco_decode_Map_String_String_None(arr[9]),
+      custom: dco_decode_Map_String_metadata_value_None(arr[9]),
     );
   }
 
@@ -3017,7 +4936,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Group dco_decode_group(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return Group(
       mlsGroupId: dco_decode_String(arr[0]),
       nostrGroupId: dco_decode_String(arr[1]),
@@ -3030,6 +4949,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       lastMessageAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[8]),
       epoch: dco_decode_u_64(arr[9]),
       state: dco_decode_group_state(arr[10]),
+      disappearingMessagesSecs: dco_decode_opt_box_autoadd_u_64(arr[11]),
+      permissions: dco_decode_group_permissions(arr[12]),
     );
   }
 
@@ -3046,6 +4967,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GroupMember dco_decode_group_member(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GroupMember(
+      pubkey: dco_decode_String(arr[0]),
+      metadata: dco_decode_flutter_metadata(arr[1]),
+      isAdmin: dco_decode_bool(arr[2]),
+      isSelf: dco_decode_bool(arr[3]),
+      addedAtEpoch: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      addedBy: dco_decode_opt_String(arr[5]),
+      leafIndex: dco_decode_opt_box_autoadd_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  GroupPermissions dco_decode_group_permissions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupPermissions(
+      adminsOnlyPost: dco_decode_bool(arr[0]),
+      adminsOnlyAddMembers: dco_decode_bool(arr[1]),
+      slowModeSecs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  GroupRelayAlert dco_decode_group_relay_alert(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupRelayAlert(
+      groupId: dco_decode_String(arr[0]),
+      groupName: dco_decode_String(arr[1]),
+      allUnhealthySince: dco_decode_Chrono_Utc(arr[2]),
+    );
+  }
+
+  @protected
+  GroupRelayHealth dco_decode_group_relay_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GroupRelayHealth(
+      relays: dco_decode_list_relay_health(arr[0]),
+      allUnhealthySince: dco_decode_opt_box_autoadd_Chrono_Utc(arr[1]),
+    );
+  }
+
   @protected
   GroupState dco_decode_group_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3070,6 +5042,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  InvalidRelayUrlReason dco_decode_invalid_relay_url_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return InvalidRelayUrlReason.values[raw as int];
+  }
+
+  @protected
+  InviteLink dco_decode_invite_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return InviteLink(
+      token: dco_decode_String(arr[0]),
+      mlsGroupId: dco_decode_String(arr[1]),
+      expiresAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[2]),
+      maxUses: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      uses: dco_decode_u_32(arr[4]),
+      createdAt: dco_decode_Chrono_Utc(arr[5]),
+    );
+  }
+
+  @protected
+  JoinRequest dco_decode_join_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return JoinRequest(
+      id: dco_decode_String(arr[0]),
+      mlsGroupId: dco_decode_String(arr[1]),
+      requesterPubkey: dco_decode_String(arr[2]),
+      requesterMetadata: dco_decode_flutter_metadata(arr[3]),
+      createdAt: dco_decode_Chrono_Utc(arr[4]),
+    );
+  }
+
   @protected
   List<Tag>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -3125,12 +5132,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_group_information).toList();
   }
 
+  @protected
+  List<GroupMember> dco_decode_list_group_member(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_group_member).toList();
+  }
+
+  @protected
+  List<JoinRequest> dco_decode_list_join_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_join_request).toList();
+  }
+
   @protected
   List<MediaFile> dco_decode_list_media_file(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_media_file).toList();
   }
 
+  @protected
+  List<MetadataValue> dco_decode_list_metadata_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_metadata_value).toList();
+  }
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint16List;
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3138,9 +5169,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+  List<(String, MetadataValue)> dco_decode_list_record_string_metadata_value(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+    return (raw as List<dynamic>).map(dco_decode_record_string_metadata_value).toList();
   }
 
   @protected
@@ -3149,6 +5182,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_relay).toList();
   }
 
+  @protected
+  List<RelayConnectionInfo> dco_decode_list_relay_connection_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_relay_connection_info).toList();
+  }
+
+  @protected
+  List<RelayHealth> dco_decode_list_relay_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_relay_health).toList();
+  }
+
+  @protected
+  List<RelayWarning> dco_decode_list_relay_warning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_relay_warning).toList();
+  }
+
   @protected
   List<SerializableToken> dco_decode_list_serializable_token(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3167,12 +5218,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_user_reaction).toList();
   }
 
+  @protected
+  List<UserSearchResult> dco_decode_list_user_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_user_search_result).toList();
+  }
+
   @protected
   List<Welcome> dco_decode_list_welcome(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_welcome).toList();
   }
 
+  @protected
+  List<WelcomeActionResult> dco_decode_list_welcome_action_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_welcome_action_result).toList();
+  }
+
   @protected
   MediaFile dco_decode_media_file(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3194,6 +5257,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MemberFingerprint dco_decode_member_fingerprint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MemberFingerprint(
+      memberPubkey: dco_decode_String(arr[0]),
+      fingerprint: dco_decode_String(arr[1]),
+      safetyNumber: dco_decode_String(arr[2]),
+      qrPayload: dco_decode_String(arr[3]),
+      verificationState: dco_decode_verification_state(arr[4]),
+      verifiedAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[5]),
+    );
+  }
+
   @protected
   MessageWithTokens dco_decode_message_with_tokens(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3209,6 +5287,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MetadataValue dco_decode_metadata_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MetadataValue_Null();
+      case 1:
+        return MetadataValue_Bool(
+          dco_decode_bool(raw[1]),
+        );
+      case 2:
+        return MetadataValue_Integer(
+          dco_decode_i_64(raw[1]),
+        );
+      case 3:
+        return MetadataValue_UnsignedInteger(
+          dco_decode_u_64(raw[1]),
+        );
+      case 4:
+        return MetadataValue_Float(
+          dco_decode_f_64(raw[1]),
+        );
+      case 5:
+        return MetadataValue_String(
+          dco_decode_String(raw[1]),
+        );
+      case 6:
+        return MetadataValue_Array(
+          dco_decode_list_metadata_value(raw[1]),
+        );
+      case 7:
+        return MetadataValue_Object(
+          dco_decode_Map_String_metadata_value_None(raw[1]),
+        );
+      default:
+        throw Exception('unreachable');
+    }
+  }
+
+  @protected
+  Nip05Status dco_decode_nip_05_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Nip05Status.values[raw as int];
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3221,6 +5344,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_Chrono_Utc(raw);
   }
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
   @protected
   FileMetadata? dco_decode_opt_box_autoadd_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3233,6 +5362,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_flutter_event(raw);
   }
 
+  @protected
+  GroupPermissions? dco_decode_opt_box_autoadd_group_permissions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_group_permissions(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ProxySettings? dco_decode_opt_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_proxy_settings(raw);
+  }
+
+  @protected
+  RelayInfo? dco_decode_opt_box_autoadd_relay_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_relay_info(raw);
+  }
+
+  @protected
+  SystemEvent? dco_decode_opt_box_autoadd_system_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_system_event(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  WelcomeState? dco_decode_opt_box_autoadd_welcome_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_welcome_state(raw);
+  }
+
   @protected
   List<Tag>?
   dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -3264,6 +5441,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_u_8_array_32(raw);
   }
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProxySettings(
+      proxyUrl: dco_decode_String(arr[0]),
+      bypassRelays: dco_decode_list_String(arr[1]),
+    );
+  }
+
   @protected
   ReactionSummary dco_decode_reaction_summary(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3276,7 +5464,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
+  (String, MetadataValue) dco_decode_record_string_metadata_value(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2) {
@@ -3284,7 +5472,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
     return (
       dco_decode_String(arr[0]),
-      dco_decode_String(arr[1]),
+      dco_decode_metadata_value(arr[1]),
     );
   }
 
@@ -3292,14 +5480,105 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Relay dco_decode_relay(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return Relay(
       url: dco_decode_String(arr[0]),
       createdAt: dco_decode_Chrono_Utc(arr[1]),
       updatedAt: dco_decode_Chrono_Utc(arr[2]),
+      info: dco_decode_opt_box_autoadd_relay_info(arr[3]),
+    );
+  }
+
+  @protected
+  RelayAuthPolicy dco_decode_relay_auth_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RelayAuthPolicy.values[raw as int];
+  }
+
+  @protected
+  RelayAuthState dco_decode_relay_auth_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RelayAuthState.values[raw as int];
+  }
+
+  @protected
+  RelayConnectionInfo dco_decode_relay_connection_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return RelayConnectionInfo(
+      url: dco_decode_String(arr[0]),
+      status: dco_decode_relay_status(arr[1]),
+      lastConnectedAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[2]),
+      failedAttempts: dco_decode_u_64(arr[3]),
+      lastError: dco_decode_opt_String(arr[4]),
+      latencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      bytesSent: dco_decode_u_64(arr[6]),
+      bytesReceived: dco_decode_u_64(arr[7]),
+      authState: dco_decode_relay_auth_state(arr[8]),
+    );
+  }
+
+  @protected
+  RelayHealth dco_decode_relay_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RelayHealth(
+      url: dco_decode_String(arr[0]),
+      score: dco_decode_f_64(arr[1]),
+      successRate: dco_decode_f_64(arr[2]),
+      latencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      isHealthy: dco_decode_bool(arr[4]),
+      unhealthySince: dco_decode_opt_box_autoadd_Chrono_Utc(arr[5]),
+    );
+  }
+
+  @protected
+  RelayInfo dco_decode_relay_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return RelayInfo(
+      url: dco_decode_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      software: dco_decode_opt_String(arr[3]),
+      version: dco_decode_opt_String(arr[4]),
+      supportedNips: dco_decode_list_prim_u_16_strict(arr[5]),
+      maxMessageLength: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      authRequired: dco_decode_bool(arr[7]),
+      paymentRequired: dco_decode_bool(arr[8]),
+      restrictedWrites: dco_decode_bool(arr[9]),
+      icon: dco_decode_opt_String(arr[10]),
+      warnings: dco_decode_list_relay_warning(arr[11]),
+      fetchedAt: dco_decode_Chrono_Utc(arr[12]),
+    );
+  }
+
+  @protected
+  RelayListChange dco_decode_relay_list_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RelayListChange(
+      added: dco_decode_list_String(arr[0]),
+      removed: dco_decode_list_String(arr[1]),
     );
   }
 
+  @protected
+  RelayStatus dco_decode_relay_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RelayStatus.values[raw as int];
+  }
+
+  @protected
+  RelayWarning dco_decode_relay_warning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RelayWarning.values[raw as int];
+  }
+
   @protected
   SerializableToken dco_decode_serializable_token(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3311,6 +5590,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SyncPolicy dco_decode_sync_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SyncPolicy_CacheOnly();
+      case 1:
+        return SyncPolicy_StaleWhileRevalidate(
+          maxAgeSecs: dco_decode_u_64(raw[1]),
+        );
+      case 2:
+        return SyncPolicy_ForceRefresh();
+      case 3:
+        return SyncPolicy_RefreshWithTimeout(
+          timeoutMs: dco_decode_u_64(raw[1]),
+        );
+      default:
+        throw Exception('unreachable');
+    }
+  }
+
+  @protected
+  SystemEvent dco_decode_system_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SystemEvent_DisappearingMessagesChanged(
+          changedBy: dco_decode_String(raw[1]),
+          durationSecs: dco_decode_opt_box_autoadd_u_64(raw[2]),
+        );
+      case 1:
+        return SystemEvent_MembersRemoved(
+          removedBy: dco_decode_String(raw[1]),
+          members: dco_decode_list_String(raw[2]),
+          reason: dco_decode_opt_String(raw[3]),
+        );
+      case 2:
+        return SystemEvent_PermissionsChanged(
+          changedBy: dco_decode_String(raw[1]),
+          permissions: dco_decode_box_autoadd_group_permissions(raw[2]),
+        );
+      default:
+        throw Exception('unreachable');
+    }
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3369,12 +5694,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   User dco_decode_user(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return User(
       pubkey: dco_decode_String(arr[0]),
       metadata: dco_decode_flutter_metadata(arr[1]),
       createdAt: dco_decode_Chrono_Utc(arr[2]),
       updatedAt: dco_decode_Chrono_Utc(arr[3]),
+      lastFetchedAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[4]),
+      fetchedFromRelays: dco_decode_list_String(arr[5]),
+      nip05Status: dco_decode_nip_05_status(arr[6]),
+      nip05CheckedAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[7]),
     );
   }
 
@@ -3390,12 +5719,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  UserSearchMatch dco_decode_user_search_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UserSearchMatch.values[raw as int];
+  }
+
+  @protected
+  UserSearchResult dco_decode_user_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UserSearchResult(
+      user: dco_decode_user(arr[0]),
+      matchedBy: dco_decode_user_search_match(arr[1]),
+      isFollow: dco_decode_bool(arr[2]),
+    );
+  }
+
   @protected
   BigInt dco_decode_usize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  VerificationState dco_decode_verification_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VerificationState.values[raw as int];
+  }
+
   @protected
   Welcome dco_decode_welcome(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3416,6 +5769,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WelcomeActionResult dco_decode_welcome_action_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WelcomeActionResult(
+      welcomeEventId: dco_decode_String(arr[0]),
+      success: dco_decode_bool(arr[1]),
+      errorMessage: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  WelcomePolicy dco_decode_welcome_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WelcomePolicy(
+      senderPolicy: dco_decode_welcome_sender_policy(arr[0]),
+      maxMemberCount: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      autoAcceptFromFollows: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  WelcomePreview dco_decode_welcome_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WelcomePreview(
+      welcome: dco_decode_welcome(arr[0]),
+      welcomerMetadata: dco_decode_flutter_metadata(arr[1]),
+      isFollowingWelcomer: dco_decode_bool(arr[2]),
+      groupImagePath: dco_decode_opt_String(arr[3]),
+      memberPubkeys: dco_decode_list_String(arr[4]),
+      groupType: dco_decode_group_type(arr[5]),
+    );
+  }
+
+  @protected
+  WelcomeSenderPolicy dco_decode_welcome_sender_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WelcomeSenderPolicy.values[raw as int];
+  }
+
   @protected
   WelcomeState dco_decode_welcome_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3426,13 +5824,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   WhitenoiseConfig dco_decode_whitenoise_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return WhitenoiseConfig(
       dataDir: dco_decode_String(arr[0]),
       logsDir: dco_decode_String(arr[1]),
+      proxyUrl: dco_decode_opt_String(arr[2]),
     );
   }
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
   @protected
   AppSettings
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -3535,11 +5941,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
+  Map<String, MetadataValue> sse_decode_Map_String_metadata_value_None(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_record_string_string(deserializer);
+    final inner = sse_decode_list_record_string_metadata_value(deserializer);
     return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
@@ -3609,6 +6015,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RustStreamSink<GroupRelayAlert> sse_decode_StreamSink_group_relay_alert_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<User> sse_decode_StreamSink_user_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3661,6 +6083,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final var_message = sse_decode_String(deserializer);
         return ApiError_NostrHex(message: var_message);
       case 7:
+        final var_message = sse_decode_String(deserializer);
+        return ApiError_PermissionDenied(message: var_message);
+      case 8:
+        final var_reason = sse_decode_invalid_relay_url_reason(deserializer);
+        final var_message = sse_decode_String(deserializer);
+        return ApiError_InvalidRelayUrl(
+          reason: var_reason,
+          message: var_message,
+        );
+      case 9:
         final var_message = sse_decode_String(deserializer);
         return ApiError_Other(message: var_message);
       default:
@@ -3668,6 +6100,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  BlockWelcomerResult sse_decode_block_welcomer_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_declined = sse_decode_u_32(deserializer);
+    final var_failed = sse_decode_list_welcome_action_result(deserializer);
+    return BlockWelcomerResult(declined: var_declined, failed: var_failed);
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3686,6 +6128,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_api_error(deserializer));
   }
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
   @protected
   FileMetadata sse_decode_box_autoadd_file_metadata(
     SseDeserializer deserializer,
@@ -3724,6 +6172,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_group(deserializer));
   }
 
+  @protected
+  GroupPermissions sse_decode_box_autoadd_group_permissions(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_permissions(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_settings(deserializer));
+  }
+
+  @protected
+  RelayInfo sse_decode_box_autoadd_relay_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_relay_info(deserializer));
+  }
+
+  @protected
+  SyncPolicy sse_decode_box_autoadd_sync_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_policy(deserializer));
+  }
+
+  @protected
+  SystemEvent sse_decode_box_autoadd_system_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_system_event(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  WelcomePolicy sse_decode_box_autoadd_welcome_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_welcome_policy(deserializer));
+  }
+
+  @protected
+  WelcomeState sse_decode_box_autoadd_welcome_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_welcome_state(deserializer));
+  }
+
   @protected
   WhitenoiseConfig sse_decode_box_autoadd_whitenoise_config(
     SseDeserializer deserializer,
@@ -3747,6 +6265,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_reactions = sse_decode_reaction_summary(deserializer);
     final var_mediaAttachments = sse_decode_list_media_file(deserializer);
     final var_kind = sse_decode_u_16(deserializer);
+    final var_systemEvent = sse_decode_opt_box_autoadd_system_event(
+      deserializer,
+    );
     return ChatMessage(
       id: var_id,
       pubkey: var_pubkey,
@@ -3760,6 +6281,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       reactions: var_reactions,
       mediaAttachments: var_mediaAttachments,
       kind: var_kind,
+      systemEvent: var_systemEvent,
     );
   }
 
@@ -3772,6 +6294,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return EmojiReaction(emoji: var_emoji, count: var_count, users: var_users);
   }
 
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
   @protected
   FileMetadata sse_decode_file_metadata(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3816,6 +6344,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_imageKey = sse_decode_opt_u_8_array_32(deserializer);
     final var_imageHash = sse_decode_opt_u_8_array_32(deserializer);
     final var_imageNonce = sse_decode_opt_u_8_array_12(deserializer);
+    final var_disappearingMessagesSecs = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
+    final var_permissions = sse_decode_opt_box_autoadd_group_permissions(
+      deserializer,
+    );
+    final var_replaceUnhealthyRelays = sse_decode_opt_box_autoadd_bool(
+      deserializer,
+    );
     return FlutterGroupDataUpdate(
       name: var_name,
       description: var_description,
@@ -3824,6 +6361,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       imageKey: var_imageKey,
       imageHash: var_imageHash,
       imageNonce: var_imageNonce,
+      disappearingMessagesSecs: var_disappearingMessagesSecs,
+      permissions: var_permissions,
+      replaceUnhealthyRelays: var_replaceUnhealthyRelays,
     );
   }
 
@@ -3839,7 +6379,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_nip05 = sse_decode_opt_String(deserializer);
     final var_lud06 = sse_decode_opt_String(deserializer);
     final var_lud16 = sse_decode_opt_String(deserializer);
-    final var_custom = sse_decode_Map_String_String_None(deserializer);
+    final var_custom = sse_decode_Map_String_metadata_value_None(deserializer);
     return FlutterMetadata(
       name: var_name,
       displayName: var_displayName,
@@ -3870,6 +6410,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
     final var_epoch = sse_decode_u_64(deserializer);
     final var_state = sse_decode_group_state(deserializer);
+    final var_disappearingMessagesSecs = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
+    final var_permissions = sse_decode_group_permissions(deserializer);
     return Group(
       mlsGroupId: var_mlsGroupId,
       nostrGroupId: var_nostrGroupId,
@@ -3882,6 +6426,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       lastMessageAt: var_lastMessageAt,
       epoch: var_epoch,
       state: var_state,
+      disappearingMessagesSecs: var_disappearingMessagesSecs,
+      permissions: var_permissions,
     );
   }
 
@@ -3900,6 +6446,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  GroupMember sse_decode_group_member(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_pubkey = sse_decode_String(deserializer);
+    final var_metadata = sse_decode_flutter_metadata(deserializer);
+    final var_isAdmin = sse_decode_bool(deserializer);
+    final var_isSelf = sse_decode_bool(deserializer);
+    final var_addedAtEpoch = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_addedBy = sse_decode_opt_String(deserializer);
+    final var_leafIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return GroupMember(
+      pubkey: var_pubkey,
+      metadata: var_metadata,
+      isAdmin: var_isAdmin,
+      isSelf: var_isSelf,
+      addedAtEpoch: var_addedAtEpoch,
+      addedBy: var_addedBy,
+      leafIndex: var_leafIndex,
+    );
+  }
+
+  @protected
+  GroupPermissions sse_decode_group_permissions(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_adminsOnlyPost = sse_decode_bool(deserializer);
+    final var_adminsOnlyAddMembers = sse_decode_bool(deserializer);
+    final var_slowModeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return GroupPermissions(
+      adminsOnlyPost: var_adminsOnlyPost,
+      adminsOnlyAddMembers: var_adminsOnlyAddMembers,
+      slowModeSecs: var_slowModeSecs,
+    );
+  }
+
+  @protected
+  GroupRelayAlert sse_decode_group_relay_alert(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_groupId = sse_decode_String(deserializer);
+    final var_groupName = sse_decode_String(deserializer);
+    final var_allUnhealthySince = sse_decode_Chrono_Utc(deserializer);
+    return GroupRelayAlert(
+      groupId: var_groupId,
+      groupName: var_groupName,
+      allUnhealthySince: var_allUnhealthySince,
+    );
+  }
+
+  @protected
+  GroupRelayHealth sse_decode_group_relay_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_relays = sse_decode_list_relay_health(deserializer);
+    final var_allUnhealthySince = sse_decode_opt_box_autoadd_Chrono_Utc(
+      deserializer,
+    );
+    return GroupRelayHealth(
+      relays: var_relays,
+      allUnhealthySince: var_allUnhealthySince,
+    );
+  }
+
   @protected
   GroupState sse_decode_group_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3926,6 +6532,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  InvalidRelayUrlReason sse_decode_invalid_relay_url_reason(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return InvalidRelayUrlReason.values[inner];
+  }
+
+  @protected
+  InviteLink sse_decode_invite_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_token = sse_decode_String(deserializer);
+    final var_mlsGroupId = sse_decode_String(deserializer);
+    final var_expiresAt = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+    final var_maxUses = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_uses = sse_decode_u_32(deserializer);
+    final var_createdAt = sse_decode_Chrono_Utc(deserializer);
+    return InviteLink(
+      token: var_token,
+      mlsGroupId: var_mlsGroupId,
+      expiresAt: var_expiresAt,
+      maxUses: var_maxUses,
+      uses: var_uses,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  JoinRequest sse_decode_join_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_mlsGroupId = sse_decode_String(deserializer);
+    final var_requesterPubkey = sse_decode_String(deserializer);
+    final var_requesterMetadata = sse_decode_flutter_metadata(deserializer);
+    final var_createdAt = sse_decode_Chrono_Utc(deserializer);
+    return JoinRequest(
+      id: var_id,
+      mlsGroupId: var_mlsGroupId,
+      requesterPubkey: var_requesterPubkey,
+      requesterMetadata: var_requesterMetadata,
+      createdAt: var_createdAt,
+    );
+  }
+
   @protected
   List<Tag>
   sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -4035,6 +6686,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<GroupMember> sse_decode_list_group_member(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <GroupMember>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_group_member(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<JoinRequest> sse_decode_list_join_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <JoinRequest>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_join_request(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<MediaFile> sse_decode_list_media_file(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4042,40 +6717,101 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final len_ = sse_decode_i_32(deserializer);
     final ans_ = <MediaFile>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_media_file(deserializer));
+      ans_.add(sse_decode_media_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MetadataValue> sse_decode_list_metadata_value(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MetadataValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_metadata_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint16List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, MetadataValue)> sse_decode_list_record_string_metadata_value(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <(String, MetadataValue)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_metadata_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Relay> sse_decode_list_relay(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <Relay>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_relay(deserializer));
     }
     return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  List<RelayConnectionInfo> sse_decode_list_relay_connection_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    final ans_ = <RelayConnectionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_relay_connection_info(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  List<RelayHealth> sse_decode_list_relay_health(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <(String, String)>[];
+    final ans_ = <RelayHealth>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
+      ans_.add(sse_decode_relay_health(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<Relay> sse_decode_list_relay(SseDeserializer deserializer) {
+  List<RelayWarning> sse_decode_list_relay_warning(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <Relay>[];
+    final ans_ = <RelayWarning>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_relay(deserializer));
+      ans_.add(sse_decode_relay_warning(deserializer));
     }
     return ans_;
   }
@@ -4120,6 +6856,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<UserSearchResult> sse_decode_list_user_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <UserSearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_user_search_result(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Welcome> sse_decode_list_welcome(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4132,6 +6882,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<WelcomeActionResult> sse_decode_list_welcome_action_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <WelcomeActionResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_welcome_action_result(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   MediaFile sse_decode_media_file(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4165,6 +6929,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MemberFingerprint sse_decode_member_fingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_memberPubkey = sse_decode_String(deserializer);
+    final var_fingerprint = sse_decode_String(deserializer);
+    final var_safetyNumber = sse_decode_String(deserializer);
+    final var_qrPayload = sse_decode_String(deserializer);
+    final var_verificationState = sse_decode_verification_state(deserializer);
+    final var_verifiedAt = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+    return MemberFingerprint(
+      memberPubkey: var_memberPubkey,
+      fingerprint: var_fingerprint,
+      safetyNumber: var_safetyNumber,
+      qrPayload: var_qrPayload,
+      verificationState: var_verificationState,
+      verifiedAt: var_verifiedAt,
+    );
+  }
+
   @protected
   MessageWithTokens sse_decode_message_with_tokens(
     SseDeserializer deserializer,
@@ -4186,6 +6971,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MetadataValue sse_decode_metadata_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return MetadataValue_Null();
+      case 1:
+        final var_field0 = sse_decode_bool(deserializer);
+        return MetadataValue_Bool(var_field0);
+      case 2:
+        final var_field0 = sse_decode_i_64(deserializer);
+        return MetadataValue_Integer(var_field0);
+      case 3:
+        final var_field0 = sse_decode_u_64(deserializer);
+        return MetadataValue_UnsignedInteger(var_field0);
+      case 4:
+        final var_field0 = sse_decode_f_64(deserializer);
+        return MetadataValue_Float(var_field0);
+      case 5:
+        final var_field0 = sse_decode_String(deserializer);
+        return MetadataValue_String(var_field0);
+      case 6:
+        final var_field0 = sse_decode_list_metadata_value(deserializer);
+        return MetadataValue_Array(var_field0);
+      case 7:
+        final var_field0 = sse_decode_Map_String_metadata_value_None(
+          deserializer,
+        );
+        return MetadataValue_Object(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  Nip05Status sse_decode_nip_05_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return Nip05Status.values[inner];
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4210,6 +7038,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   FileMetadata? sse_decode_opt_box_autoadd_file_metadata(
     SseDeserializer deserializer,
@@ -4236,6 +7075,104 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  GroupPermissions? sse_decode_opt_box_autoadd_group_permissions(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_group_permissions(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProxySettings? sse_decode_opt_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proxy_settings(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RelayInfo? sse_decode_opt_box_autoadd_relay_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_relay_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SystemEvent? sse_decode_opt_box_autoadd_system_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_system_event(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WelcomeState? sse_decode_opt_box_autoadd_welcome_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_welcome_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<Tag>?
   sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -4285,6 +7222,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_proxyUrl = sse_decode_String(deserializer);
+    final var_bypassRelays = sse_decode_list_String(deserializer);
+    return ProxySettings(
+      proxyUrl: var_proxyUrl,
+      bypassRelays: var_bypassRelays,
+    );
+  }
+
   @protected
   ReactionSummary sse_decode_reaction_summary(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4297,12 +7245,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
+  (String, MetadataValue) sse_decode_record_string_metadata_value(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     final var_field0 = sse_decode_String(deserializer);
-    final var_field1 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_metadata_value(deserializer);
     return (var_field0, var_field1);
   }
 
@@ -4312,13 +7260,134 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_url = sse_decode_String(deserializer);
     final var_createdAt = sse_decode_Chrono_Utc(deserializer);
     final var_updatedAt = sse_decode_Chrono_Utc(deserializer);
+    final var_info = sse_decode_opt_box_autoadd_relay_info(deserializer);
     return Relay(
       url: var_url,
       createdAt: var_createdAt,
       updatedAt: var_updatedAt,
+      info: var_info,
+    );
+  }
+
+  @protected
+  RelayAuthPolicy sse_decode_relay_auth_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RelayAuthPolicy.values[inner];
+  }
+
+  @protected
+  RelayAuthState sse_decode_relay_auth_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RelayAuthState.values[inner];
+  }
+
+  @protected
+  RelayConnectionInfo sse_decode_relay_connection_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_status = sse_decode_relay_status(deserializer);
+    final var_lastConnectedAt = sse_decode_opt_box_autoadd_Chrono_Utc(
+      deserializer,
+    );
+    final var_failedAttempts = sse_decode_u_64(deserializer);
+    final var_lastError = sse_decode_opt_String(deserializer);
+    final var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_bytesSent = sse_decode_u_64(deserializer);
+    final var_bytesReceived = sse_decode_u_64(deserializer);
+    final var_authState = sse_decode_relay_auth_state(deserializer);
+    return RelayConnectionInfo(
+      url: var_url,
+      status: var_status,
+      lastConnectedAt: var_lastConnectedAt,
+      failedAttempts: var_failedAttempts,
+      lastError: var_lastError,
+      latencyMs: var_latencyMs,
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      authState: var_authState,
+    );
+  }
+
+  @protected
+  RelayHealth sse_decode_relay_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_score = sse_decode_f_64(deserializer);
+    final var_successRate = sse_decode_f_64(deserializer);
+    final var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_isHealthy = sse_decode_bool(deserializer);
+    final var_unhealthySince = sse_decode_opt_box_autoadd_Chrono_Utc(
+      deserializer,
+    );
+    return RelayHealth(
+      url: var_url,
+      score: var_score,
+      successRate: var_successRate,
+      latencyMs: var_latencyMs,
+      isHealthy: var_isHealthy,
+      unhealthySince: var_unhealthySince,
+    );
+  }
+
+  @protected
+  RelayInfo sse_decode_relay_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_name = sse_decode_opt_String(deserializer);
+    final var_description = sse_decode_opt_String(deserializer);
+    final var_software = sse_decode_opt_String(deserializer);
+    final var_version = sse_decode_opt_String(deserializer);
+    final var_supportedNips = sse_decode_list_prim_u_16_strict(deserializer);
+    final var_maxMessageLength = sse_decode_opt_box_autoadd_i_64(deserializer);
+    final var_authRequired = sse_decode_bool(deserializer);
+    final var_paymentRequired = sse_decode_bool(deserializer);
+    final var_restrictedWrites = sse_decode_bool(deserializer);
+    final var_icon = sse_decode_opt_String(deserializer);
+    final var_warnings = sse_decode_list_relay_warning(deserializer);
+    final var_fetchedAt = sse_decode_Chrono_Utc(deserializer);
+    return RelayInfo(
+      url: var_url,
+      name: var_name,
+      description: var_description,
+      software: var_software,
+      version: var_version,
+      supportedNips: var_supportedNips,
+      maxMessageLength: var_maxMessageLength,
+      authRequired: var_authRequired,
+      paymentRequired: var_paymentRequired,
+      restrictedWrites: var_restrictedWrites,
+      icon: var_icon,
+      warnings: var_warnings,
+      fetchedAt: var_fetchedAt,
     );
   }
 
+  @protected
+  RelayListChange sse_decode_relay_list_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_added = sse_decode_list_String(deserializer);
+    final var_removed = sse_decode_list_String(deserializer);
+    return RelayListChange(added: var_added, removed: var_removed);
+  }
+
+  @protected
+  RelayStatus sse_decode_relay_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RelayStatus.values[inner];
+  }
+
+  @protected
+  RelayWarning sse_decode_relay_warning(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RelayWarning.values[inner];
+  }
+
   @protected
   SerializableToken sse_decode_serializable_token(
     SseDeserializer deserializer,
@@ -4329,6 +7398,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SerializableToken(tokenType: var_tokenType, content: var_content);
   }
 
+  @protected
+  SyncPolicy sse_decode_sync_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return SyncPolicy_CacheOnly();
+      case 1:
+        final var_maxAgeSecs = sse_decode_u_64(deserializer);
+        return SyncPolicy_StaleWhileRevalidate(maxAgeSecs: var_maxAgeSecs);
+      case 2:
+        return SyncPolicy_ForceRefresh();
+      case 3:
+        final var_timeoutMs = sse_decode_u_64(deserializer);
+        return SyncPolicy_RefreshWithTimeout(timeoutMs: var_timeoutMs);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SystemEvent sse_decode_system_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        final var_changedBy = sse_decode_String(deserializer);
+        final var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+        return SystemEvent_DisappearingMessagesChanged(
+          changedBy: var_changedBy,
+          durationSecs: var_durationSecs,
+        );
+      case 1:
+        final var_removedBy = sse_decode_String(deserializer);
+        final var_members = sse_decode_list_String(deserializer);
+        final var_reason = sse_decode_opt_String(deserializer);
+        return SystemEvent_MembersRemoved(
+          removedBy: var_removedBy,
+          members: var_members,
+          reason: var_reason,
+        );
+      case 2:
+        final var_changedBy = sse_decode_String(deserializer);
+        final var_permissions = sse_decode_box_autoadd_group_permissions(
+          deserializer,
+        );
+        return SystemEvent_PermissionsChanged(
+          changedBy: var_changedBy,
+          permissions: var_permissions,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4394,11 +7520,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final var_metadata = sse_decode_flutter_metadata(deserializer);
     final var_createdAt = sse_decode_Chrono_Utc(deserializer);
     final var_updatedAt = sse_decode_Chrono_Utc(deserializer);
+    final var_lastFetchedAt = sse_decode_opt_box_autoadd_Chrono_Utc(
+      deserializer,
+    );
+    final var_fetchedFromRelays = sse_decode_list_String(deserializer);
+    final var_nip05Status = sse_decode_nip_05_status(deserializer);
+    final var_nip05CheckedAt = sse_decode_opt_box_autoadd_Chrono_Utc(
+      deserializer,
+    );
     return User(
       pubkey: var_pubkey,
       metadata: var_metadata,
       createdAt: var_createdAt,
       updatedAt: var_updatedAt,
+      lastFetchedAt: var_lastFetchedAt,
+      fetchedFromRelays: var_fetchedFromRelays,
+      nip05Status: var_nip05Status,
+      nip05CheckedAt: var_nip05CheckedAt,
     );
   }
 
@@ -4415,12 +7553,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  UserSearchMatch sse_decode_user_search_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return UserSearchMatch.values[inner];
+  }
+
+  @protected
+  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_user = sse_decode_user(deserializer);
+    final var_matchedBy = sse_decode_user_search_match(deserializer);
+    final var_isFollow = sse_decode_bool(deserializer);
+    return UserSearchResult(
+      user: var_user,
+      matchedBy: var_matchedBy,
+      isFollow: var_isFollow,
+    );
+  }
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  VerificationState sse_decode_verification_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return VerificationState.values[inner];
+  }
+
   @protected
   Welcome sse_decode_welcome(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4450,6 +7617,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  WelcomeActionResult sse_decode_welcome_action_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_welcomeEventId = sse_decode_String(deserializer);
+    final var_success = sse_decode_bool(deserializer);
+    final var_errorMessage = sse_decode_opt_String(deserializer);
+    return WelcomeActionResult(
+      welcomeEventId: var_welcomeEventId,
+      success: var_success,
+      errorMessage: var_errorMessage,
+    );
+  }
+
+  @protected
+  WelcomePolicy sse_decode_welcome_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_senderPolicy = sse_decode_welcome_sender_policy(deserializer);
+    final var_maxMemberCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_autoAcceptFromFollows = sse_decode_bool(deserializer);
+    return WelcomePolicy(
+      senderPolicy: var_senderPolicy,
+      maxMemberCount: var_maxMemberCount,
+      autoAcceptFromFollows: var_autoAcceptFromFollows,
+    );
+  }
+
+  @protected
+  WelcomePreview sse_decode_welcome_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_welcome = sse_decode_welcome(deserializer);
+    final var_welcomerMetadata = sse_decode_flutter_metadata(deserializer);
+    final var_isFollowingWelcomer = sse_decode_bool(deserializer);
+    final var_groupImagePath = sse_decode_opt_String(deserializer);
+    final var_memberPubkeys = sse_decode_list_String(deserializer);
+    final var_groupType = sse_decode_group_type(deserializer);
+    return WelcomePreview(
+      welcome: var_welcome,
+      welcomerMetadata: var_welcomerMetadata,
+      isFollowingWelcomer: var_isFollowingWelcomer,
+      groupImagePath: var_groupImagePath,
+      memberPubkeys: var_memberPubkeys,
+      groupType: var_groupType,
+    );
+  }
+
+  @protected
+  WelcomeSenderPolicy sse_decode_welcome_sender_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return WelcomeSenderPolicy.values[inner];
+  }
+
   @protected
   WelcomeState sse_decode_welcome_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4462,7 +7685,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     final var_dataDir = sse_decode_String(deserializer);
     final var_logsDir = sse_decode_String(deserializer);
-    return WhitenoiseConfig(dataDir: var_dataDir, logsDir: var_logsDir);
+    final var_proxyUrl = sse_decode_opt_String(deserializer);
+    return WhitenoiseConfig(
+      dataDir: var_dataDir,
+      logsDir: var_logsDir,
+      proxyUrl: var_proxyUrl,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
@@ -4574,12 +7811,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_Map_String_metadata_value_None(
+    Map<String, MetadataValue> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
+    sse_encode_list_record_string_metadata_value(
       self.entries.map((e) => (e.key, e.value)).toList(),
       serializer,
     );
@@ -4648,6 +7885,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_group_relay_alert_Sse(
+    RustStreamSink<GroupRelayAlert> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_relay_alert,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_user_Sse(
+    RustStreamSink<User> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4688,12 +7959,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case ApiError_NostrHex(message: final message):
         sse_encode_i_32(6, serializer);
         sse_encode_String(message, serializer);
-      case ApiError_Other(message: final message):
+      case ApiError_PermissionDenied(message: final message):
         sse_encode_i_32(7, serializer);
         sse_encode_String(message, serializer);
+      case ApiError_InvalidRelayUrl(
+        reason: final reason,
+        message: final message,
+      ):
+        sse_encode_i_32(8, serializer);
+        sse_encode_invalid_relay_url_reason(reason, serializer);
+        sse_encode_String(message, serializer);
+      case ApiError_Other(message: final message):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(message, serializer);
     }
   }
 
+  @protected
+  void sse_encode_block_welcomer_result(
+    BlockWelcomerResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.declined, serializer);
+    sse_encode_list_welcome_action_result(self.failed, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4718,6 +8009,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_api_error(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_file_metadata(
     FileMetadata self,
@@ -4760,6 +8057,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_group(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_group_permissions(
+    GroupPermissions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_permissions(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_relay_info(
+    RelayInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_relay_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_policy(
+    SyncPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_system_event(
+    SystemEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_system_event(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_welcome_policy(
+    WelcomePolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_welcome_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_welcome_state(
+    WelcomeState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_welcome_state(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_whitenoise_config(
     WhitenoiseConfig self,
@@ -4784,6 +8165,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_reaction_summary(self.reactions, serializer);
     sse_encode_list_media_file(self.mediaAttachments, serializer);
     sse_encode_u_16(self.kind, serializer);
+    sse_encode_opt_box_autoadd_system_event(self.systemEvent, serializer);
   }
 
   @protected
@@ -4794,6 +8176,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_String(self.users, serializer);
   }
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
   @protected
   void sse_encode_file_metadata(FileMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4826,6 +8214,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_u_8_array_32(self.imageKey, serializer);
     sse_encode_opt_u_8_array_32(self.imageHash, serializer);
     sse_encode_opt_u_8_array_12(self.imageNonce, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.disappearingMessagesSecs, serializer);
+    sse_encode_opt_box_autoadd_group_permissions(self.permissions, serializer);
+    sse_encode_opt_box_autoadd_bool(self.replaceUnhealthyRelays, serializer);
   }
 
   @protected
@@ -4843,7 +8234,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.nip05, serializer);
     sse_encode_opt_String(self.lud06, serializer);
     sse_encode_opt_String(self.lud16, serializer);
-    sse_encode_Map_String_String_None(self.custom, serializer);
+    sse_encode_Map_String_metadata_value_None(self.custom, serializer);
   }
 
   @protected
@@ -4860,6 +8251,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_Chrono_Utc(self.lastMessageAt, serializer);
     sse_encode_u_64(self.epoch, serializer);
     sse_encode_group_state(self.state, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.disappearingMessagesSecs, serializer);
+    sse_encode_group_permissions(self.permissions, serializer);
   }
 
   @protected
@@ -4874,6 +8267,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Chrono_Utc(self.updatedAt, serializer);
   }
 
+  @protected
+  void sse_encode_group_member(GroupMember self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_flutter_metadata(self.metadata, serializer);
+    sse_encode_bool(self.isAdmin, serializer);
+    sse_encode_bool(self.isSelf, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.addedAtEpoch, serializer);
+    sse_encode_opt_String(self.addedBy, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.leafIndex, serializer);
+  }
+
+  @protected
+  void sse_encode_group_permissions(
+    GroupPermissions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.adminsOnlyPost, serializer);
+    sse_encode_bool(self.adminsOnlyAddMembers, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.slowModeSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_group_relay_alert(
+    GroupRelayAlert self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.groupId, serializer);
+    sse_encode_String(self.groupName, serializer);
+    sse_encode_Chrono_Utc(self.allUnhealthySince, serializer);
+  }
+
+  @protected
+  void sse_encode_group_relay_health(
+    GroupRelayHealth self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_relay_health(self.relays, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.allUnhealthySince, serializer);
+  }
+
   @protected
   void sse_encode_group_state(GroupState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4898,6 +8335,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_invalid_relay_url_reason(
+    InvalidRelayUrlReason self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_invite_link(InviteLink self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.token, serializer);
+    sse_encode_String(self.mlsGroupId, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.expiresAt, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxUses, serializer);
+    sse_encode_u_32(self.uses, serializer);
+    sse_encode_Chrono_Utc(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_join_request(JoinRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.mlsGroupId, serializer);
+    sse_encode_String(self.requesterPubkey, serializer);
+    sse_encode_flutter_metadata(self.requesterMetadata, serializer);
+    sse_encode_Chrono_Utc(self.createdAt, serializer);
+  }
+
   @protected
   void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
     List<Tag> self,
@@ -4988,6 +8455,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_group_member(
+    List<GroupMember> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_group_member(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_join_request(
+    List<JoinRequest> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_join_request(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_media_file(
     List<MediaFile> self,
@@ -4996,38 +8487,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_media_file(item, serializer);
+      sse_encode_media_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_metadata_value(
+    List<MetadataValue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_metadata_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_16_strict(
+    Uint16List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint16List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_record_string_metadata_value(
+    List<(String, MetadataValue)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_metadata_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_relay(List<Relay> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_relay(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_list_relay_connection_info(
+    List<RelayConnectionInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_relay_connection_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_list_relay_health(
+    List<RelayHealth> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
+      sse_encode_relay_health(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_relay(List<Relay> self, SseSerializer serializer) {
+  void sse_encode_list_relay_warning(
+    List<RelayWarning> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_relay(item, serializer);
+      sse_encode_relay_warning(item, serializer);
     }
   }
 
@@ -5064,6 +8613,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_user_search_result(
+    List<UserSearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_user_search_result(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_welcome(List<Welcome> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5073,6 +8634,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_welcome_action_result(
+    List<WelcomeActionResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_welcome_action_result(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_media_file(MediaFile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5090,6 +8663,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Chrono_Utc(self.createdAt, serializer);
   }
 
+  @protected
+  void sse_encode_member_fingerprint(
+    MemberFingerprint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.memberPubkey, serializer);
+    sse_encode_String(self.fingerprint, serializer);
+    sse_encode_String(self.safetyNumber, serializer);
+    sse_encode_String(self.qrPayload, serializer);
+    sse_encode_verification_state(self.verificationState, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.verifiedAt, serializer);
+  }
+
   @protected
   void sse_encode_message_with_tokens(
     MessageWithTokens self,
@@ -5104,6 +8691,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_serializable_token(self.tokens, serializer);
   }
 
+  @protected
+  void sse_encode_metadata_value(MetadataValue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MetadataValue_Null():
+        sse_encode_i_32(0, serializer);
+      case MetadataValue_Bool(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(field0, serializer);
+      case MetadataValue_Integer(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_64(field0, serializer);
+      case MetadataValue_UnsignedInteger(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(field0, serializer);
+      case MetadataValue_Float(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_f_64(field0, serializer);
+      case MetadataValue_String(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case MetadataValue_Array(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_list_metadata_value(field0, serializer);
+      case MetadataValue_Object(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_Map_String_metadata_value_None(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_nip_05_status(Nip05Status self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5127,6 +8750,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_file_metadata(
     FileMetadata? self,
@@ -5153,6 +8786,104 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_group_permissions(
+    GroupPermissions? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_group_permissions(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_proxy_settings(
+    ProxySettings? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proxy_settings(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_relay_info(
+    RelayInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_relay_info(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_system_event(
+    SystemEvent? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_system_event(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_welcome_state(
+    WelcomeState? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_welcome_state(self, serializer);
+    }
+  }
+
   @protected
   void
   sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -5203,6 +8934,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.proxyUrl, serializer);
+    sse_encode_list_String(self.bypassRelays, serializer);
+  }
+
   @protected
   void sse_encode_reaction_summary(
     ReactionSummary self,
@@ -5214,13 +8952,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
+  void sse_encode_record_string_metadata_value(
+    (String, MetadataValue) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
+    sse_encode_metadata_value(self.$2, serializer);
   }
 
   @protected
@@ -5229,6 +8967,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.url, serializer);
     sse_encode_Chrono_Utc(self.createdAt, serializer);
     sse_encode_Chrono_Utc(self.updatedAt, serializer);
+    sse_encode_opt_box_autoadd_relay_info(self.info, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_auth_policy(
+    RelayAuthPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_auth_state(
+    RelayAuthState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_connection_info(
+    RelayConnectionInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_relay_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.lastConnectedAt, serializer);
+    sse_encode_u_64(self.failedAttempts, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_relay_auth_state(self.authState, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_health(RelayHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_f_64(self.successRate, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+    sse_encode_bool(self.isHealthy, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.unhealthySince, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_info(RelayInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.software, serializer);
+    sse_encode_opt_String(self.version, serializer);
+    sse_encode_list_prim_u_16_strict(self.supportedNips, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.maxMessageLength, serializer);
+    sse_encode_bool(self.authRequired, serializer);
+    sse_encode_bool(self.paymentRequired, serializer);
+    sse_encode_bool(self.restrictedWrites, serializer);
+    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_list_relay_warning(self.warnings, serializer);
+    sse_encode_Chrono_Utc(self.fetchedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_list_change(
+    RelayListChange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.added, serializer);
+    sse_encode_list_String(self.removed, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_status(RelayStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_warning(RelayWarning self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -5241,6 +9066,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.content, serializer);
   }
 
+  @protected
+  void sse_encode_sync_policy(SyncPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SyncPolicy_CacheOnly():
+        sse_encode_i_32(0, serializer);
+      case SyncPolicy_StaleWhileRevalidate(maxAgeSecs: final maxAgeSecs):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(maxAgeSecs, serializer);
+      case SyncPolicy_ForceRefresh():
+        sse_encode_i_32(2, serializer);
+      case SyncPolicy_RefreshWithTimeout(timeoutMs: final timeoutMs):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_system_event(SystemEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SystemEvent_DisappearingMessagesChanged(
+        changedBy: final changedBy,
+        durationSecs: final durationSecs,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(changedBy, serializer);
+        sse_encode_opt_box_autoadd_u_64(durationSecs, serializer);
+      case SystemEvent_MembersRemoved(
+        removedBy: final removedBy,
+        members: final members,
+        reason: final reason,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(removedBy, serializer);
+        sse_encode_list_String(members, serializer);
+        sse_encode_opt_String(reason, serializer);
+      case SystemEvent_PermissionsChanged(
+        changedBy: final changedBy,
+        permissions: final permissions,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(changedBy, serializer);
+        sse_encode_box_autoadd_group_permissions(permissions, serializer);
+    }
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5300,6 +9172,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_flutter_metadata(self.metadata, serializer);
     sse_encode_Chrono_Utc(self.createdAt, serializer);
     sse_encode_Chrono_Utc(self.updatedAt, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.lastFetchedAt, serializer);
+    sse_encode_list_String(self.fetchedFromRelays, serializer);
+    sse_encode_nip_05_status(self.nip05Status, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.nip05CheckedAt, serializer);
   }
 
   @protected
@@ -5310,12 +9186,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Chrono_Utc(self.createdAt, serializer);
   }
 
+  @protected
+  void sse_encode_user_search_match(
+    UserSearchMatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_user_search_result(
+    UserSearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_user(self.user, serializer);
+    sse_encode_user_search_match(self.matchedBy, serializer);
+    sse_encode_bool(self.isFollow, serializer);
+  }
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
 
+  @protected
+  void sse_encode_verification_state(
+    VerificationState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_welcome(Welcome self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5332,6 +9237,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.createdAt, serializer);
   }
 
+  @protected
+  void sse_encode_welcome_action_result(
+    WelcomeActionResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.welcomeEventId, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_policy(WelcomePolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_welcome_sender_policy(self.senderPolicy, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxMemberCount, serializer);
+    sse_encode_bool(self.autoAcceptFromFollows, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_preview(
+    WelcomePreview self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_welcome(self.welcome, serializer);
+    sse_encode_flutter_metadata(self.welcomerMetadata, serializer);
+    sse_encode_bool(self.isFollowingWelcomer, serializer);
+    sse_encode_opt_String(self.groupImagePath, serializer);
+    sse_encode_list_String(self.memberPubkeys, serializer);
+    sse_encode_group_type(self.groupType, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_sender_policy(
+    WelcomeSenderPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_welcome_state(WelcomeState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5346,6 +9293,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.dataDir, serializer);
     sse_encode_String(self.logsDir, serializer);
+    sse_encode_opt_String(self.proxyUrl, serializer);
   }
 }
 
@@ -13,12 +13,16 @@ import 'api.dart';
 import 'api/accounts.dart';
 import 'api/error.dart';
 import 'api/groups.dart';
+import 'api/invites.dart';
 import 'api/media_files.dart';
 import 'api/messages.dart';
 import 'api/metadata.dart';
+import 'api/network.dart';
+import 'api/proxy.dart';
 import 'api/relays.dart';
 import 'api/users.dart';
 import 'api/utils.dart';
+import 'api/verification.dart';
 import 'api/welcomes.dart';
 import 'frb_generated.dart';
 import 'lib.dart';
@@ -49,6 +53,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ThemeModePtr =>
       wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerThemeModePtr;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   AppSettings
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -99,7 +106,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DateTime dco_decode_Chrono_Utc(dynamic raw);
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+  Map<String, MetadataValue> dco_decode_Map_String_metadata_value_None(
+    dynamic raw,
+  );
 
   @protected
   AppSettings dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -131,6 +140,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<GroupRelayAlert> dco_decode_StreamSink_group_relay_alert_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<User> dco_decode_StreamSink_user_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -140,6 +157,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiError dco_decode_api_error(dynamic raw);
 
+  @protected
+  BlockWelcomerResult dco_decode_block_welcomer_result(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -149,6 +169,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiError dco_decode_box_autoadd_api_error(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
   @protected
   FileMetadata dco_decode_box_autoadd_file_metadata(dynamic raw);
 
@@ -166,6 +189,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Group dco_decode_box_autoadd_group(dynamic raw);
 
+  @protected
+  GroupPermissions dco_decode_box_autoadd_group_permissions(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw);
+
+  @protected
+  RelayInfo dco_decode_box_autoadd_relay_info(dynamic raw);
+
+  @protected
+  SyncPolicy dco_decode_box_autoadd_sync_policy(dynamic raw);
+
+  @protected
+  SystemEvent dco_decode_box_autoadd_system_event(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WelcomePolicy dco_decode_box_autoadd_welcome_policy(dynamic raw);
+
+  @protected
+  WelcomeState dco_decode_box_autoadd_welcome_state(dynamic raw);
+
   @protected
   WhitenoiseConfig dco_decode_box_autoadd_whitenoise_config(dynamic raw);
 
@@ -175,6 +228,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   EmojiReaction dco_decode_emoji_reaction(dynamic raw);
 
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
   @protected
   FileMetadata dco_decode_file_metadata(dynamic raw);
 
@@ -193,6 +249,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GroupInformation dco_decode_group_information(dynamic raw);
 
+  @protected
+  GroupMember dco_decode_group_member(dynamic raw);
+
+  @protected
+  GroupPermissions dco_decode_group_permissions(dynamic raw);
+
+  @protected
+  GroupRelayAlert dco_decode_group_relay_alert(dynamic raw);
+
+  @protected
+  GroupRelayHealth dco_decode_group_relay_health(dynamic raw);
+
   @protected
   GroupState dco_decode_group_state(dynamic raw);
 
@@ -205,6 +273,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  InvalidRelayUrlReason dco_decode_invalid_relay_url_reason(dynamic raw);
+
+  @protected
+  InviteLink dco_decode_invite_link(dynamic raw);
+
+  @protected
+  JoinRequest dco_decode_join_request(dynamic raw);
+
   @protected
   List<Tag>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -232,18 +309,41 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<GroupInformation> dco_decode_list_group_information(dynamic raw);
 
+  @protected
+  List<GroupMember> dco_decode_list_group_member(dynamic raw);
+
+  @protected
+  List<JoinRequest> dco_decode_list_join_request(dynamic raw);
+
   @protected
   List<MediaFile> dco_decode_list_media_file(dynamic raw);
 
+  @protected
+  List<MetadataValue> dco_decode_list_metadata_value(dynamic raw);
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+  List<(String, MetadataValue)> dco_decode_list_record_string_metadata_value(
+    dynamic raw,
+  );
 
   @protected
   List<Relay> dco_decode_list_relay(dynamic raw);
 
+  @protected
+  List<RelayConnectionInfo> dco_decode_list_relay_connection_info(dynamic raw);
+
+  @protected
+  List<RelayHealth> dco_decode_list_relay_health(dynamic raw);
+
+  @protected
+  List<RelayWarning> dco_decode_list_relay_warning(dynamic raw);
+
   @protected
   List<SerializableToken> dco_decode_list_serializable_token(dynamic raw);
 
@@ -253,27 +353,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<UserReaction> dco_decode_list_user_reaction(dynamic raw);
 
+  @protected
+  List<UserSearchResult> dco_decode_list_user_search_result(dynamic raw);
+
   @protected
   List<Welcome> dco_decode_list_welcome(dynamic raw);
 
+  @protected
+  List<WelcomeActionResult> dco_decode_list_welcome_action_result(dynamic raw);
+
   @protected
   MediaFile dco_decode_media_file(dynamic raw);
 
+  @protected
+  MemberFingerprint dco_decode_member_fingerprint(dynamic raw);
+
   @protected
   MessageWithTokens dco_decode_message_with_tokens(dynamic raw);
 
+  @protected
+  MetadataValue dco_decode_metadata_value(dynamic raw);
+
+  @protected
+  Nip05Status dco_decode_nip_05_status(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
   @protected
   FileMetadata? dco_decode_opt_box_autoadd_file_metadata(dynamic raw);
 
   @protected
   FlutterEvent? dco_decode_opt_box_autoadd_flutter_event(dynamic raw);
 
+  @protected
+  GroupPermissions? dco_decode_opt_box_autoadd_group_permissions(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ProxySettings? dco_decode_opt_box_autoadd_proxy_settings(dynamic raw);
+
+  @protected
+  RelayInfo? dco_decode_opt_box_autoadd_relay_info(dynamic raw);
+
+  @protected
+  SystemEvent? dco_decode_opt_box_autoadd_system_event(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WelcomeState? dco_decode_opt_box_autoadd_welcome_state(dynamic raw);
+
   @protected
   List<Tag>?
   dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -289,18 +431,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   U8Array32? dco_decode_opt_u_8_array_32(dynamic raw);
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw);
+
   @protected
   ReactionSummary dco_decode_reaction_summary(dynamic raw);
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+  (String, MetadataValue) dco_decode_record_string_metadata_value(dynamic raw);
 
   @protected
   Relay dco_decode_relay(dynamic raw);
 
+  @protected
+  RelayAuthPolicy dco_decode_relay_auth_policy(dynamic raw);
+
+  @protected
+  RelayAuthState dco_decode_relay_auth_state(dynamic raw);
+
+  @protected
+  RelayConnectionInfo dco_decode_relay_connection_info(dynamic raw);
+
+  @protected
+  RelayHealth dco_decode_relay_health(dynamic raw);
+
+  @protected
+  RelayInfo dco_decode_relay_info(dynamic raw);
+
+  @protected
+  RelayListChange dco_decode_relay_list_change(dynamic raw);
+
+  @protected
+  RelayStatus dco_decode_relay_status(dynamic raw);
+
+  @protected
+  RelayWarning dco_decode_relay_warning(dynamic raw);
+
   @protected
   SerializableToken dco_decode_serializable_token(dynamic raw);
 
+  @protected
+  SyncPolicy dco_decode_sync_policy(dynamic raw);
+
+  @protected
+  SystemEvent dco_decode_system_event(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -331,18 +506,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserReaction dco_decode_user_reaction(dynamic raw);
 
+  @protected
+  UserSearchMatch dco_decode_user_search_match(dynamic raw);
+
+  @protected
+  UserSearchResult dco_decode_user_search_result(dynamic raw);
+
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  VerificationState dco_decode_verification_state(dynamic raw);
+
   @protected
   Welcome dco_decode_welcome(dynamic raw);
 
+  @protected
+  WelcomeActionResult dco_decode_welcome_action_result(dynamic raw);
+
+  @protected
+  WelcomePolicy dco_decode_welcome_policy(dynamic raw);
+
+  @protected
+  WelcomePreview dco_decode_welcome_preview(dynamic raw);
+
+  @protected
+  WelcomeSenderPolicy dco_decode_welcome_sender_policy(dynamic raw);
+
   @protected
   WelcomeState dco_decode_welcome_state(dynamic raw);
 
   @protected
   WhitenoiseConfig dco_decode_whitenoise_config(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
   @protected
   AppSettings
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -393,7 +592,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
+  Map<String, MetadataValue> sse_decode_Map_String_metadata_value_None(
     SseDeserializer deserializer,
   );
 
@@ -427,6 +626,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<GroupRelayAlert> sse_decode_StreamSink_group_relay_alert_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<User> sse_decode_StreamSink_user_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
@@ -436,6 +645,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiError sse_decode_api_error(SseDeserializer deserializer);
 
+  @protected
+  BlockWelcomerResult sse_decode_block_welcomer_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -445,6 +659,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiError sse_decode_box_autoadd_api_error(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   FileMetadata sse_decode_box_autoadd_file_metadata(
     SseDeserializer deserializer,
@@ -468,6 +685,44 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Group sse_decode_box_autoadd_group(SseDeserializer deserializer);
 
+  @protected
+  GroupPermissions sse_decode_box_autoadd_group_permissions(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayInfo sse_decode_box_autoadd_relay_info(SseDeserializer deserializer);
+
+  @protected
+  SyncPolicy sse_decode_box_autoadd_sync_policy(SseDeserializer deserializer);
+
+  @protected
+  SystemEvent sse_decode_box_autoadd_system_event(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  WelcomePolicy sse_decode_box_autoadd_welcome_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WelcomeState sse_decode_box_autoadd_welcome_state(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WhitenoiseConfig sse_decode_box_autoadd_whitenoise_config(
     SseDeserializer deserializer,
@@ -479,6 +734,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   EmojiReaction sse_decode_emoji_reaction(SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
   @protected
   FileMetadata sse_decode_file_metadata(SseDeserializer deserializer);
 
@@ -499,6 +757,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GroupInformation sse_decode_group_information(SseDeserializer deserializer);
 
+  @protected
+  GroupMember sse_decode_group_member(SseDeserializer deserializer);
+
+  @protected
+  GroupPermissions sse_decode_group_permissions(SseDeserializer deserializer);
+
+  @protected
+  GroupRelayAlert sse_decode_group_relay_alert(SseDeserializer deserializer);
+
+  @protected
+  GroupRelayHealth sse_decode_group_relay_health(SseDeserializer deserializer);
+
   @protected
   GroupState sse_decode_group_state(SseDeserializer deserializer);
 
@@ -511,6 +781,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  InvalidRelayUrlReason sse_decode_invalid_relay_url_reason(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InviteLink sse_decode_invite_link(SseDeserializer deserializer);
+
+  @protected
+  JoinRequest sse_decode_join_request(SseDeserializer deserializer);
+
   @protected
   List<Tag>
   sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -544,20 +825,47 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<GroupMember> sse_decode_list_group_member(SseDeserializer deserializer);
+
+  @protected
+  List<JoinRequest> sse_decode_list_join_request(SseDeserializer deserializer);
+
   @protected
   List<MediaFile> sse_decode_list_media_file(SseDeserializer deserializer);
 
+  @protected
+  List<MetadataValue> sse_decode_list_metadata_value(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
+  List<(String, MetadataValue)> sse_decode_list_record_string_metadata_value(
     SseDeserializer deserializer,
   );
 
   @protected
   List<Relay> sse_decode_list_relay(SseDeserializer deserializer);
 
+  @protected
+  List<RelayConnectionInfo> sse_decode_list_relay_connection_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RelayHealth> sse_decode_list_relay_health(SseDeserializer deserializer);
+
+  @protected
+  List<RelayWarning> sse_decode_list_relay_warning(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<SerializableToken> sse_decode_list_serializable_token(
     SseDeserializer deserializer,
@@ -571,23 +879,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<UserSearchResult> sse_decode_list_user_search_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Welcome> sse_decode_list_welcome(SseDeserializer deserializer);
 
+  @protected
+  List<WelcomeActionResult> sse_decode_list_welcome_action_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MediaFile sse_decode_media_file(SseDeserializer deserializer);
 
+  @protected
+  MemberFingerprint sse_decode_member_fingerprint(SseDeserializer deserializer);
+
   @protected
   MessageWithTokens sse_decode_message_with_tokens(
     SseDeserializer deserializer,
   );
 
+  @protected
+  MetadataValue sse_decode_metadata_value(SseDeserializer deserializer);
+
+  @protected
+  Nip05Status sse_decode_nip_05_status(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   FileMetadata? sse_decode_opt_box_autoadd_file_metadata(
     SseDeserializer deserializer,
@@ -598,6 +928,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  GroupPermissions? sse_decode_opt_box_autoadd_group_permissions(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ProxySettings? sse_decode_opt_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayInfo? sse_decode_opt_box_autoadd_relay_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SystemEvent? sse_decode_opt_box_autoadd_system_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  WelcomeState? sse_decode_opt_box_autoadd_welcome_state(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Tag>?
   sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -613,20 +977,55 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   U8Array32? sse_decode_opt_u_8_array_32(SseDeserializer deserializer);
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer);
+
   @protected
   ReactionSummary sse_decode_reaction_summary(SseDeserializer deserializer);
 
   @protected
-  (String, String) sse_decode_record_string_string(
+  (String, MetadataValue) sse_decode_record_string_metadata_value(
     SseDeserializer deserializer,
   );
 
   @protected
   Relay sse_decode_relay(SseDeserializer deserializer);
 
+  @protected
+  RelayAuthPolicy sse_decode_relay_auth_policy(SseDeserializer deserializer);
+
+  @protected
+  RelayAuthState sse_decode_relay_auth_state(SseDeserializer deserializer);
+
+  @protected
+  RelayConnectionInfo sse_decode_relay_connection_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelayHealth sse_decode_relay_health(SseDeserializer deserializer);
+
+  @protected
+  RelayInfo sse_decode_relay_info(SseDeserializer deserializer);
+
+  @protected
+  RelayListChange sse_decode_relay_list_change(SseDeserializer deserializer);
+
+  @protected
+  RelayStatus sse_decode_relay_status(SseDeserializer deserializer);
+
+  @protected
+  RelayWarning sse_decode_relay_warning(SseDeserializer deserializer);
+
   @protected
   SerializableToken sse_decode_serializable_token(SseDeserializer deserializer);
 
+  @protected
+  SyncPolicy sse_decode_sync_policy(SseDeserializer deserializer);
+
+  @protected
+  SystemEvent sse_decode_system_event(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -659,18 +1058,49 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserReaction sse_decode_user_reaction(SseDeserializer deserializer);
 
+  @protected
+  UserSearchMatch sse_decode_user_search_match(SseDeserializer deserializer);
+
+  @protected
+  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  VerificationState sse_decode_verification_state(SseDeserializer deserializer);
+
   @protected
   Welcome sse_decode_welcome(SseDeserializer deserializer);
 
+  @protected
+  WelcomeActionResult sse_decode_welcome_action_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WelcomePolicy sse_decode_welcome_policy(SseDeserializer deserializer);
+
+  @protected
+  WelcomePreview sse_decode_welcome_preview(SseDeserializer deserializer);
+
+  @protected
+  WelcomeSenderPolicy sse_decode_welcome_sender_policy(
+    SseDeserializer deserializer,
+  );
+
   @protected
   WelcomeState sse_decode_welcome_state(SseDeserializer deserializer);
 
   @protected
   WhitenoiseConfig sse_decode_whitenoise_config(SseDeserializer deserializer);
 
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAppSettings(
@@ -726,8 +1156,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_Map_String_metadata_value_None(
+    Map<String, MetadataValue> self,
     SseSerializer serializer,
   );
 
@@ -767,6 +1197,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_group_relay_alert_Sse(
+    RustStreamSink<GroupRelayAlert> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_user_Sse(
+    RustStreamSink<User> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -776,6 +1218,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_api_error(ApiError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_block_welcomer_result(
+    BlockWelcomerResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -791,6 +1239,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_file_metadata(
     FileMetadata self,
@@ -818,6 +1269,60 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_group(Group self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_group_permissions(
+    GroupPermissions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_relay_info(
+    RelayInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_policy(
+    SyncPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_system_event(
+    SystemEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_welcome_policy(
+    WelcomePolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_welcome_state(
+    WelcomeState self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_whitenoise_config(
     WhitenoiseConfig self,
@@ -830,6 +1335,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_emoji_reaction(EmojiReaction self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_file_metadata(FileMetadata self, SseSerializer serializer);
 
@@ -857,6 +1365,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_group_member(GroupMember self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_permissions(
+    GroupPermissions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_group_relay_alert(
+    GroupRelayAlert self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_group_relay_health(
+    GroupRelayHealth self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_group_state(GroupState self, SseSerializer serializer);
 
@@ -869,6 +1398,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_invalid_relay_url_reason(
+    InvalidRelayUrlReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_invite_link(InviteLink self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_join_request(JoinRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
     List<Tag> self,
@@ -908,12 +1449,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_group_member(
+    List<GroupMember> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_join_request(
+    List<JoinRequest> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_media_file(
     List<MediaFile> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_metadata_value(
+    List<MetadataValue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_16_strict(
+    Uint16List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -921,14 +1486,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_list_record_string_metadata_value(
+    List<(String, MetadataValue)> self,
     SseSerializer serializer,
   );
 
   @protected
   void sse_encode_list_relay(List<Relay> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_relay_connection_info(
+    List<RelayConnectionInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_relay_health(
+    List<RelayHealth> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_relay_warning(
+    List<RelayWarning> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_serializable_token(
     List<SerializableToken> self,
@@ -944,18 +1527,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_user_search_result(
+    List<UserSearchResult> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_welcome(List<Welcome> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_welcome_action_result(
+    List<WelcomeActionResult> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_media_file(MediaFile self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_member_fingerprint(
+    MemberFingerprint self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_message_with_tokens(
     MessageWithTokens self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_metadata_value(MetadataValue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nip_05_status(Nip05Status self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -965,6 +1572,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_file_metadata(
     FileMetadata? self,
@@ -977,6 +1587,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_group_permissions(
+    GroupPermissions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_proxy_settings(
+    ProxySettings? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_relay_info(
+    RelayInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_system_event(
+    SystemEvent? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_welcome_state(
+    WelcomeState? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTag(
@@ -993,6 +1645,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_u_8_array_32(U8Array32? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer);
+
   @protected
   void sse_encode_reaction_summary(
     ReactionSummary self,
@@ -1000,20 +1655,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
+  void sse_encode_record_string_metadata_value(
+    (String, MetadataValue) self,
     SseSerializer serializer,
   );
 
   @protected
   void sse_encode_relay(Relay self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_relay_auth_policy(
+    RelayAuthPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relay_auth_state(
+    RelayAuthState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relay_connection_info(
+    RelayConnectionInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relay_health(RelayHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_relay_info(RelayInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_relay_list_change(
+    RelayListChange self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relay_status(RelayStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_relay_warning(RelayWarning self, SseSerializer serializer);
+
   @protected
   void sse_encode_serializable_token(
     SerializableToken self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_sync_policy(SyncPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_system_event(SystemEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -1047,12 +1744,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_user_reaction(UserReaction self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_user_search_match(
+    UserSearchMatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_user_search_result(
+    UserSearchResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_verification_state(
+    VerificationState self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_welcome(Welcome self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_welcome_action_result(
+    WelcomeActionResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_welcome_policy(WelcomePolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_preview(
+    WelcomePreview self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_welcome_sender_policy(
+    WelcomeSenderPolicy self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_welcome_state(WelcomeState self, SseSerializer serializer);
 
@@ -17,6 +17,7 @@ import 'package:whitenoise/ui/core/ui/wn_image.dart';
 import 'package:whitenoise/ui/user_profile_list/new_group_chat_sheet.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class AddToGroupScreen extends ConsumerStatefulWidget {
   const AddToGroupScreen({super.key, required this.userNpub});
@@ -159,7 +160,10 @@ class _AddToGroupScreenState extends ConsumerState<AddToGroupScreen> {
               // If not in follows, fetch from user profile data provider
               // Use blocking fetch for adding user to group to ensure fresh metadata
               final userProfileNotifier = ref.read(userProfileProvider.notifier);
-              userProfileToAdd = await userProfileNotifier.getUserProfile(widget.userNpub);
+              userProfileToAdd = await userProfileNotifier.getUserProfile(
+                widget.userNpub,
+                syncPolicy: blockingRefreshSyncPolicy,
+              );
             }
           } catch (e) {
             // Create a basic user profile with just the public key
@@ -7,6 +7,7 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:flutter_screenutil/flutter_screenutil.dart';
 import 'package:gap/gap.dart';
 import 'package:whitenoise/config/extensions/toast_extension.dart';
+import 'package:whitenoise/src/rust/api/error.dart' show ApiError;
 import 'package:whitenoise/ui/core/themes/assets.dart';
 import 'package:whitenoise/ui/core/themes/src/extensions.dart';
 import 'package:whitenoise/ui/core/ui/wn_bottom_sheet.dart';
@@ -20,9 +21,13 @@ import 'package:whitenoise/utils/relay_validation.dart';
 class AddRelayBottomSheet extends ConsumerStatefulWidget {
   final Function(String) onRelayAdded;
 
+  /// Returns the normalized URL, or throws [ApiError] when Rust rejects the input.
+  final Future<String> Function(String) onValidateRelay;
+
   const AddRelayBottomSheet({
     super.key,
     required this.onRelayAdded,
+    required this.onValidateRelay,
   });
 
   @override
@@ -31,6 +36,7 @@ class AddRelayBottomSheet extends ConsumerStatefulWidget {
   static Future<void> show({
     required BuildContext context,
     required Function(String) onRelayAdded,
+    required Future<String> Function(String) onValidateRelay,
   }) async {
     await WnBottomSheet.show(
       context: context,
@@ -39,6 +45,7 @@ class AddRelayBottomSheet extends ConsumerStatefulWidget {
       builder:
           (context) => AddRelayBottomSheet(
             onRelayAdded: onRelayAdded,
+            onValidateRelay: onValidateRelay,
           ),
     );
   }
@@ -48,6 +55,7 @@ class _AddRelayBottomSheetState extends ConsumerState<AddRelayBottomSheet> {
   final TextEditingController _relayUrlController = TextEditingController();
   bool _isValidatingUrl = false;
   bool _validUrl = false;
+  String? _normalizedUrl;
   String? _relayValidationError;
   Timer? _debounceTimer;
 
@@ -79,14 +87,14 @@ class _AddRelayBottomSheetState extends ConsumerState<AddRelayBottomSheet> {
   Future<void> _validateRelayUrl() async {
     if (_isValidatingUrl) return;
 
+    final url = _relayUrlController.text.trim();
     try {
-      final url = _relayUrlController.text.trim();
-
       // If URL is empty or just the prefix, don't validate
       if (RelayValidation.shouldSkipValidation(url)) {
         setState(() {
           _isValidatingUrl = false;
           _validUrl = false;
+          _normalizedUrl = null;
           _relayValidationError = null;
         });
         return;
@@ -95,36 +103,44 @@ class _AddRelayBottomSheetState extends ConsumerState<AddRelayBottomSheet> {
       setState(() {
         _isValidatingUrl = true;
         _validUrl = false;
+        _normalizedUrl = null;
         _relayValidationError = null;
       });
 
-      final String? validationError = RelayValidation.validateRelayUrl(url);
+      final normalizedUrl = await widget.onValidateRelay(url);
+      if (!mounted) return;
 
-      if (validationError == null) {
-        setState(() {
-          _validUrl = true;
-          _relayValidationError = null;
-          _isValidatingUrl = false;
-        });
-      } else {
-        setState(() {
-          _validUrl = false;
-          _relayValidationError = validationError;
-          _isValidatingUrl = false;
-        });
-      }
+      setState(() {
+        _validUrl = true;
+        _normalizedUrl = normalizedUrl;
+        _relayValidationError = null;
+        _isValidatingUrl = false;
+      });
+    } on ApiError catch (e) {
+      final validationError = await e.messageText();
+      if (!mounted) return;
+      setState(() {
+        _validUrl = false;
+        _relayValidationError = validationError;
+        _isValidatingUrl = false;
+      });
     } catch (e) {
       ref.showErrorToast('network.failedValidatingRelay'.tr());
       setState(() {
         _isValidatingUrl = false;
         _validUrl = false;
       });
+    } finally {
+      // Input edited while Rust was validating is checked again
+      if (mounted && _relayUrlController.text.trim() != url) {
+        unawaited(_validateRelayUrl());
+      }
     }
   }
 
   Future<void> _addRelays() async {
-    final relayUrl = _relayUrlController.text.trim();
-    if (relayUrl.isEmpty || !_validUrl) {
+    final relayUrl = _normalizedUrl;
+    if (relayUrl == null || !_validUrl) {
       ref.showErrorToast('network.pleaseEnterValidRelay'.tr());
       return;
     }
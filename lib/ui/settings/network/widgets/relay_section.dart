@@ -36,9 +36,22 @@ class _RelaySectionState extends ConsumerState<RelaySection> {
     await AddRelayBottomSheet.show(
       context: context,
       onRelayAdded: _addRelay,
+      onValidateRelay: _validateRelay,
     );
   }
 
+  Future<String> _validateRelay(String url) {
+    // Call the appropriate notifier's validateRelay method
+    final relayNotifier = widget.relayNotifier;
+    if (relayNotifier is NormalRelaysNotifier) {
+      return relayNotifier.validateRelay(url);
+    }
+    if (relayNotifier is InboxRelaysNotifier) {
+      return relayNotifier.validateRelay(url);
+    }
+    return (relayNotifier as KeyPackageRelaysNotifier).validateRelay(url);
+  }
+
   Future<void> _addRelay(String url) async {
     try {
       // Call the appropriate notifier's addRelay method
@@ -18,6 +18,7 @@ import 'package:whitenoise/ui/core/ui/wn_image.dart';
 import 'package:whitenoise/ui/core/ui/wn_skeleton_container.dart';
 import 'package:whitenoise/ui/user_profile_list/start_chat_bottom_sheet.dart';
 import 'package:whitenoise/utils/public_key_validation_extension.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class ShareProfileQrScanScreen extends ConsumerStatefulWidget {
   const ShareProfileQrScanScreen({super.key, this.hideViewQrButton = false});
@@ -207,7 +208,10 @@ class _ShareProfileQrScanScreenState extends ConsumerState<ShareProfileQrScanScr
         _controller.stop();
         final userProfileNotifier = ref.read(userProfileProvider.notifier);
         // Use blocking fetch for QR code scan to ensure fresh metadata
-        final userProfile = await userProfileNotifier.getUserProfile(npub.trim());
+        final userProfile = await userProfileNotifier.getUserProfile(
+          npub.trim(),
+          syncPolicy: blockingRefreshSyncPolicy,
+        );
         if (mounted) {
           await StartChatBottomSheet.show(
             context: context,
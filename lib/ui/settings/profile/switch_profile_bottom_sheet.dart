@@ -16,6 +16,7 @@ import 'package:whitenoise/ui/settings/profile/connect_profile_bottom_sheet.dart
 import 'package:whitenoise/ui/user_profile_list/widgets/user_profile_tile.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class SwitchProfileBottomSheet extends ConsumerStatefulWidget {
   final Function(UserProfile) onProfileSelected;
@@ -119,8 +120,10 @@ class _SwitchProfileBottomSheetState extends ConsumerState<SwitchProfileBottomSh
       final List<Future<UserProfile>> accountsProfileDataFutures =
           accounts
               .map(
-                (account) =>
-                    userProfileNotifier.getUserProfile(account.pubkey, blockingDataSync: false),
+                (account) => userProfileNotifier.getUserProfile(
+                  account.pubkey,
+                  syncPolicy: backgroundRefreshSyncPolicy,
+                ),
               )
               .toList();
       final List<UserProfile> accountsProfileData = await Future.wait(accountsProfileDataFutures);
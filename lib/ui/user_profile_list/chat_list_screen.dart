@@ -8,11 +8,13 @@ import 'package:flutter_screenutil/flutter_screenutil.dart';
 import 'package:gap/gap.dart';
 import 'package:go_router/go_router.dart';
 import 'package:logging/logging.dart';
+import 'package:whitenoise/config/extensions/toast_extension.dart';
 import 'package:whitenoise/config/providers/active_pubkey_provider.dart';
 import 'package:whitenoise/config/providers/avatar_color_provider.dart';
 import 'package:whitenoise/config/providers/delayed_relay_error_provider.dart';
 import 'package:whitenoise/config/providers/filtered_chat_items_provider.dart';
 import 'package:whitenoise/config/providers/group_provider.dart';
+import 'package:whitenoise/config/providers/group_relay_alert_provider.dart';
 import 'package:whitenoise/config/providers/polling_provider.dart';
 import 'package:whitenoise/config/providers/profile_ready_card_visibility_provider.dart';
 import 'package:whitenoise/config/providers/relay_status_provider.dart';
@@ -340,6 +342,13 @@ class _ChatListScreenState extends ConsumerState<ChatListScreen>
     final delayedRelayErrorState = ref.watch(delayedRelayErrorProvider);
     final shouldShowRelayError = delayedRelayErrorState.shouldShowBanner;
 
+    ref.listen(groupRelayAlertProvider, (previous, next) {
+      final alert = next.valueOrNull;
+      if (alert != null && alert != previous?.valueOrNull) {
+        ref.showWarningToast('chats.groupRelaysDown'.tr({'name': alert.groupName}));
+      }
+    });
+
     return GestureDetector(
       onTap: _unfocusSearchIfNeeded,
       child: Scaffold(
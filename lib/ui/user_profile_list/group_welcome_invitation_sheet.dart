@@ -14,6 +14,7 @@ import 'package:whitenoise/ui/core/ui/wn_button.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
 import 'package:whitenoise/utils/string_extensions.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class GroupWelcomeInvitationSheet extends StatelessWidget {
   final Welcome welcome;
@@ -121,7 +122,7 @@ class _GroupMessageInviteState extends ConsumerState<GroupMessageInvite> {
       }
       return await wn_users_api.userMetadata(
         pubkey: widget.welcome.welcomer,
-        blockingDataSync: true,
+        syncPolicy: backgroundRefreshSyncPolicy,
       );
     } catch (e) {
       return null;
@@ -251,7 +252,7 @@ class _DirectMessageAvatarState extends ConsumerState<DirectMessageAvatar> {
       }
       return await wn_users_api.userMetadata(
         pubkey: widget.welcome.welcomer,
-        blockingDataSync: true,
+        syncPolicy: backgroundRefreshSyncPolicy,
       );
     } catch (e) {
       return null;
@@ -295,7 +296,10 @@ class _DirectMessageInviteCardState extends ConsumerState<DirectMessageInviteCar
         ref.showErrorToast('No active account found');
         return null;
       }
-      return wn_users_api.userMetadata(pubkey: widget.welcome.welcomer, blockingDataSync: true);
+      return wn_users_api.userMetadata(
+        pubkey: widget.welcome.welcomer,
+        syncPolicy: backgroundRefreshSyncPolicy,
+      );
     } catch (e) {
       return null;
     }
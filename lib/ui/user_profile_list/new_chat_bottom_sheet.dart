@@ -26,6 +26,7 @@ import 'package:whitenoise/ui/user_profile_list/widgets/user_profile_tile.dart';
 import 'package:whitenoise/utils/clipboard_utils.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/public_key_validation_extension.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class NewChatBottomSheet extends ConsumerStatefulWidget {
   const NewChatBottomSheet({super.key});
@@ -154,7 +155,10 @@ class _NewChatBottomSheetState extends ConsumerState<NewChatBottomSheet> {
     try {
       final userProfileNotifier = ref.read(userProfileProvider.notifier);
       // Use blocking fetch for user search to ensure fresh metadata
-      final userProfile = await userProfileNotifier.getUserProfile(publicKey.trim());
+      final userProfile = await userProfileNotifier.getUserProfile(
+        publicKey.trim(),
+        syncPolicy: blockingRefreshSyncPolicy,
+      );
 
       if (mounted) {
         setState(() {
@@ -263,6 +267,7 @@ class _NewChatBottomSheetState extends ConsumerState<NewChatBottomSheet> {
               // Use blocking fetch for support user to ensure fresh metadata
               final supportUserProfile = await userProfileNotifier.getUserProfile(
                 kSupportNpub,
+                syncPolicy: blockingRefreshSyncPolicy,
               );
               _handleUserProfileTap(supportUserProfile);
             } catch (e) {
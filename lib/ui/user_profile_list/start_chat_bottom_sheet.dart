@@ -25,6 +25,7 @@ import 'package:whitenoise/ui/user_profile_list/widgets/share_invite_callout.dar
 import 'package:whitenoise/ui/user_profile_list/widgets/user_profile_card.dart';
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 // User API interface for testing
 abstract class WnUsersApi {
@@ -40,7 +41,10 @@ class DefaultWnUsersApi implements WnUsersApi {
     final hexPubkey = PubkeyFormatter(pubkey: pubkey).toHex();
     if (hexPubkey == null) return Future.value(false);
 
-    return wn_users_api.userHasKeyPackage(pubkey: hexPubkey, blockingDataSync: false);
+    return wn_users_api.userHasKeyPackage(
+      pubkey: hexPubkey,
+      syncPolicy: backgroundRefreshSyncPolicy,
+    );
   }
 }
 
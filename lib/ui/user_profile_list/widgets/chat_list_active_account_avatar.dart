@@ -7,6 +7,7 @@ import 'package:whitenoise/config/providers/user_profile_provider.dart';
 import 'package:whitenoise/domain/models/user_profile.dart';
 import 'package:whitenoise/routing/routes.dart';
 import 'package:whitenoise/ui/core/ui/wn_avatar.dart';
+import 'package:whitenoise/utils/sync_policies.dart';
 
 class ChatListActiveAccountAvatar extends ConsumerStatefulWidget {
   const ChatListActiveAccountAvatar({
@@ -54,7 +55,7 @@ class _ChatListActiveAccountAvatarState extends ConsumerState<ChatListActiveAcco
       // Use non-blocking fetch for chat list avatar performance
       final UserProfile profileData = await ref
           .read(userProfileProvider.notifier)
-          .getUserProfile(pubkey, blockingDataSync: false);
+          .getUserProfile(pubkey, syncPolicy: backgroundRefreshSyncPolicy);
       if (!mounted) return;
       final String? currentPubkey = ref.read(activeAccountProvider).valueOrNull?.account?.pubkey;
       if (currentPubkey != pubkey) return;
@@ -0,0 +1,13 @@
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy;
+
+/// How old cached user data may get before a background refresh is started
+const Duration userDataMaxAge = Duration(hours: 1);
+
+/// Fetches user data from relays before returning it
+const SyncPolicy blockingRefreshSyncPolicy = SyncPolicy.forceRefresh();
+
+/// Returns cached user data right away and refreshes it in the background once it is
+/// older than [userDataMaxAge]
+final SyncPolicy backgroundRefreshSyncPolicy = SyncPolicy.staleWhileRevalidate(
+  maxAgeSecs: BigInt.from(userDataMaxAge.inSeconds),
+);
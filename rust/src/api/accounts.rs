@@ -1,4 +1,12 @@
-use crate::api::{error::ApiError, metadata::FlutterMetadata, relays::Relay, users::User};
+use crate::api::{
+    error::ApiError,
+    metadata::FlutterMetadata,
+    proxy::onion_relays_allowed,
+    relays::{
+        PRIVATE_RELAYS_ALLOWED, Relay, normalize_relay_url, relays_with_info, validated_relay_url,
+    },
+    users::User,
+};
 use chrono::{DateTime, TimeZone, Utc};
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
@@ -143,13 +151,21 @@ pub async fn upload_account_profile_picture(
         .map_err(ApiError::from)
 }
 
+/// Returns the account's relays of the given type.
+///
+/// With `include_info` set, each relay carries its (cached) NIP-11 document so the
+/// relay settings screen can warn about relays unsuitable for MLS messaging.
 #[frb]
-pub async fn account_relays(pubkey: String, relay_type: RelayType) -> Result<Vec<Relay>, ApiError> {
+pub async fn account_relays(
+    pubkey: String,
+    relay_type: RelayType,
+    include_info: bool,
+) -> Result<Vec<Relay>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
     let relays = account.relays(relay_type, whitenoise).await?;
-    Ok(relays.into_iter().map(|r| r.into()).collect())
+    Ok(relays_with_info(whitenoise, relays, include_info).await)
 }
 
 #[frb]
@@ -161,7 +177,7 @@ pub async fn add_account_relay(
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
-    let relay_url = RelayUrl::parse(&url)?;
+    let relay_url = validated_relay_url(whitenoise, &account, &url, relay_type).await?;
     let relay = whitenoise.find_or_create_relay_by_url(&relay_url).await?;
     account
         .add_relay(&relay, relay_type, whitenoise)
@@ -169,6 +185,74 @@ pub async fn add_account_relay(
         .map_err(ApiError::from)
 }
 
+/// Relays added and removed by [`set_account_relays`]
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct RelayListChange {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Replaces the account's relay list of one type in a single step.
+///
+/// All URLs are validated up front, so an invalid entry leaves the list untouched.
+/// The database is updated in one transaction, one replaceable relay list event is
+/// published, and subscriptions are refreshed once. Nothing is published when the
+/// list is unchanged.
+#[frb]
+pub async fn set_account_relays(
+    pubkey: String,
+    relay_type: RelayType,
+    urls: Vec<String>,
+) -> Result<RelayListChange, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let allow_onion = onion_relays_allowed(whitenoise).await?;
+    let mut relay_urls: Vec<RelayUrl> = Vec::with_capacity(urls.len());
+    for url in &urls {
+        let relay_url = normalize_relay_url(url, allow_onion, PRIVATE_RELAYS_ALLOWED)?;
+        if !relay_urls.contains(&relay_url) {
+            relay_urls.push(relay_url);
+        }
+    }
+    if relay_urls.is_empty() {
+        return Err(ApiError::Other {
+            message: "At least one relay is required".to_string(),
+        });
+    }
+
+    let current: Vec<RelayUrl> = account
+        .relays(relay_type, whitenoise)
+        .await?
+        .into_iter()
+        // Entries stored before validation existed may not be normalized yet
+        .map(|relay| normalize_relay_url(relay.url.as_str(), true, true).unwrap_or(relay.url))
+        .collect();
+    let added: Vec<RelayUrl> = relay_urls
+        .iter()
+        .filter(|url| !current.contains(url))
+        .cloned()
+        .collect();
+    let removed: Vec<RelayUrl> = current
+        .iter()
+        .filter(|url| !relay_urls.contains(url))
+        .cloned()
+        .collect();
+
+    if !added.is_empty() || !removed.is_empty() {
+        whitenoise
+            .replace_account_relays(&account, relay_type, &relay_urls)
+            .await?;
+    }
+
+    Ok(RelayListChange {
+        added: added.iter().map(|url| url.to_string()).collect(),
+        removed: removed.iter().map(|url| url.to_string()).collect(),
+    })
+}
+
 #[frb]
 pub async fn remove_account_relay(
     pubkey: String,
@@ -1,3 +1,4 @@
+use crate::api::relays::InvalidRelayUrlReason;
 use flutter_rust_bridge::frb;
 use thiserror::Error;
 
@@ -28,6 +29,17 @@ pub enum ApiError {
     #[error("Nostr hex error: {message}")]
     NostrHex { message: String },
 
+    /// The group's permission settings do not allow the action
+    #[error("Permission denied: {message}")]
+    PermissionDenied { message: String },
+
+    /// Relay URL rejected by validation, with the reason for Dart to branch on
+    #[error("Invalid relay url: {message}")]
+    InvalidRelayUrl {
+        reason: InvalidRelayUrlReason,
+        message: String,
+    },
+
     #[error("Other error: {message}")]
     Other { message: String },
 }
@@ -81,6 +93,14 @@ impl From<nostr_sdk::types::ParseError> for ApiError {
     }
 }
 
+impl From<nostr_sdk::nips::nip19::Error> for ApiError {
+    fn from(error: nostr_sdk::nips::nip19::Error) -> Self {
+        Self::NostrParse {
+            message: error.to_string(),
+        }
+    }
+}
+
 impl From<hex::FromHexError> for ApiError {
     fn from(error: hex::FromHexError) -> Self {
         Self::NostrHex {
@@ -108,6 +128,8 @@ impl ApiError {
             ApiError::NostrEvent { .. } => "NostrEvent".to_string(),
             ApiError::NostrParse { .. } => "NostrParse".to_string(),
             ApiError::NostrHex { .. } => "NostrHex".to_string(),
+            ApiError::PermissionDenied { .. } => "PermissionDenied".to_string(),
+            ApiError::InvalidRelayUrl { .. } => "InvalidRelayUrl".to_string(),
             ApiError::Other { .. } => "Other".to_string(),
         }
     }
@@ -122,6 +144,8 @@ impl ApiError {
             ApiError::NostrEvent { message } => message.clone(),
             ApiError::NostrParse { message } => message.clone(),
             ApiError::NostrHex { message } => message.clone(),
+            ApiError::PermissionDenied { message } => message.clone(),
+            ApiError::InvalidRelayUrl { message, .. } => message.clone(),
             ApiError::Other { message } => message.clone(),
         }
     }
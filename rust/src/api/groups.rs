@@ -1,15 +1,29 @@
-use crate::api::{error::ApiError, group_id_from_string, group_id_to_string};
+use crate::api::{
+    error::ApiError, group_id_from_string, group_id_to_string, metadata::FlutterMetadata,
+    relays::RelayHealth, welcomes::is_direct_message_welcome,
+};
+use crate::frb_generated::StreamSink;
 use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::group_types::Group as WhitenoiseGroup;
 use mdk_core::prelude::group_types::GroupState as WhitenoiseGroupState;
-use mdk_core::prelude::{NostrGroupConfigData, NostrGroupDataUpdate};
+use mdk_core::prelude::{GroupId, NostrGroupConfigData, NostrGroupDataUpdate};
 use nostr_sdk::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::Duration;
 use whitenoise::{
-    GroupInformation as WhitenoiseGroupInformation, GroupType as WhitenoiseGroupType, RelayType,
-    Whitenoise,
+    Account as WhitenoiseAccount, GroupInformation as WhitenoiseGroupInformation,
+    GroupPermissions as WhitenoiseGroupPermissions, GroupType as WhitenoiseGroupType, RelayType,
+    UserSyncMode, Whitenoise,
 };
 
+/// How long all relays of a group must be unhealthy before its admins are alerted
+const GROUP_RELAYS_UNHEALTHY_THRESHOLD: Duration = Duration::from_secs(15 * 60);
+
+/// How often [`watch_group_relay_alerts`] rechecks the health of group relays
+const GROUP_RELAY_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
 pub struct Group {
@@ -24,6 +38,46 @@ pub struct Group {
     pub last_message_at: Option<DateTime<Utc>>,
     pub epoch: u64,
     pub state: GroupState,
+    /// Lifetime of new messages in seconds, `None` when disappearing messages are off
+    pub disappearing_messages_secs: Option<u64>,
+    pub permissions: GroupPermissions,
+}
+
+/// Group-level permission settings carried in the group data extension.
+///
+/// These are enforced before sending, and every member's message aggregation drops
+/// incoming posts that broke the settings in effect when they were sent, so they hold
+/// regardless of which client sent them.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupPermissions {
+    /// Announcement channel: only admins may send messages
+    pub admins_only_post: bool,
+    /// Only admins may add members
+    pub admins_only_add_members: bool,
+    /// Minimum seconds between two messages from the same non-admin member
+    pub slow_mode_secs: Option<u64>,
+}
+
+impl From<WhitenoiseGroupPermissions> for GroupPermissions {
+    fn from(permissions: WhitenoiseGroupPermissions) -> Self {
+        Self {
+            admins_only_post: permissions.admins_only_post,
+            admins_only_add_members: permissions.admins_only_add_members,
+            slow_mode_secs: permissions.slow_mode_secs,
+        }
+    }
+}
+
+impl From<GroupPermissions> for WhitenoiseGroupPermissions {
+    fn from(permissions: GroupPermissions) -> Self {
+        Self {
+            admins_only_post: permissions.admins_only_post,
+            admins_only_add_members: permissions.admins_only_add_members,
+            // A zero interval is the same as no slow mode
+            slow_mode_secs: permissions.slow_mode_secs.filter(|secs| *secs > 0),
+        }
+    }
 }
 
 impl From<WhitenoiseGroup> for Group {
@@ -43,6 +97,8 @@ impl From<WhitenoiseGroup> for Group {
             }),
             epoch: group.epoch,
             state: group.state.into(),
+            disappearing_messages_secs: group.disappearing_messages_secs,
+            permissions: group.permissions.into(),
         }
     }
 }
@@ -57,6 +113,12 @@ pub struct FlutterGroupDataUpdate {
     pub image_key: Option<[u8; 32]>,
     pub image_hash: Option<[u8; 32]>,
     pub image_nonce: Option<[u8; 12]>,
+    /// New disappearing messages timer in seconds, `Some(0)` turns it off
+    pub disappearing_messages_secs: Option<u64>,
+    pub permissions: Option<GroupPermissions>,
+    /// With `Some(true)`, swaps the group's unhealthy relays for healthy NIP-65 relays of
+    /// its members; ignored when `relays` is set
+    pub replace_unhealthy_relays: Option<bool>,
 }
 
 impl From<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
@@ -69,6 +131,11 @@ impl From<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
             image_key: group_data.image_key.map(Some),
             image_hash: group_data.image_hash.map(Some),
             image_nonce: group_data.image_nonce.map(Some),
+            // Zero clears the timer, any other value sets it
+            disappearing_messages_secs: group_data
+                .disappearing_messages_secs
+                .map(|secs| (secs > 0).then_some(secs)),
+            permissions: group_data.permissions.map(|p| p.into()),
             // Will silently drop invalid relay inputs
             relays: group_data.relays.map(|relays| {
                 relays
@@ -88,6 +155,14 @@ impl From<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
 }
 
 impl Group {
+    /// Returns whether `pubkey` is one of the group's admins.
+    #[frb(sync)]
+    pub fn is_admin(&self, pubkey: String) -> bool {
+        PublicKey::parse(&pubkey)
+            .map(|pk| self.admin_pubkeys.contains(&pk.to_hex()))
+            .unwrap_or(false)
+    }
+
     #[frb]
     pub async fn group_type(&self, account_pubkey: String) -> Result<GroupType, ApiError> {
         let whitenoise = Whitenoise::get_instance()?;
@@ -121,6 +196,10 @@ impl Group {
         Ok(group_information.group_type == WhitenoiseGroupType::Group)
     }
 
+    /// Applies `group_data` to the group.
+    ///
+    /// With `replace_unhealthy_relays`, the new relay list is picked by
+    /// [`replacement_relays`] from the members' relays.
     #[frb]
     pub async fn update_group_data(
         &self,
@@ -131,13 +210,178 @@ impl Group {
         let mls_group_id = group_id_from_string(&self.mls_group_id)?;
         let parsed_pubkey = PublicKey::parse(&account_pubkey)?;
         let account = whitenoise.find_account_by_pubkey(&parsed_pubkey).await?;
+        let replace_unhealthy_relays =
+            group_data.relays.is_none() && group_data.replace_unhealthy_relays == Some(true);
+        let mut update = NostrGroupDataUpdate::from(group_data);
+        if replace_unhealthy_relays {
+            update.relays = Some(replacement_relays(whitenoise, &account, &mls_group_id).await?);
+        }
         whitenoise
-            .update_group_data(&account, &mls_group_id, group_data.into())
+            .update_group_data(&account, &mls_group_id, update)
             .await
             .map_err(ApiError::from)
     }
 }
 
+/// Picks the relays to replace a group's relay list with.
+///
+/// Healthy relays are kept and each unhealthy one is swapped for a healthy NIP-65 relay of
+/// the group's members, preferring relays more members use and then higher health scores.
+async fn replacement_relays(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group_id: &GroupId,
+) -> Result<Vec<RelayUrl>, ApiError> {
+    let current = whitenoise.group_relays(account, group_id).await?;
+    let mut relays = Vec::with_capacity(current.len());
+    for url in &current {
+        if whitenoise.relay_health(url).await?.is_healthy() {
+            relays.push(url.clone());
+        }
+    }
+    let missing = current.len() - relays.len();
+    if missing == 0 {
+        return Ok(relays);
+    }
+
+    let current: HashSet<RelayUrl> = current.into_iter().collect();
+    let mut usage: HashMap<RelayUrl, usize> = HashMap::new();
+    for member in whitenoise.group_members(account, group_id).await? {
+        let user = whitenoise
+            .find_or_create_user_by_pubkey(&member, UserSyncMode::Background)
+            .await?;
+        for relay in user.relays_by_type(RelayType::Nip65, whitenoise).await? {
+            if !current.contains(&relay.url) {
+                *usage.entry(relay.url).or_default() += 1;
+            }
+        }
+    }
+
+    let mut candidates = Vec::with_capacity(usage.len());
+    for (url, members_using) in usage {
+        let health = whitenoise.relay_health(&url).await?;
+        if health.is_healthy() {
+            candidates.push((url, members_using, health.score));
+        }
+    }
+    candidates.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.total_cmp(&a.2)));
+    relays.extend(candidates.into_iter().take(missing).map(|(url, _, _)| url));
+
+    if relays.is_empty() {
+        return Err(ApiError::Other {
+            message: "No healthy relays found to replace the group's relays".to_string(),
+        });
+    }
+    Ok(relays)
+}
+
+/// Health of a group's relays as seen by one account
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupRelayHealth {
+    pub relays: Vec<RelayHealth>,
+    /// When the last of the group's relays turned unhealthy, `None` if any is healthy
+    pub all_unhealthy_since: Option<DateTime<Utc>>,
+}
+
+/// Sent to a group's admins once all of its relays have been unhealthy for too long
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupRelayAlert {
+    pub group_id: String,
+    pub group_name: String,
+    /// When the last of the group's relays turned unhealthy
+    pub all_unhealthy_since: DateTime<Utc>,
+}
+
+async fn relay_health_for_group(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group_id: &GroupId,
+) -> Result<GroupRelayHealth, ApiError> {
+    let mut relays: Vec<RelayHealth> = Vec::new();
+    for relay_url in whitenoise.group_relays(account, group_id).await? {
+        relays.push(whitenoise.relay_health(&relay_url).await?.into());
+    }
+
+    // All relays are down since the most recent of them went down
+    let all_unhealthy_since = if !relays.is_empty() && relays.iter().all(|r| !r.is_healthy) {
+        relays.iter().filter_map(|r| r.unhealthy_since).max()
+    } else {
+        None
+    };
+    Ok(GroupRelayHealth {
+        relays,
+        all_unhealthy_since,
+    })
+}
+
+#[frb]
+pub async fn group_relay_health(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<GroupRelayHealth, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    relay_health_for_group(whitenoise, &account, &group_id).await
+}
+
+/// Pushes a [`GroupRelayAlert`] for each group the account administers once all of the
+/// group's relays have been unhealthy for longer than the alert threshold.
+///
+/// Each outage is reported once; a group alerts again only after one of its relays has
+/// recovered. Health is rechecked every minute until Dart cancels the stream.
+#[frb]
+pub async fn watch_group_relay_alerts(
+    account_pubkey: String,
+    sink: StreamSink<GroupRelayAlert>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let mut alerted: HashMap<GroupId, DateTime<Utc>> = HashMap::new();
+    let mut interval = tokio::time::interval(GROUP_RELAY_HEALTH_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+        for group in whitenoise.groups(&account, true).await? {
+            if !group.admin_pubkeys.contains(&pubkey) {
+                continue;
+            }
+            // A group whose relays can't be read is checked again on the next tick
+            let Ok(health) =
+                relay_health_for_group(whitenoise, &account, &group.mls_group_id).await
+            else {
+                continue;
+            };
+            let Some(since) = health.all_unhealthy_since else {
+                alerted.remove(&group.mls_group_id);
+                continue;
+            };
+            let unhealthy_for = Utc::now()
+                .signed_duration_since(since)
+                .to_std()
+                .unwrap_or_default();
+            if unhealthy_for < GROUP_RELAYS_UNHEALTHY_THRESHOLD
+                || alerted.get(&group.mls_group_id) == Some(&since)
+            {
+                continue;
+            }
+            let alert = GroupRelayAlert {
+                group_id: group_id_to_string(&group.mls_group_id),
+                group_name: group.name,
+                all_unhealthy_since: since,
+            };
+            if sink.add(alert).is_err() {
+                // Dart side cancelled the stream
+                return Ok(());
+            }
+            alerted.insert(group.mls_group_id, since);
+        }
+    }
+}
+
 // Define our own GroupState enum that can be used by Dart
 #[frb]
 #[derive(Debug, Clone)]
@@ -227,6 +471,68 @@ pub async fn group_admins(pubkey: String, group_id: String) -> Result<Vec<String
     Ok(admins.into_iter().map(|a| a.to_hex()).collect())
 }
 
+/// A group member together with their profile, role and MLS leaf information.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupMember {
+    pub pubkey: String,
+    pub metadata: FlutterMetadata,
+    pub is_admin: bool,
+    pub is_self: bool,
+    /// Epoch of the commit that added the member, if known
+    pub added_at_epoch: Option<u64>,
+    /// Pubkey of the member who committed the add, if known
+    pub added_by: Option<String>,
+    pub leaf_index: Option<u32>,
+}
+
+/// Returns the members of a group with their metadata, roles and join information.
+///
+/// Metadata is served from the local user cache; users that are missing or stale are
+/// refreshed in the background, so callers should re-fetch on metadata updates rather
+/// than block on the network here.
+#[frb]
+pub async fn group_member_details(
+    pubkey: String,
+    group_id: String,
+) -> Result<Vec<GroupMember>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let members = whitenoise.group_members(&account, &group_id).await?;
+    let admins: HashSet<PublicKey> = whitenoise
+        .group_admins(&account, &group_id)
+        .await?
+        .into_iter()
+        .collect();
+    let leaves: HashMap<PublicKey, _> = whitenoise
+        .group_member_leaves(&account, &group_id)
+        .await?
+        .into_iter()
+        .map(|leaf| (leaf.pubkey, leaf))
+        .collect();
+
+    let mut details = Vec::with_capacity(members.len());
+    for member in members {
+        let user = whitenoise
+            .find_or_create_user_by_pubkey(&member, UserSyncMode::Background)
+            .await?;
+        let leaf = leaves.get(&member);
+        details.push(GroupMember {
+            pubkey: member.to_hex(),
+            metadata: user.metadata.into(),
+            is_admin: admins.contains(&member),
+            is_self: member == account.pubkey,
+            added_at_epoch: leaf.and_then(|l| l.added_at_epoch),
+            added_by: leaf.and_then(|l| l.added_by).map(|pk| pk.to_hex()),
+            leaf_index: leaf.map(|l| l.leaf_index),
+        });
+    }
+    Ok(details)
+}
+
 #[frb]
 pub async fn create_group(
     creator_pubkey: String,
@@ -258,6 +564,8 @@ pub async fn create_group(
         image_key: None,
         image_hash: None,
         image_nonce: None,
+        disappearing_messages_secs: None,
+        permissions: WhitenoiseGroupPermissions::default(),
         relays: nostr_relays.into_iter().map(|r| r.url).collect(),
         admins: admin_pubkeys,
     };
@@ -278,6 +586,87 @@ pub async fn create_group(
     Ok(group.into())
 }
 
+/// Locks serializing [`find_or_create_dm`] per account and peer
+type DmLocks = HashMap<(PublicKey, PublicKey), Arc<tokio::sync::Mutex<()>>>;
+
+static DM_LOCKS: LazyLock<Mutex<DmLocks>> = LazyLock::new(Default::default);
+
+/// Returns the lock for DMs between `account` and `peer`, dropping locks nobody holds.
+fn dm_lock(account: PublicKey, peer: PublicKey) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = DM_LOCKS.lock().unwrap_or_else(PoisonError::into_inner);
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+    locks.entry((account, peer)).or_default().clone()
+}
+
+/// Returns the active direct message group between the account and `peer_pubkey`,
+/// creating one only when none exists.
+///
+/// A pending DM welcome from the peer is accepted and reused instead of creating a
+/// second group, so tapping "message" on a contact is idempotent. Concurrent calls for
+/// the same contact wait for each other, so they cannot both create a group.
+#[frb]
+pub async fn find_or_create_dm(
+    account_pubkey: String,
+    peer_pubkey: String,
+) -> Result<Group, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let peer_pubkey = PublicKey::parse(&peer_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let lock = dm_lock(pubkey, peer_pubkey);
+    let _guard = lock.lock().await;
+
+    let groups = whitenoise.groups(&account, true).await?;
+    let group_ids: Vec<GroupId> = groups.iter().map(|g| g.mls_group_id.clone()).collect();
+    let dm_group_ids: HashSet<GroupId> = whitenoise
+        .get_group_information_by_mls_group_ids(pubkey, &group_ids)
+        .await?
+        .into_iter()
+        .filter(|info| info.group_type == WhitenoiseGroupType::DirectMessage)
+        .map(|info| info.mls_group_id)
+        .collect();
+
+    for group in groups {
+        if !dm_group_ids.contains(&group.mls_group_id) {
+            continue;
+        }
+        let members = whitenoise
+            .group_members(&account, &group.mls_group_id)
+            .await?;
+        if members.len() == 2 && members.contains(&pubkey) && members.contains(&peer_pubkey) {
+            return Ok(group.into());
+        }
+    }
+
+    let mut pending_dm_welcome = None;
+    for welcome in whitenoise.pending_welcomes(&pubkey).await? {
+        if welcome.welcomer == peer_pubkey
+            && is_direct_message_welcome(whitenoise, &account, &welcome).await?
+        {
+            pending_dm_welcome = Some(welcome);
+            break;
+        }
+    }
+    if let Some(welcome) = pending_dm_welcome {
+        whitenoise
+            .accept_welcome(&pubkey, welcome.id.to_string())
+            .await?;
+        let group = whitenoise.group(&account, &welcome.mls_group_id).await?;
+        return Ok(group.into());
+    }
+
+    create_group(
+        pubkey.to_hex(),
+        vec![peer_pubkey.to_hex()],
+        vec![pubkey.to_hex(), peer_pubkey.to_hex()],
+        String::new(),
+        String::new(),
+        GroupType::DirectMessage,
+    )
+    .await
+}
+
 #[frb]
 pub async fn add_members_to_group(
     pubkey: String,
@@ -288,6 +677,12 @@ pub async fn add_members_to_group(
     let pubkey = PublicKey::parse(&pubkey)?;
     let group_id = group_id_from_string(&group_id)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group = whitenoise.group(&account, &group_id).await?;
+    if group.permissions.admins_only_add_members && !group.admin_pubkeys.contains(&pubkey) {
+        return Err(ApiError::PermissionDenied {
+            message: "Only admins can add members to this group".to_string(),
+        });
+    }
     let member_pubkeys = member_pubkeys
         .into_iter()
         .map(|pk| PublicKey::parse(&pk))
@@ -298,11 +693,16 @@ pub async fn add_members_to_group(
         .map_err(ApiError::from)
 }
 
+/// Removes members from a group.
+///
+/// The optional `reason` travels with the removal and is shown to every remaining
+/// member as a system timeline entry.
 #[frb]
 pub async fn remove_members_from_group(
     pubkey: String,
     group_id: String,
     member_pubkeys: Vec<String>,
+    reason: Option<String>,
 ) -> Result<(), ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
@@ -312,8 +712,11 @@ pub async fn remove_members_from_group(
         .into_iter()
         .map(|pk| PublicKey::parse(&pk))
         .collect::<Result<Vec<_>, _>>()?;
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
     whitenoise
-        .remove_members_from_group(&account, &group_id, member_pubkeys)
+        .remove_members_from_group(&account, &group_id, member_pubkeys, reason)
         .await
         .map_err(ApiError::from)
 }
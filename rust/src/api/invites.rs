@@ -0,0 +1,221 @@
+//! Shareable group invite links and the join request flow they start.
+//!
+//! An admin creates an invite link for a group and shares its token out of band. Anyone
+//! holding the token can send an encrypted join request, carrying their key package, to
+//! the group's admins. An admin then approves the request, which adds the requester to
+//! the group with that key package, or declines it.
+
+use crate::api::{
+    error::ApiError, group_id_from_string, group_id_to_string, metadata::FlutterMetadata,
+};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use mdk_core::prelude::GroupId;
+use nostr_sdk::prelude::*;
+use whitenoise::{
+    Account, GroupInvite as WhitenoiseGroupInvite, JoinRequest as WhitenoiseJoinRequest,
+    UserSyncMode, Whitenoise,
+};
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct InviteLink {
+    /// Nostr-encoded token to share, e.g. as a link or QR code
+    pub token: String,
+    pub mls_group_id: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WhitenoiseGroupInvite> for InviteLink {
+    fn from(invite: WhitenoiseGroupInvite) -> Self {
+        Self {
+            token: invite.token,
+            mls_group_id: group_id_to_string(&invite.mls_group_id),
+            expires_at: invite.expires_at,
+            max_uses: invite.max_uses,
+            uses: invite.uses,
+            created_at: invite.created_at,
+        }
+    }
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct JoinRequest {
+    pub id: String,
+    pub mls_group_id: String,
+    pub requester_pubkey: String,
+    pub requester_metadata: FlutterMetadata,
+    pub created_at: DateTime<Utc>,
+}
+
+impl JoinRequest {
+    async fn from_whitenoise(
+        whitenoise: &Whitenoise,
+        request: WhitenoiseJoinRequest,
+    ) -> Result<Self, ApiError> {
+        let requester = whitenoise
+            .find_or_create_user_by_pubkey(&request.requester, UserSyncMode::Background)
+            .await?;
+        Ok(Self {
+            id: request.id.to_hex(),
+            mls_group_id: group_id_to_string(&request.mls_group_id),
+            requester_pubkey: request.requester.to_hex(),
+            requester_metadata: requester.metadata.into(),
+            created_at: request.created_at,
+        })
+    }
+}
+
+/// Fails with `PermissionDenied` unless the account is an admin of the group.
+async fn ensure_admin(
+    whitenoise: &Whitenoise,
+    account: &Account,
+    group_id: &GroupId,
+    message: &str,
+) -> Result<(), ApiError> {
+    let group = whitenoise.group(account, group_id).await?;
+    if !group.admin_pubkeys.contains(&account.pubkey) {
+        return Err(ApiError::PermissionDenied {
+            message: message.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Creates a shareable invite link for a group.
+///
+/// Only admins can create invite links. `expires_at` and `max_uses` are optional; an
+/// invite without them never expires and can be used any number of times.
+#[frb]
+pub async fn create_invite_link(
+    account_pubkey: String,
+    group_id: String,
+    expires_at: Option<DateTime<Utc>>,
+    max_uses: Option<u32>,
+) -> Result<InviteLink, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+
+    if expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(ApiError::Other {
+            message: "Invite expiry must be in the future".to_string(),
+        });
+    }
+    if max_uses == Some(0) {
+        return Err(ApiError::Other {
+            message: "Invite max uses must be greater than zero".to_string(),
+        });
+    }
+
+    ensure_admin(
+        whitenoise,
+        &account,
+        &group_id,
+        "Only admins can create invite links",
+    )
+    .await?;
+
+    let invite = whitenoise
+        .create_group_invite(&account, &group_id, expires_at, max_uses)
+        .await?;
+    Ok(invite.into())
+}
+
+/// Sends a join request for the group behind an invite token.
+///
+/// The request is encrypted to the group's admins and carries one of the account's key
+/// packages, so an approving admin can add the account without further lookups.
+#[frb]
+pub async fn request_join(account_pubkey: String, invite: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    whitenoise
+        .request_join(&account, invite.trim())
+        .await
+        .map_err(ApiError::from)
+}
+
+#[frb]
+pub async fn pending_join_requests(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<Vec<JoinRequest>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    ensure_admin(
+        whitenoise,
+        &account,
+        &group_id,
+        "Only admins can view join requests",
+    )
+    .await?;
+    let requests = whitenoise
+        .pending_join_requests(&account, &group_id)
+        .await?;
+
+    let mut join_requests = Vec::with_capacity(requests.len());
+    for request in requests {
+        join_requests.push(JoinRequest::from_whitenoise(whitenoise, request).await?);
+    }
+    Ok(join_requests)
+}
+
+/// Approves a join request, adding the requester to the group with their key package.
+///
+/// Only admins can approve or decline join requests.
+#[frb]
+pub async fn approve_join_request(
+    account_pubkey: String,
+    group_id: String,
+    request_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let request_id = EventId::parse(&request_id)?;
+    ensure_admin(
+        whitenoise,
+        &account,
+        &group_id,
+        "Only admins can approve join requests",
+    )
+    .await?;
+    whitenoise
+        .approve_join_request(&account, &group_id, &request_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+#[frb]
+pub async fn decline_join_request(
+    account_pubkey: String,
+    group_id: String,
+    request_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let request_id = EventId::parse(&request_id)?;
+    ensure_admin(
+        whitenoise,
+        &account,
+        &group_id,
+        "Only admins can decline join requests",
+    )
+    .await?;
+    whitenoise
+        .decline_join_request(&account, &group_id, &request_id)
+        .await
+        .map_err(ApiError::from)
+}
@@ -1,4 +1,6 @@
-use crate::api::{error::ApiError, group_id_from_string, group_id_to_string};
+use crate::api::{
+    error::ApiError, group_id_from_string, group_id_to_string, network::low_bandwidth_mode,
+};
 use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
@@ -77,13 +79,22 @@ pub async fn upload_chat_media(
     Ok(media_file.into())
 }
 
+/// Downloads and decrypts a chat media file.
+///
+/// Automatic downloads (`user_initiated` is `false`) are refused in low-bandwidth mode.
 #[frb]
 pub async fn download_chat_media(
     account_pubkey: String,
     group_id: String,
     original_file_hash: String,
+    user_initiated: bool,
 ) -> Result<MediaFile, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
+    if !user_initiated && low_bandwidth_mode(whitenoise).await? {
+        return Err(ApiError::Other {
+            message: "Media is not downloaded automatically in low-bandwidth mode".to_string(),
+        });
+    }
     let pubkey = PublicKey::parse(&account_pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
     let group_id = group_id_from_string(&group_id)?;
@@ -1,14 +1,28 @@
-use crate::api::{error::ApiError, media_files::MediaFile, utils::group_id_from_string};
+use crate::api::{
+    error::ApiError, groups::GroupPermissions, media_files::MediaFile, utils::group_id_from_string,
+};
 use chrono::{DateTime, TimeZone, Utc};
 use flutter_rust_bridge::frb;
+use mdk_core::prelude::group_types::Group as WhitenoiseGroup;
 use nostr_sdk::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use whitenoise::Account as WhitenoiseAccount;
 pub use whitenoise::{
     ChatMessage as WhitenoiseChatMessage, EmojiReaction as WhitenoiseEmojiReaction,
-    MediaFile as WhitenoiseMediaFile, MessageWithTokens as WhitenoiseMessageWithTokens,
-    ReactionSummary as WhitenoiseReactionSummary, SerializableToken as WhitenoiseSerializableToken,
+    GroupPermissions as WhitenoiseGroupPermissions, MediaFile as WhitenoiseMediaFile,
+    MessageWithTokens as WhitenoiseMessageWithTokens, ReactionSummary as WhitenoiseReactionSummary,
+    SerializableToken as WhitenoiseSerializableToken, SystemEvent as WhitenoiseSystemEvent,
     UserReaction as WhitenoiseUserReaction, Whitenoise,
 };
 
+/// Tag marking a deletion as an admin moderation action rather than an author retraction.
+///
+/// Core aggregation honors deletions carrying it from current admins of the group, in
+/// addition to deletions by a message's author.
+const MODERATION_TAG: &str = "moderation";
+
 /// Flutter-compatible message with tokens
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
@@ -37,6 +51,27 @@ pub struct ChatMessage {
     pub reactions: ReactionSummary,
     pub media_attachments: Vec<MediaFile>,
     pub kind: u16,
+    /// Set for system timeline entries, which are rendered instead of `content`
+    pub system_event: Option<SystemEvent>,
+}
+
+/// Flutter-compatible system timeline entry
+#[frb]
+#[derive(Debug, Clone)]
+pub enum SystemEvent {
+    DisappearingMessagesChanged {
+        changed_by: String, // PublicKey converted to hex string
+        duration_secs: Option<u64>,
+    },
+    MembersRemoved {
+        removed_by: String,   // PublicKey converted to hex string
+        members: Vec<String>, // PublicKeys converted to hex strings
+        reason: Option<String>,
+    },
+    PermissionsChanged {
+        changed_by: String, // PublicKey converted to hex string
+        permissions: GroupPermissions,
+    },
 }
 
 /// Flutter-compatible reaction summary
@@ -138,6 +173,36 @@ impl From<&WhitenoiseSerializableToken> for SerializableToken {
     }
 }
 
+impl From<&WhitenoiseSystemEvent> for SystemEvent {
+    fn from(event: &WhitenoiseSystemEvent) -> Self {
+        match event {
+            WhitenoiseSystemEvent::DisappearingMessagesChanged {
+                changed_by,
+                duration_secs,
+            } => Self::DisappearingMessagesChanged {
+                changed_by: changed_by.to_hex(),
+                duration_secs: *duration_secs,
+            },
+            WhitenoiseSystemEvent::MembersRemoved {
+                removed_by,
+                members,
+                reason,
+            } => Self::MembersRemoved {
+                removed_by: removed_by.to_hex(),
+                members: members.iter().map(|pk| pk.to_hex()).collect(),
+                reason: reason.clone(),
+            },
+            WhitenoiseSystemEvent::PermissionsChanged {
+                changed_by,
+                permissions,
+            } => Self::PermissionsChanged {
+                changed_by: changed_by.to_hex(),
+                permissions: permissions.clone().into(),
+            },
+        }
+    }
+}
+
 impl From<WhitenoiseSerializableToken> for SerializableToken {
     fn from(token: WhitenoiseSerializableToken) -> Self {
         (&token).into()
@@ -226,6 +291,7 @@ impl From<&WhitenoiseChatMessage> for ChatMessage {
                 .map(|media_file| media_file.into())
                 .collect(),
             kind: chat_message.kind,
+            system_event: chat_message.system_event.as_ref().map(|event| event.into()),
         }
     }
 }
@@ -236,6 +302,105 @@ impl From<WhitenoiseChatMessage> for ChatMessage {
     }
 }
 
+/// Returns whether a message kind counts as a post for the group's permission settings.
+///
+/// Reactions and deletions are not posts; every other kind is, so announcement-only
+/// groups and slow mode cannot be bypassed by sending a different kind.
+fn is_post(kind: u16) -> bool {
+    kind != Kind::Reaction.as_u16() && kind != Kind::EventDeletion.as_u16()
+}
+
+/// Checks the group's permission settings before sending a post as the account.
+///
+/// Admins are exempt from both the announcement-only setting and slow mode.
+async fn ensure_can_post(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group: &WhitenoiseGroup,
+    kind: u16,
+) -> Result<(), ApiError> {
+    if !is_post(kind) || group.admin_pubkeys.contains(&account.pubkey) {
+        return Ok(());
+    }
+    if group.permissions.admins_only_post {
+        return Err(ApiError::PermissionDenied {
+            message: "Only admins can post in this group".to_string(),
+        });
+    }
+    if let Some(slow_mode_secs) = group.permissions.slow_mode_secs {
+        let last_sent_at = whitenoise
+            .last_message_sent_at(account, &group.mls_group_id, &account.pubkey)
+            .await?;
+        if let Some(last_sent_at) = last_sent_at {
+            let next_allowed_at = last_sent_at + slow_mode_secs;
+            let now = Timestamp::now();
+            if now < next_allowed_at {
+                return Err(ApiError::PermissionDenied {
+                    message: format!(
+                        "Slow mode is on, next message allowed in {} seconds",
+                        next_allowed_at.as_u64() - now.as_u64()
+                    ),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drops incoming posts that broke the group's permission settings when they were sent.
+///
+/// Walks the timeline in order and tracks the settings from its `PermissionsChanged`
+/// entries, so posts made before a setting was turned on stay visible. A timeline
+/// without such entries, e.g. of a member who joined after the settings were made, is
+/// checked against the group's `current` settings. Posts from current admins are
+/// always kept.
+fn drop_permission_violations(
+    mut messages: Vec<WhitenoiseChatMessage>,
+    admins: &BTreeSet<PublicKey>,
+    current: &WhitenoiseGroupPermissions,
+) -> Vec<WhitenoiseChatMessage> {
+    messages.sort_by_key(|m| m.created_at);
+    let has_changes = messages.iter().any(|m| {
+        matches!(
+            m.system_event,
+            Some(WhitenoiseSystemEvent::PermissionsChanged { .. })
+        )
+    });
+    // Before the first recorded change the group still had its default settings
+    let mut permissions = if has_changes {
+        WhitenoiseGroupPermissions::default()
+    } else {
+        current.clone()
+    };
+    let mut last_post_at: HashMap<PublicKey, Timestamp> = HashMap::new();
+    messages.retain(|m| {
+        if let Some(WhitenoiseSystemEvent::PermissionsChanged {
+            permissions: changed,
+            ..
+        }) = &m.system_event
+        {
+            permissions = changed.clone();
+            return true;
+        }
+        if m.system_event.is_some() || !is_post(m.kind) || admins.contains(&m.author) {
+            return true;
+        }
+        if permissions.admins_only_post {
+            return false;
+        }
+        let too_soon = permissions
+            .slow_mode_secs
+            .zip(last_post_at.get(&m.author))
+            .is_some_and(|(secs, last)| m.created_at < *last + secs);
+        if too_soon {
+            return false;
+        }
+        last_post_at.insert(m.author, m.created_at);
+        true
+    });
+    messages
+}
+
 #[frb]
 pub async fn send_message_to_group(
     pubkey: String,
@@ -248,6 +413,20 @@ pub async fn send_message_to_group(
     let pubkey = PublicKey::parse(&pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
     let group_id = group_id_from_string(&group_id)?;
+    let group = whitenoise.group(&account, &group_id).await?;
+    ensure_can_post(whitenoise, &account, &group, kind).await?;
+
+    // Attach a NIP-40 expiration to the inner message so other members' clients purge it.
+    // Relays only see the kind-445 wrapper, so they keep the encrypted event as usual.
+    let tags = match group.disappearing_messages_secs {
+        Some(secs) => {
+            let mut tags = tags.unwrap_or_default();
+            tags.push(Tag::expiration(Timestamp::now() + secs));
+            Some(tags)
+        }
+        None => tags,
+    };
+
     let message_with_tokens = whitenoise
         .send_message_to_group(&account, &group_id, message, kind, tags)
         .await?;
@@ -261,9 +440,120 @@ pub async fn fetch_aggregated_messages_for_group(
 ) -> Result<Vec<ChatMessage>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
     let group_id = group_id_from_string(&group_id)?;
+    let group = whitenoise.group(&account, &group_id).await?;
     let messages = whitenoise
         .fetch_aggregated_messages_for_group(&pubkey, &group_id)
         .await?;
-    Ok(messages.into_iter().map(|m| m.into()).collect())
+    let messages = drop_permission_violations(messages, &group.admin_pubkeys, &group.permissions);
+    // Hide messages that expired since the last purge
+    let now = Timestamp::now();
+    Ok(messages
+        .into_iter()
+        .filter(|m| {
+            m.tags
+                .expiration()
+                .is_none_or(|expires_at| *expires_at > now)
+        })
+        .map(|m| m.into())
+        .collect())
+}
+
+/// Deletes another member's message as a group admin.
+///
+/// Publishes a deletion that references the message and is marked as a moderation
+/// action. Every member's core aggregation accepts it only if the sender is an admin of
+/// the group, so the message is hidden for everyone.
+#[frb]
+pub async fn moderate_delete_message(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let message_id = EventId::parse(&message_id)?;
+
+    let group = whitenoise.group(&account, &group_id).await?;
+    if !group.admin_pubkeys.contains(&pubkey) {
+        return Err(ApiError::PermissionDenied {
+            message: "Only admins can delete other members' messages".to_string(),
+        });
+    }
+    let in_group = whitenoise
+        .fetch_aggregated_messages_for_group(&pubkey, &group_id)
+        .await?
+        .iter()
+        .any(|m| m.id == message_id.to_hex());
+    if !in_group {
+        return Err(ApiError::Other {
+            message: "Message not found in this group".to_string(),
+        });
+    }
+
+    let tags = vec![
+        Tag::event(message_id),
+        Tag::custom(TagKind::custom(MODERATION_TAG), Vec::<String>::new()),
+    ];
+    whitenoise
+        .send_message_to_group(
+            &account,
+            &group_id,
+            String::new(),
+            Kind::EventDeletion.as_u16(),
+            Some(tags),
+        )
+        .await?;
+    Ok(())
+}
+
+/// How often expired messages are purged in the background
+const EXPIRED_MESSAGE_PURGE_INTERVAL: Duration = Duration::from_secs(60);
+
+static EXPIRED_MESSAGE_PURGE_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Starts the background task that purges expired messages of all accounts.
+///
+/// Called once Whitenoise is initialized; later calls are no-ops.
+pub(crate) fn spawn_expired_message_purge() {
+    if EXPIRED_MESSAGE_PURGE_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(EXPIRED_MESSAGE_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Ok(whitenoise) = Whitenoise::get_instance() else {
+                continue;
+            };
+            let Ok(accounts) = whitenoise.all_accounts().await else {
+                continue;
+            };
+            for account in accounts {
+                // A failed purge is retried on the next tick
+                let _ = whitenoise.purge_expired_messages(&account).await;
+            }
+        }
+    });
+}
+
+/// Deletes decrypted messages whose NIP-40 expiration has passed, together with their
+/// downloaded media files, from local storage for all groups of the account.
+///
+/// # Returns
+///
+/// The number of messages that were purged
+///
+/// Expired messages are also purged every minute in the background; call this to purge
+/// right away, e.g. when a chat is opened.
+#[frb]
+pub async fn purge_expired_messages(pubkey: String) -> Result<u64, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let purged = whitenoise.purge_expired_messages(&account).await?;
+    Ok(purged as u64)
 }
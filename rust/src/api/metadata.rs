@@ -6,18 +6,106 @@
 //! # Key Features
 //! - Flutter-compatible representation of Nostr metadata
 //! - Conversion between core library types and Flutter-compatible types
-//! - Custom field management with proper serialization
+//! - Typed custom fields that round-trip losslessly through Flutter
 //! - Standard Nostr metadata fields (NIP-01 compliant)
 
 use flutter_rust_bridge::frb;
 pub use nostr_sdk::Metadata;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// Flutter-compatible representation of a JSON value in a custom metadata field.
+///
+/// Each JSON type has its own variant, so a string such as `"123"` stays a string and
+/// nested arrays and objects keep their structure across the bridge.
+#[frb]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    Null,
+    Bool(bool),
+    /// Integers that fit in an `i64`
+    Integer(i64),
+    /// Integers above `i64::MAX`
+    UnsignedInteger(u64),
+    Float(f64),
+    String(String),
+    Array(Vec<MetadataValue>),
+    Object(HashMap<String, MetadataValue>),
+}
+
+impl From<serde_json::Value> for MetadataValue {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => MetadataValue::Null,
+            serde_json::Value::Bool(b) => MetadataValue::Bool(b),
+            serde_json::Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    MetadataValue::Integer(i)
+                } else if let Some(u) = n.as_u64() {
+                    MetadataValue::UnsignedInteger(u)
+                } else {
+                    // serde_json numbers are always an i64, a u64 or a finite f64
+                    MetadataValue::Float(n.as_f64().unwrap_or_default())
+                }
+            }
+            serde_json::Value::String(s) => MetadataValue::String(s),
+            serde_json::Value::Array(values) => {
+                MetadataValue::Array(values.into_iter().map(MetadataValue::from).collect())
+            }
+            serde_json::Value::Object(map) => MetadataValue::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, MetadataValue::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<MetadataValue> for serde_json::Value {
+    fn from(value: MetadataValue) -> Self {
+        match value {
+            MetadataValue::Null => serde_json::Value::Null,
+            MetadataValue::Bool(b) => serde_json::Value::Bool(b),
+            MetadataValue::Integer(i) => serde_json::Value::from(i),
+            MetadataValue::UnsignedInteger(u) => serde_json::Value::from(u),
+            // JSON has no NaN or infinity, so those become null like in serde_json
+            MetadataValue::Float(f) => serde_json::Number::from_f64(f)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            MetadataValue::String(s) => serde_json::Value::String(s),
+            MetadataValue::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(serde_json::Value::from).collect())
+            }
+            MetadataValue::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, serde_json::Value::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn custom_to_flutter(
+    custom: BTreeMap<String, serde_json::Value>,
+) -> HashMap<String, MetadataValue> {
+    custom
+        .into_iter()
+        .map(|(key, value)| (key, value.into()))
+        .collect()
+}
+
+fn custom_from_flutter(
+    custom: HashMap<String, MetadataValue>,
+) -> BTreeMap<String, serde_json::Value> {
+    custom
+        .into_iter()
+        .map(|(key, value)| (key, value.into()))
+        .collect()
+}
 
 /// Flutter-compatible representation of user metadata following Nostr protocol standards.
 ///
 /// This struct provides a bridge between the core library's `Metadata` type and Flutter's
-/// type system. The `custom` field is kept private to avoid flutter_rust_bridge
-/// auto-generation issues and is accessed through getter/setter methods.
+/// type system.
 ///
 /// # Nostr Metadata Fields
 /// Most fields correspond to standard Nostr metadata as defined in NIP-01 and related NIPs.
@@ -42,30 +130,15 @@ pub struct FlutterMetadata {
     pub lud06: Option<String>,
     /// Lightning Network address in newer format
     pub lud16: Option<String>,
-    /// Additional custom metadata fields
-    pub custom: HashMap<String, String>,
+    /// Additional custom metadata fields, keeping their JSON types
+    pub custom: HashMap<String, MetadataValue>,
 }
 
 /// Implements conversion from the core `Metadata` type to our Flutter-compatible `FlutterMetadata` type.
 ///
-/// This conversion handles the transformation of the `custom` field from `BTreeMap<String, serde_json::Value>`
-/// to `HashMap<String, String>`, converting JSON values to their string representations.
-/// Arrays and objects are serialized to JSON strings.
+/// Custom fields are converted to [`MetadataValue`]s, keeping their JSON types.
 impl From<Metadata> for FlutterMetadata {
     fn from(metadata: Metadata) -> Self {
-        // Convert BTreeMap<String, serde_json::Value> to HashMap<String, String>
-        let custom_string_map = metadata
-            .custom
-            .iter()
-            .map(|(key, value)| {
-                let string_value = match value {
-                    serde_json::Value::String(s) => s.clone(),
-                    _ => serde_json::to_string(value).unwrap_or_else(|_| "null".to_string()),
-                };
-                (key.clone(), string_value)
-            })
-            .collect();
-
         FlutterMetadata {
             name: metadata.name,
             display_name: metadata.display_name,
@@ -76,7 +149,7 @@ impl From<Metadata> for FlutterMetadata {
             nip05: metadata.nip05,
             lud06: metadata.lud06,
             lud16: metadata.lud16,
-            custom: custom_string_map,
+            custom: custom_to_flutter(metadata.custom),
         }
     }
 }
@@ -87,53 +160,16 @@ impl From<Metadata> for FlutterMetadata {
 /// without taking ownership.
 impl From<&Metadata> for FlutterMetadata {
     fn from(metadata: &Metadata) -> Self {
-        // Convert BTreeMap<String, serde_json::Value> to HashMap<String, String>
-        let custom_string_map = metadata
-            .custom
-            .iter()
-            .map(|(key, value)| {
-                let string_value = match value {
-                    serde_json::Value::String(s) => s.clone(),
-                    _ => serde_json::to_string(value).unwrap_or_else(|_| "null".to_string()),
-                };
-                (key.clone(), string_value)
-            })
-            .collect();
-
-        FlutterMetadata {
-            name: metadata.name.clone(),
-            display_name: metadata.display_name.clone(),
-            about: metadata.about.clone(),
-            picture: metadata.picture.clone(),
-            banner: metadata.banner.clone(),
-            website: metadata.website.clone(),
-            nip05: metadata.nip05.clone(),
-            lud06: metadata.lud06.clone(),
-            lud16: metadata.lud16.clone(),
-            custom: custom_string_map,
-        }
+        metadata.clone().into()
     }
 }
 
 /// Implements conversion from our Flutter-compatible `FlutterMetadata` type back to the core `Metadata` type.
 ///
-/// This conversion reverses the process performed by the `From<Metadata>` implementation,
-/// attempting to parse string values back to JSON where possible, falling back to
-/// string values when parsing fails.
+/// This conversion reverses the `From<Metadata>` implementation exactly, so custom
+/// fields are published with the JSON types they were read with.
 impl From<FlutterMetadata> for Metadata {
     fn from(metadata_data: FlutterMetadata) -> Self {
-        // Convert HashMap<String, String> back to BTreeMap<String, serde_json::Value>
-        let custom_value_map = metadata_data
-            .custom
-            .iter()
-            .map(|(key, value)| {
-                // Try to parse as JSON first, fall back to string
-                let json_value = serde_json::from_str(value)
-                    .unwrap_or_else(|_| serde_json::Value::String(value.clone()));
-                (key.clone(), json_value)
-            })
-            .collect();
-
         Metadata {
             name: metadata_data.name,
             display_name: metadata_data.display_name,
@@ -144,7 +180,7 @@ impl From<FlutterMetadata> for Metadata {
             nip05: metadata_data.nip05,
             lud06: metadata_data.lud06,
             lud16: metadata_data.lud16,
-            custom: custom_value_map,
+            custom: custom_from_flutter(metadata_data.custom),
         }
     }
 }
@@ -155,29 +191,89 @@ impl From<FlutterMetadata> for Metadata {
 /// without taking ownership.
 impl From<&FlutterMetadata> for Metadata {
     fn from(metadata_data: &FlutterMetadata) -> Self {
-        // Convert HashMap<String, String> back to BTreeMap<String, serde_json::Value>
-        let custom_value_map = metadata_data
-            .custom
-            .iter()
-            .map(|(key, value)| {
-                // Try to parse as JSON first, fall back to string
-                let json_value = serde_json::from_str(value)
-                    .unwrap_or_else(|_| serde_json::Value::String(value.clone()));
-                (key.clone(), json_value)
-            })
-            .collect();
+        metadata_data.clone().into()
+    }
+}
 
-        Metadata {
-            name: metadata_data.name.clone(),
-            display_name: metadata_data.display_name.clone(),
-            about: metadata_data.about.clone(),
-            picture: metadata_data.picture.clone(),
-            banner: metadata_data.banner.clone(),
-            website: metadata_data.website.clone(),
-            nip05: metadata_data.nip05.clone(),
-            lud06: metadata_data.lud06.clone(),
-            lud16: metadata_data.lud16.clone(),
-            custom: custom_value_map,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn json_value() -> impl Strategy<Value = serde_json::Value> {
+        let leaf = prop_oneof![
+            Just(serde_json::Value::Null),
+            any::<bool>().prop_map(serde_json::Value::Bool),
+            any::<i64>().prop_map(serde_json::Value::from),
+            any::<u64>().prop_map(serde_json::Value::from),
+            any::<f64>()
+                .prop_filter_map("JSON numbers are finite", serde_json::Number::from_f64)
+                .prop_map(serde_json::Value::Number),
+            any::<String>().prop_map(serde_json::Value::String),
+        ];
+        leaf.prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(serde_json::Value::Array),
+                prop::collection::btree_map(any::<String>(), inner, 0..8)
+                    .prop_map(|map| serde_json::Value::Object(map.into_iter().collect())),
+            ]
+        })
+    }
+
+    prop_compose! {
+        fn metadata()(
+            name in any::<Option<String>>(),
+            display_name in any::<Option<String>>(),
+            about in any::<Option<String>>(),
+            picture in any::<Option<String>>(),
+            banner in any::<Option<String>>(),
+            website in any::<Option<String>>(),
+            nip05 in any::<Option<String>>(),
+            lud06 in any::<Option<String>>(),
+            lud16 in any::<Option<String>>(),
+            custom in prop::collection::btree_map(any::<String>(), json_value(), 0..8),
+        ) -> Metadata {
+            Metadata {
+                name,
+                display_name,
+                about,
+                picture,
+                banner,
+                website,
+                nip05,
+                lud06,
+                lud16,
+                custom,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn metadata_round_trip_is_identity(metadata in metadata()) {
+            let round_tripped = Metadata::from(FlutterMetadata::from(&metadata));
+            prop_assert_eq!(round_tripped, metadata);
+        }
+
+        #[test]
+        fn custom_value_round_trip_is_identity(value in json_value()) {
+            let round_tripped = serde_json::Value::from(MetadataValue::from(value.clone()));
+            prop_assert_eq!(round_tripped, value);
+        }
+    }
+
+    #[test]
+    fn json_like_strings_stay_strings() {
+        for text in ["123", "true", "null", "[1,2]", "{\"a\":1}"] {
+            let mut metadata = FlutterMetadata::from(Metadata::default());
+            metadata
+                .custom
+                .insert("field".to_string(), MetadataValue::String(text.to_string()));
+            let metadata = Metadata::from(metadata);
+            assert_eq!(
+                metadata.custom.get("field"),
+                Some(&serde_json::Value::String(text.to_string()))
+            );
         }
     }
 }
@@ -19,6 +19,8 @@ pub struct WhitenoiseConfig {
     pub data_dir: String,
     /// Path to the directory where log files will be written
     pub logs_dir: String,
+    /// Optional SOCKS5 proxy (e.g. Tor) used from startup until changed in the app settings
+    pub proxy_url: Option<String>,
 }
 
 impl From<whitenoise::WhitenoiseConfig> for WhitenoiseConfig {
@@ -26,6 +28,7 @@ impl From<whitenoise::WhitenoiseConfig> for WhitenoiseConfig {
         Self {
             data_dir: config.data_dir.to_string_lossy().to_string(),
             logs_dir: config.logs_dir.to_string_lossy().to_string(),
+            proxy_url: config.proxy.map(|address| format!("socks5://{address}")),
         }
     }
 }
@@ -38,6 +41,7 @@ impl From<whitenoise::WhitenoiseConfig> for WhitenoiseConfig {
 /// # Parameters
 /// * `data_dir` - Path string for data directory where app data will be stored
 /// * `logs_dir` - Path string for logs directory where log files will be written
+/// * `proxy_url` - Optional SOCKS5 proxy address, e.g. `socks5://127.0.0.1:9050` or `localhost:9050`
 ///
 /// # Returns
 /// A WhitenoiseConfig object ready for initialization
@@ -46,24 +50,37 @@ impl From<whitenoise::WhitenoiseConfig> for WhitenoiseConfig {
 /// ```rust
 /// let config = create_whitenoise_config(
 ///     "/path/to/data".to_string(),
-///     "/path/to/logs".to_string()
+///     "/path/to/logs".to_string(),
+///     None
 /// );
 /// ```
 #[frb]
-pub fn create_whitenoise_config(data_dir: String, logs_dir: String) -> WhitenoiseConfig {
-    WhitenoiseConfig { data_dir, logs_dir }
+pub fn create_whitenoise_config(
+    data_dir: String,
+    logs_dir: String,
+    proxy_url: Option<String>,
+) -> WhitenoiseConfig {
+    WhitenoiseConfig {
+        data_dir,
+        logs_dir,
+        proxy_url,
+    }
 }
 
 // Declare the modules
 pub mod accounts;
 pub mod error;
 pub mod groups;
+pub mod invites;
 pub mod media_files;
 pub mod messages;
 pub mod metadata;
+pub mod network;
+pub mod proxy;
 pub mod relays;
 pub mod users;
 pub mod utils;
+pub mod verification;
 pub use utils::{group_id_from_string, group_id_to_string};
 pub mod welcomes;
 
@@ -71,21 +88,31 @@ pub mod welcomes;
 pub use accounts::*;
 pub use error::*;
 pub use groups::*;
+pub use invites::*;
 pub use media_files::*;
 pub use messages::*;
 pub use metadata::*;
+pub use network::*;
+pub use proxy::*;
 pub use relays::*;
 pub use users::*;
 pub use utils::*;
+pub use verification::*;
 pub use welcomes::*;
 
 #[frb]
 pub async fn initialize_whitenoise(config: WhitenoiseConfig) -> Result<(), ApiError> {
-    let core_config =
+    let mut core_config =
         whitenoise::WhitenoiseConfig::new(Path::new(&config.data_dir), Path::new(&config.logs_dir));
-    Whitenoise::initialize_whitenoise(core_config)
-        .await
-        .map_err(ApiError::from)
+    if let Some(proxy_url) = &config.proxy_url {
+        core_config.proxy = Some(proxy::parse_proxy_address(proxy_url).await?);
+    }
+    proxy::set_startup_proxy(core_config.proxy);
+    Whitenoise::initialize_whitenoise(core_config).await?;
+    messages::spawn_expired_message_purge();
+    verification::spawn_key_change_monitor();
+    welcomes::spawn_welcome_auto_accept();
+    Ok(())
 }
 
 #[frb]
@@ -108,3 +135,19 @@ pub async fn update_theme_mode(theme_mode: ThemeMode) -> Result<(), ApiError> {
         .await
         .map_err(ApiError::from)
 }
+
+#[frb]
+pub async fn get_welcome_policy() -> Result<WelcomePolicy, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let settings = whitenoise.app_settings().await?;
+    Ok(settings.welcome_policy.into())
+}
+
+#[frb]
+pub async fn update_welcome_policy(welcome_policy: WelcomePolicy) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    whitenoise
+        .update_welcome_policy(welcome_policy.into())
+        .await
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,71 @@
+//! Network lifecycle controls for app backgrounding and metered connections.
+//!
+//! Dart calls [`pause_network`] when the app goes to the background (or the user turns
+//! on data saver) and [`resume_network`] when it comes back. While paused, all relay
+//! pools are disconnected. On resume, every subscription is re-opened with a `since`
+//! filter starting at the last event seen from each relay, so nothing published in
+//! the meantime is missed.
+
+use crate::api::error::ApiError;
+use flutter_rust_bridge::frb;
+use tokio::sync::Mutex;
+use whitenoise::Whitenoise;
+
+/// Serializes pause and resume so a check and the transition it guards can't interleave
+static NETWORK_TRANSITION: Mutex<()> = Mutex::const_new(());
+
+/// Disconnects all relay pools of all accounts.
+///
+/// Calling this while already paused is a no-op.
+#[frb]
+pub async fn pause_network() -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let _transition = NETWORK_TRANSITION.lock().await;
+    if whitenoise.is_network_paused().await {
+        return Ok(());
+    }
+    whitenoise.pause_network().await.map_err(ApiError::from)
+}
+
+/// Reconnects all relay pools and catches up subscriptions from the last-seen
+/// timestamp of each relay.
+///
+/// Calling this while not paused is a no-op.
+#[frb]
+pub async fn resume_network() -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let _transition = NETWORK_TRANSITION.lock().await;
+    if !whitenoise.is_network_paused().await {
+        return Ok(());
+    }
+    whitenoise.resume_network().await.map_err(ApiError::from)
+}
+
+#[frb]
+pub async fn is_network_paused() -> Result<bool, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    Ok(whitenoise.is_network_paused().await)
+}
+
+pub(crate) async fn low_bandwidth_mode(whitenoise: &Whitenoise) -> Result<bool, ApiError> {
+    Ok(whitenoise.app_settings().await?.low_bandwidth_mode)
+}
+
+/// Returns whether low-bandwidth mode is on.
+///
+/// In low-bandwidth mode, cached user data is served as is instead of being refreshed
+/// from relays, and `download_chat_media` only downloads on an explicit user action.
+#[frb]
+pub async fn is_low_bandwidth_mode() -> Result<bool, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    low_bandwidth_mode(whitenoise).await
+}
+
+#[frb]
+pub async fn update_low_bandwidth_mode(enabled: bool) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    whitenoise
+        .update_low_bandwidth_mode(enabled)
+        .await
+        .map_err(ApiError::from)
+}
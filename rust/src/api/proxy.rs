@@ -0,0 +1,185 @@
+//! SOCKS5 proxy settings for relay and Blossom traffic.
+//!
+//! When a proxy is set, relay websockets, NIP-05 lookups and all media HTTP requests
+//! (chat media uploads and downloads, group images and profile pictures) are routed
+//! through it.
+//! Individual relays can opt out, and `.onion` relay URLs are accepted only while a
+//! proxy is configured since they are unreachable otherwise.
+
+use crate::api::{
+    error::ApiError,
+    relays::{PRIVATE_RELAYS_ALLOWED, normalize_relay_url},
+};
+use flutter_rust_bridge::frb;
+use std::net::SocketAddr;
+use std::sync::{Mutex, PoisonError};
+use tokio::net::lookup_host;
+use whitenoise::{ProxySettings as WhitenoiseProxySettings, Whitenoise};
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct ProxySettings {
+    /// Proxy address such as `socks5://127.0.0.1:9050` or `127.0.0.1:9050`
+    pub proxy_url: String,
+    /// Relays that connect directly instead of through the proxy
+    pub bypass_relays: Vec<String>,
+}
+
+impl From<WhitenoiseProxySettings> for ProxySettings {
+    fn from(settings: WhitenoiseProxySettings) -> Self {
+        Self {
+            proxy_url: format!("socks5://{}", settings.address),
+            bypass_relays: settings
+                .bypass_relays
+                .iter()
+                .map(|url| url.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl ProxySettings {
+    /// Validates the settings, resolving the proxy host name if needed.
+    async fn into_whitenoise(self) -> Result<WhitenoiseProxySettings, ApiError> {
+        let bypass_relays = self
+            .bypass_relays
+            .iter()
+            .map(|url| normalize_relay_url(url, true, PRIVATE_RELAYS_ALLOWED))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(WhitenoiseProxySettings {
+            address: parse_proxy_address(&self.proxy_url).await?,
+            bypass_relays,
+        })
+    }
+}
+
+/// Proxy passed in `WhitenoiseConfig.proxy_url`, in effect until the app settings change
+static STARTUP_PROXY: Mutex<Option<SocketAddr>> = Mutex::new(None);
+
+/// Strips an optional `socks5://` or `socks5h://` prefix and trailing slash, leaving `host:port`.
+fn proxy_host_port(proxy_url: &str) -> &str {
+    proxy_url
+        .strip_prefix("socks5://")
+        .or_else(|| proxy_url.strip_prefix("socks5h://"))
+        .unwrap_or(proxy_url)
+        .trim_end_matches('/')
+}
+
+/// Parses a SOCKS5 proxy address, with or without a `socks5://` or `socks5h://` prefix.
+///
+/// Accepts an IP address or a host name such as `localhost:9050`. Host names are
+/// resolved on Tokio's blocking pool so the caller's executor isn't stalled.
+pub(crate) async fn parse_proxy_address(proxy_url: &str) -> Result<SocketAddr, ApiError> {
+    let proxy_url = proxy_url.trim();
+    let resolved = match lookup_host(proxy_host_port(proxy_url)).await {
+        Ok(mut addresses) => addresses.next(),
+        Err(_) => None,
+    };
+    resolved.ok_or_else(|| ApiError::Other {
+        message: format!("Invalid SOCKS5 proxy address: {proxy_url}"),
+    })
+}
+
+/// Records the proxy Whitenoise was initialized with.
+pub(crate) fn set_startup_proxy(address: Option<SocketAddr>) {
+    *STARTUP_PROXY.lock().unwrap_or_else(PoisonError::into_inner) = address;
+}
+
+fn startup_proxy() -> Option<SocketAddr> {
+    *STARTUP_PROXY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the proxy currently in effect: the one in the app settings or, until those
+/// are changed, the one Whitenoise was initialized with.
+async fn active_proxy(whitenoise: &Whitenoise) -> Result<Option<ProxySettings>, ApiError> {
+    let settings = whitenoise.app_settings().await?;
+    Ok(match settings.proxy {
+        Some(proxy) => Some(proxy.into()),
+        None => startup_proxy().map(|address| ProxySettings {
+            proxy_url: format!("socks5://{address}"),
+            bypass_relays: Vec::new(),
+        }),
+    })
+}
+
+/// Returns whether `.onion` relays can be reached with the proxy in effect.
+pub(crate) async fn onion_relays_allowed(whitenoise: &Whitenoise) -> Result<bool, ApiError> {
+    Ok(active_proxy(whitenoise).await?.is_some())
+}
+
+#[frb]
+pub async fn get_proxy_settings() -> Result<Option<ProxySettings>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    active_proxy(whitenoise).await
+}
+
+/// Sets or, with `None`, clears the proxy and reconnects all relays accordingly.
+///
+/// This replaces the proxy Whitenoise was initialized with as well.
+#[frb]
+pub async fn update_proxy_settings(proxy_settings: Option<ProxySettings>) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let proxy = match proxy_settings {
+        Some(settings) => Some(settings.into_whitenoise().await?),
+        None => None,
+    };
+    whitenoise.update_proxy_settings(proxy).await?;
+    set_startup_proxy(None);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(proxy_url: &str) -> Result<SocketAddr, ApiError> {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(parse_proxy_address(proxy_url))
+    }
+
+    #[test]
+    fn test_proxy_host_port_strips_scheme_and_slash() {
+        assert_eq!(proxy_host_port("socks5://127.0.0.1:9050"), "127.0.0.1:9050");
+        assert_eq!(
+            proxy_host_port("socks5h://tor.local:9050/"),
+            "tor.local:9050"
+        );
+        assert_eq!(proxy_host_port("127.0.0.1:9050"), "127.0.0.1:9050");
+    }
+
+    #[test]
+    fn test_parse_proxy_address_accepts_ip_addresses() {
+        let expected: SocketAddr = "127.0.0.1:9050".parse().unwrap();
+        assert_eq!(parse("socks5://127.0.0.1:9050").unwrap(), expected);
+        assert_eq!(parse("  127.0.0.1:9050/ ").unwrap(), expected);
+        assert_eq!(
+            parse("socks5h://[::1]:9050").unwrap(),
+            "[::1]:9050".parse::<SocketAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_proxy_address_resolves_host_names() {
+        let address = parse("socks5://localhost:9050").unwrap();
+        assert!(address.ip().is_loopback());
+        assert_eq!(address.port(), 9050);
+    }
+
+    #[test]
+    fn test_parse_proxy_address_rejects_invalid_input() {
+        for proxy_url in [
+            "",
+            "socks5://",
+            "127.0.0.1",
+            "127.0.0.1:port",
+            "http://127.0.0.1:9050",
+        ] {
+            assert!(
+                matches!(parse(proxy_url), Err(ApiError::Other { .. })),
+                "{proxy_url} should be rejected"
+            );
+        }
+    }
+}
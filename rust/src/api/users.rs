@@ -1,9 +1,18 @@
 use crate::api::relays::Relay;
-use crate::api::{ApiError, metadata::FlutterMetadata};
+use crate::api::{
+    ApiError, metadata::FlutterMetadata, network::low_bandwidth_mode,
+    utils::parse_pubkey_with_hints,
+};
+use crate::frb_generated::StreamSink;
 use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
-use whitenoise::{RelayType, User as WhitenoiseUser, UserSyncMode, Whitenoise};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use whitenoise::{
+    Nip05Status as WhitenoiseNip05Status, RelayType, User as WhitenoiseUser, UserSyncMode,
+    Whitenoise, WhitenoiseError,
+};
 
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
@@ -12,6 +21,14 @@ pub struct User {
     pub metadata: FlutterMetadata,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the user's data was last fetched from relays, `None` if never
+    pub last_fetched_at: Option<DateTime<Utc>>,
+    /// Relays the last fetch got the user's data from
+    pub fetched_from_relays: Vec<String>,
+    /// Whether `metadata.nip05` points back to this user
+    pub nip05_status: Nip05Status,
+    /// When `nip05_status` was last checked, `None` if never
+    pub nip05_checked_at: Option<DateTime<Utc>>,
 }
 
 impl From<WhitenoiseUser> for User {
@@ -21,41 +38,270 @@ impl From<WhitenoiseUser> for User {
             metadata: user.metadata.into(),
             created_at: user.created_at,
             updated_at: user.updated_at,
+            last_fetched_at: user.last_synced_at,
+            fetched_from_relays: user
+                .synced_from_relays
+                .iter()
+                .map(|url| url.to_string())
+                .collect(),
+            nip05_status: user.nip05_status.into(),
+            nip05_checked_at: user.nip05_checked_at,
         }
     }
 }
 
+/// Result of checking a user's NIP-05 identifier against the domain's
+/// `.well-known/nostr.json`.
+///
+/// The core caches the result and checks again once it is older than a day, or right
+/// away when the user's metadata changes.
 #[frb]
-pub async fn get_user(pubkey: String, blocking_data_sync: bool) -> Result<User, ApiError> {
-    let whitenoise = Whitenoise::get_instance()?;
-    let pubkey = PublicKey::parse(&pubkey)?;
-    let sync_mode = if blocking_data_sync {
-        UserSyncMode::Blocking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nip05Status {
+    // Unverified: The user has no NIP-05 identifier or it has not been checked yet
+    Unverified,
+    // Verified: The domain maps the identifier to the user's pubkey
+    Verified,
+    // Mismatch: The domain maps the identifier to another pubkey or does not list it
+    Mismatch,
+    // Unreachable: The domain could not be reached or did not return valid JSON
+    Unreachable,
+}
+
+impl From<WhitenoiseNip05Status> for Nip05Status {
+    fn from(status: WhitenoiseNip05Status) -> Self {
+        match status {
+            WhitenoiseNip05Status::Unverified => Nip05Status::Unverified,
+            WhitenoiseNip05Status::Verified => Nip05Status::Verified,
+            WhitenoiseNip05Status::Mismatch => Nip05Status::Mismatch,
+            WhitenoiseNip05Status::Unreachable => Nip05Status::Unreachable,
+        }
+    }
+}
+
+/// How a user lookup balances cached data against fetching from relays
+#[frb]
+#[derive(Debug, Clone, Copy)]
+pub enum SyncPolicy {
+    /// Only return cached data, never touch the network; fails for unknown users
+    CacheOnly,
+    /// Return cached data right away and refresh in the background if it is older than
+    /// `max_age_secs` (or the user is unknown)
+    StaleWhileRevalidate { max_age_secs: u64 },
+    /// Always fetch from relays before returning
+    ForceRefresh,
+    /// Fetch from relays, but return cached data if that takes longer than `timeout_ms`
+    RefreshWithTimeout { timeout_ms: u64 },
+}
+
+/// Returns whether a cached user was fetched from relays within `max_age`.
+fn is_fresh(user: &WhitenoiseUser, max_age: Duration) -> bool {
+    user.last_synced_at.is_some_and(|fetched_at| {
+        Utc::now()
+            .signed_duration_since(fetched_at)
+            .to_std()
+            .is_ok_and(|age| age <= max_age)
+    })
+}
+
+/// Returns the cached user, or `None` if the user is unknown.
+///
+/// Database errors are propagated instead of being treated as a cache miss.
+async fn find_cached_user(
+    whitenoise: &Whitenoise,
+    pubkey: &PublicKey,
+) -> Result<Option<WhitenoiseUser>, ApiError> {
+    match whitenoise.find_user_by_pubkey(pubkey).await {
+        Ok(user) => Ok(Some(user)),
+        Err(WhitenoiseError::UserNotFound) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Adjusts `policy` for low-bandwidth mode, where cached users are served as is.
+///
+/// Only `ForceRefresh`, which is meant for explicit user actions, still refreshes cached
+/// users. Unknown users are fetched under every policy except `CacheOnly`.
+async fn effective_sync_policy(
+    whitenoise: &Whitenoise,
+    policy: SyncPolicy,
+) -> Result<SyncPolicy, ApiError> {
+    if !low_bandwidth_mode(whitenoise).await? {
+        return Ok(policy);
+    }
+    Ok(match policy {
+        SyncPolicy::StaleWhileRevalidate { .. } | SyncPolicy::RefreshWithTimeout { .. } => {
+            SyncPolicy::StaleWhileRevalidate {
+                max_age_secs: u64::MAX,
+            }
+        }
+        SyncPolicy::CacheOnly | SyncPolicy::ForceRefresh => policy,
+    })
+}
+
+/// Time allowed for each relay query of an outbox lookup
+const OUTBOX_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Returns the relays a NIP-65 relay list marks for writing.
+///
+/// Relays without a read or write marker are used for both.
+fn write_relays(relay_list: &Event) -> Vec<RelayUrl> {
+    nip65::extract_relay_list(relay_list)
+        .filter(|(_, metadata)| !matches!(metadata, Some(RelayMetadata::Read)))
+        .map(|(url, _)| url.clone())
+        .collect()
+}
+
+/// Concatenates relay lists, dropping duplicates while keeping the first occurrence.
+fn merge_relays(first: &[RelayUrl], second: &[RelayUrl]) -> Vec<RelayUrl> {
+    let mut seen = HashSet::new();
+    first
+        .iter()
+        .chain(second)
+        .filter(|url| seen.insert(*url))
+        .cloned()
+        .collect()
+}
+
+/// Fetches a user from their own write relays, following the outbox model.
+///
+/// The user's NIP-65 relay list is looked up on the indexer relays and the user's stored
+/// relay hints. Metadata, relay lists and key packages are then fetched from the write
+/// relays it names, which are stored as hints for later lookups. Users without a relay
+/// list are fetched from the indexer relays and hints instead.
+async fn fetch_user_via_outbox(
+    whitenoise: &Whitenoise,
+    pubkey: &PublicKey,
+) -> Result<WhitenoiseUser, ApiError> {
+    let hints = whitenoise.user_relay_hints(pubkey).await?;
+    let discovery_relays = merge_relays(&whitenoise.indexer_relays().await?, &hints);
+
+    let relay_list_filter = Filter::new().author(*pubkey).kind(Kind::RelayList);
+    let relay_lists = whitenoise
+        .fetch_events_from_relays(&discovery_relays, relay_list_filter, OUTBOX_FETCH_TIMEOUT)
+        .await?;
+    // Events are sorted newest first, and only the newest relay list is current
+    let outbox_relays = relay_lists.first().map(write_relays).unwrap_or_default();
+
+    let source_relays = if outbox_relays.is_empty() {
+        discovery_relays
     } else {
-        UserSyncMode::Background
+        whitenoise
+            .add_user_relay_hints(pubkey, &outbox_relays)
+            .await?;
+        merge_relays(&outbox_relays, &hints)
+    };
+    let user_filter = Filter::new().author(*pubkey).kinds([
+        Kind::Metadata,
+        Kind::RelayList,
+        Kind::MlsKeyPackageRelays,
+        Kind::MlsKeyPackage,
+    ]);
+    let events = whitenoise
+        .fetch_events_from_relays(&source_relays, user_filter, OUTBOX_FETCH_TIMEOUT)
+        .await?;
+    Ok(whitenoise
+        .store_user_events(pubkey, events, &source_relays)
+        .await?)
+}
+
+/// Looks up a user according to `policy`.
+///
+/// Fetches go to the user's own write relays, see [`fetch_user_via_outbox`].
+pub(crate) async fn find_user_with_policy(
+    whitenoise: &'static Whitenoise,
+    pubkey: &PublicKey,
+    policy: SyncPolicy,
+) -> Result<WhitenoiseUser, ApiError> {
+    let policy = effective_sync_policy(whitenoise, policy).await?;
+    let user = match policy {
+        SyncPolicy::CacheOnly => whitenoise.find_user_by_pubkey(pubkey).await?,
+        SyncPolicy::StaleWhileRevalidate { max_age_secs } => {
+            let cached = find_cached_user(whitenoise, pubkey).await?;
+            let max_age = Duration::from_secs(max_age_secs);
+            match cached {
+                Some(user) if is_fresh(&user, max_age) => user,
+                cached => {
+                    let pubkey = *pubkey;
+                    tokio::spawn(async move {
+                        // A failed fetch leaves the cache as is; the next lookup retries it
+                        let _ = fetch_user_via_outbox(whitenoise, &pubkey).await;
+                    });
+                    match cached {
+                        Some(user) => user,
+                        None => {
+                            whitenoise
+                                .find_or_create_user_by_pubkey(&pubkey, UserSyncMode::Background)
+                                .await?
+                        }
+                    }
+                }
+            }
+        }
+        SyncPolicy::ForceRefresh => fetch_user_via_outbox(whitenoise, pubkey).await?,
+        SyncPolicy::RefreshWithTimeout { timeout_ms } => {
+            // Spawned so that a timed out fetch keeps running and still updates the cache
+            let owned_pubkey = *pubkey;
+            let refresh =
+                tokio::spawn(async move { fetch_user_via_outbox(whitenoise, &owned_pubkey).await });
+            match tokio::time::timeout(Duration::from_millis(timeout_ms), refresh).await {
+                Ok(Ok(user)) => user?,
+                Ok(Err(e)) => {
+                    return Err(ApiError::Other {
+                        message: e.to_string(),
+                    });
+                }
+                // Serve cached data while the spawned fetch finishes the refresh
+                Err(_) => match find_cached_user(whitenoise, pubkey).await? {
+                    Some(user) => user,
+                    None => {
+                        whitenoise
+                            .find_or_create_user_by_pubkey(pubkey, UserSyncMode::Background)
+                            .await?
+                    }
+                },
+            }
+        }
     };
-    let user = whitenoise
-        .find_or_create_user_by_pubkey(&pubkey, sync_mode)
-        .await
-        .map_err(ApiError::from)?;
+    Ok(user)
+}
+
+/// Parses a hex, npub, nprofile or nevent reference and stores any relay hints it
+/// carries as additional relays to fetch the user from.
+async fn resolve_user_reference(
+    whitenoise: &Whitenoise,
+    reference: &str,
+) -> Result<PublicKey, ApiError> {
+    let (pubkey, relay_hints) = parse_pubkey_with_hints(reference)?;
+    if !relay_hints.is_empty() {
+        whitenoise
+            .add_user_relay_hints(&pubkey, &relay_hints)
+            .await?;
+    }
+    Ok(pubkey)
+}
+
+/// Returns a user, creating it if unknown.
+///
+/// `pubkey` may be a hex key, npub, nprofile or nevent; relay hints from the latter two
+/// are stored for the user. The returned user reports when and from which relays it was
+/// last fetched, so the UI can show staleness.
+#[frb]
+pub async fn get_user(pubkey: String, sync_policy: SyncPolicy) -> Result<User, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = resolve_user_reference(whitenoise, &pubkey).await?;
+    let user = find_user_with_policy(whitenoise, &pubkey, sync_policy).await?;
     Ok(user.into())
 }
 
 #[frb]
 pub async fn user_metadata(
     pubkey: String,
-    blocking_data_sync: bool,
+    sync_policy: SyncPolicy,
 ) -> Result<FlutterMetadata, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let pubkey = PublicKey::parse(&pubkey)?;
-    let sync_mode = if blocking_data_sync {
-        UserSyncMode::Blocking
-    } else {
-        UserSyncMode::Background
-    };
-    let user = whitenoise
-        .find_or_create_user_by_pubkey(&pubkey, sync_mode)
-        .await?;
+    let pubkey = resolve_user_reference(whitenoise, &pubkey).await?;
+    let user = find_user_with_policy(whitenoise, &pubkey, sync_policy).await?;
     Ok(user.metadata.into())
 }
 
@@ -63,18 +309,11 @@ pub async fn user_metadata(
 pub async fn user_relays(
     pubkey: String,
     relay_type: RelayType,
-    blocking_data_sync: bool,
+    sync_policy: SyncPolicy,
 ) -> Result<Vec<Relay>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let pubkey = PublicKey::parse(&pubkey)?;
-    let sync_mode = if blocking_data_sync {
-        UserSyncMode::Blocking
-    } else {
-        UserSyncMode::Background
-    };
-    let user = whitenoise
-        .find_or_create_user_by_pubkey(&pubkey, sync_mode)
-        .await?;
+    let pubkey = resolve_user_reference(whitenoise, &pubkey).await?;
+    let user = find_user_with_policy(whitenoise, &pubkey, sync_policy).await?;
     let relays = user.relays_by_type(relay_type, &whitenoise).await?;
     Ok(relays.into_iter().map(|r| r.into()).collect())
 }
@@ -82,17 +321,433 @@ pub async fn user_relays(
 #[frb]
 pub async fn user_has_key_package(
     pubkey: String,
-    blocking_data_sync: bool,
+    sync_policy: SyncPolicy,
 ) -> Result<bool, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let pubkey = PublicKey::parse(&pubkey)?;
-    let sync_mode = if blocking_data_sync {
-        UserSyncMode::Blocking
-    } else {
-        UserSyncMode::Background
-    };
-    let user = whitenoise
-        .find_or_create_user_by_pubkey(&pubkey, sync_mode)
-        .await?;
+    let pubkey = resolve_user_reference(whitenoise, &pubkey).await?;
+    let user = find_user_with_policy(whitenoise, &pubkey, sync_policy).await?;
     Ok(user.key_package_event(whitenoise).await?.is_some())
 }
+
+/// Checks the user's NIP-05 identifier again, ignoring the cached result.
+///
+/// Use this when the user asks to re-check, e.g. after the domain was unreachable.
+#[frb]
+pub async fn verify_user_nip05(pubkey: String) -> Result<User, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = resolve_user_reference(whitenoise, &pubkey).await?;
+    let user = whitenoise.verify_user_nip05(&pubkey).await?;
+    Ok(user.into())
+}
+
+/// Cached users and the pubkeys that still need a fetch under a sync policy.
+async fn partition_cached_users(
+    whitenoise: &Whitenoise,
+    pubkeys: &[PublicKey],
+    policy: SyncPolicy,
+) -> Result<(Vec<WhitenoiseUser>, Vec<PublicKey>), ApiError> {
+    let mut cached = Vec::with_capacity(pubkeys.len());
+    let mut to_fetch = Vec::new();
+    for pubkey in pubkeys {
+        match find_cached_user(whitenoise, pubkey).await? {
+            Some(user) => {
+                let needs_fetch = match policy {
+                    SyncPolicy::CacheOnly => false,
+                    SyncPolicy::StaleWhileRevalidate { max_age_secs } => {
+                        !is_fresh(&user, Duration::from_secs(max_age_secs))
+                    }
+                    SyncPolicy::ForceRefresh | SyncPolicy::RefreshWithTimeout { .. } => true,
+                };
+                if needs_fetch {
+                    to_fetch.push(*pubkey);
+                }
+                cached.push(user);
+            }
+            None if !matches!(policy, SyncPolicy::CacheOnly) => to_fetch.push(*pubkey),
+            None => {}
+        }
+    }
+    Ok((cached, to_fetch))
+}
+
+/// Merges freshly fetched users over cached ones, keeping the order of `pubkeys`.
+fn merge_users(
+    pubkeys: &[PublicKey],
+    cached: Vec<WhitenoiseUser>,
+    fetched: Vec<WhitenoiseUser>,
+) -> Vec<User> {
+    let mut users: HashMap<PublicKey, WhitenoiseUser> = cached
+        .into_iter()
+        .chain(fetched)
+        .map(|user| (user.pubkey, user))
+        .collect();
+    pubkeys
+        .iter()
+        .filter_map(|pk| users.remove(pk))
+        .map(User::from)
+        .collect()
+}
+
+fn parse_unique_pubkeys(pubkeys: &[String]) -> Result<Vec<PublicKey>, ApiError> {
+    let mut seen = HashSet::new();
+    let mut parsed = Vec::with_capacity(pubkeys.len());
+    for pubkey in pubkeys {
+        let pubkey = PublicKey::parse(pubkey)?;
+        if seen.insert(pubkey) {
+            parsed.push(pubkey);
+        }
+    }
+    Ok(parsed)
+}
+
+/// Returns many users in one call, in the order of `pubkeys`.
+///
+/// Users that need fetching under `sync_policy` are fetched together with a single
+/// multi-author relay filter instead of one query per user. With
+/// `StaleWhileRevalidate`, cached users are returned right away and unknown users are
+/// left out until the background fetch stores them; use [`watch_users`] to receive them.
+/// With `RefreshWithTimeout`, a fetch that times out keeps running in the background.
+#[frb]
+pub async fn get_users(
+    pubkeys: Vec<String>,
+    sync_policy: SyncPolicy,
+) -> Result<Vec<User>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkeys = parse_unique_pubkeys(&pubkeys)?;
+    let sync_policy = effective_sync_policy(whitenoise, sync_policy).await?;
+    let (cached, to_fetch) = partition_cached_users(whitenoise, &pubkeys, sync_policy).await?;
+
+    let fetched = match sync_policy {
+        _ if to_fetch.is_empty() => Vec::new(),
+        SyncPolicy::CacheOnly => Vec::new(),
+        SyncPolicy::StaleWhileRevalidate { .. } => {
+            tokio::spawn(async move {
+                // A failed sync leaves the cache as is; the next lookup retries it
+                let _ = whitenoise.sync_users_metadata(&to_fetch).await;
+            });
+            Vec::new()
+        }
+        SyncPolicy::ForceRefresh => whitenoise.sync_users_metadata(&to_fetch).await?,
+        SyncPolicy::RefreshWithTimeout { timeout_ms } => {
+            // Spawned so that a timed out fetch keeps running and still updates the cache
+            let refresh =
+                tokio::spawn(async move { whitenoise.sync_users_metadata(&to_fetch).await });
+            match tokio::time::timeout(Duration::from_millis(timeout_ms), refresh).await {
+                Ok(Ok(users)) => users?,
+                Ok(Err(e)) => {
+                    return Err(ApiError::Other {
+                        message: e.to_string(),
+                    });
+                }
+                Err(_) => Vec::new(),
+            }
+        }
+    };
+    Ok(merge_users(&pubkeys, cached, fetched))
+}
+
+/// Streams users as their data becomes available.
+///
+/// Cached users are sent immediately; users that need fetching under `sync_policy` are
+/// fetched with a single multi-author filter and sent again as their metadata arrives.
+/// The stream closes once the fetch completes.
+#[frb]
+pub async fn watch_users(
+    pubkeys: Vec<String>,
+    sync_policy: SyncPolicy,
+    sink: StreamSink<User>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkeys = parse_unique_pubkeys(&pubkeys)?;
+    let sync_policy = effective_sync_policy(whitenoise, sync_policy).await?;
+    let (cached, to_fetch) = partition_cached_users(whitenoise, &pubkeys, sync_policy).await?;
+
+    for user in cached {
+        if sink.add(user.into()).is_err() {
+            // Dart side cancelled the stream
+            return Ok(());
+        }
+    }
+    if to_fetch.is_empty() || matches!(sync_policy, SyncPolicy::CacheOnly) {
+        return Ok(());
+    }
+
+    let mut updates = whitenoise.stream_users_metadata(&to_fetch).await?;
+    while let Some(user) = updates.recv().await {
+        if sink.add(user.into()).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Maximum number of results returned by [`search_users`]
+const USER_SEARCH_LIMIT: usize = 50;
+
+/// Time allowed for a NIP-05 lookup before the search continues without it
+const NIP05_LOOKUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How a [`search_users`] result matched the query
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UserSearchMatch {
+    /// The query was the user's pubkey, npub or nprofile
+    Pubkey,
+    /// The query was a NIP-05 identifier that resolved to the user
+    Nip05,
+    /// A cached name, display name or NIP-05 starts with the query
+    Prefix,
+    /// A cached name, display name or NIP-05 contains the query
+    Substring,
+    /// The query's characters appear in order in a cached name, display name or NIP-05
+    Fuzzy,
+    /// Returned by a NIP-50 search relay
+    Relay,
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct UserSearchResult {
+    pub user: User,
+    pub matched_by: UserSearchMatch,
+    pub is_follow: bool,
+}
+
+/// Returns whether `query` looks like a NIP-05 identifier (`name@domain`).
+fn is_nip05_identifier(query: &str) -> bool {
+    match query.split_once('@') {
+        Some((name, domain)) => {
+            !name.is_empty()
+                && !domain.is_empty()
+                && !domain.contains('@')
+                && !query.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+/// Scores a single metadata field against a lowercase query, best match first.
+fn match_field(field: &str, query: &str) -> Option<UserSearchMatch> {
+    let field = field.to_lowercase();
+    if field.starts_with(query) {
+        return Some(UserSearchMatch::Prefix);
+    }
+    if field.contains(query) {
+        return Some(UserSearchMatch::Substring);
+    }
+    let mut chars = field.chars();
+    query
+        .chars()
+        .all(|q| chars.any(|c| c == q))
+        .then_some(UserSearchMatch::Fuzzy)
+}
+
+/// Sorts matches by how they matched, followed users first within each rank, and keeps
+/// the best match per user up to [`USER_SEARCH_LIMIT`].
+fn rank_matches<T>(
+    mut matches: Vec<(T, UserSearchMatch)>,
+    pubkey_of: impl Fn(&T) -> PublicKey,
+    follows: &HashSet<PublicKey>,
+) -> Vec<(T, UserSearchMatch)> {
+    matches.sort_by_key(|(user, matched_by)| (*matched_by, !follows.contains(&pubkey_of(user))));
+    let mut seen = HashSet::new();
+    matches
+        .into_iter()
+        .filter(|(user, _)| seen.insert(pubkey_of(user)))
+        .take(USER_SEARCH_LIMIT)
+        .collect()
+}
+
+/// Returns the best match of a cached user's name, display name or NIP-05 against
+/// a lowercase query.
+fn match_user(user: &WhitenoiseUser, query: &str) -> Option<UserSearchMatch> {
+    let metadata = &user.metadata;
+    [&metadata.name, &metadata.display_name, &metadata.nip05]
+        .into_iter()
+        .flatten()
+        .filter_map(|field| match_field(field, query))
+        .min()
+}
+
+/// Searches for users by pubkey, NIP-05 identifier, name or display name.
+///
+/// Pubkeys, npubs and nprofiles return that user directly. A `name@domain` query is
+/// resolved through the domain's `.well-known/nostr.json` by the same core lookup that
+/// verifies NIP-05 identifiers, sharing its cache, so it finds users that are not cached
+/// yet. All other queries are matched fuzzily against the cached names,
+/// display names and NIP-05 identifiers. With `search_relays`, the account's NIP-50
+/// search relays are queried as well; if that fails, the local results are returned.
+///
+/// Results are ranked by how they matched, with users the account follows first within
+/// each rank.
+#[frb]
+pub async fn search_users(
+    account_pubkey: String,
+    query: String,
+    search_relays: bool,
+) -> Result<Vec<UserSearchResult>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let follows: HashSet<PublicKey> = whitenoise
+        .follows(&account)
+        .await?
+        .into_iter()
+        .map(|user| user.pubkey)
+        .collect();
+    let mut matches: Vec<(WhitenoiseUser, UserSearchMatch)> = Vec::new();
+
+    if let Ok(pubkey) = resolve_user_reference(whitenoise, query).await {
+        let user = whitenoise
+            .find_or_create_user_by_pubkey(&pubkey, UserSyncMode::Background)
+            .await?;
+        matches.push((user, UserSearchMatch::Pubkey));
+    } else if is_nip05_identifier(query) {
+        // An unknown or unreachable identifier still falls through to the local search
+        let lookup = whitenoise.resolve_nip05(query);
+        if let Ok(Ok(Some(pubkey))) = tokio::time::timeout(NIP05_LOOKUP_TIMEOUT, lookup).await {
+            let user = whitenoise
+                .find_or_create_user_by_pubkey(&pubkey, UserSyncMode::Background)
+                .await?;
+            matches.push((user, UserSearchMatch::Nip05));
+        }
+    }
+
+    let lowercase_query = query.to_lowercase();
+    for user in whitenoise.all_users().await? {
+        if let Some(matched_by) = match_user(&user, &lowercase_query) {
+            matches.push((user, matched_by));
+        }
+    }
+
+    if search_relays {
+        // Search relays are best effort; without them the local results are returned
+        let relay_users = whitenoise
+            .search_users_on_relays(&account, query, USER_SEARCH_LIMIT)
+            .await
+            .unwrap_or_default();
+        matches.extend(
+            relay_users
+                .into_iter()
+                .map(|user| (user, UserSearchMatch::Relay)),
+        );
+    }
+
+    Ok(rank_matches(matches, |user| user.pubkey, &follows)
+        .into_iter()
+        .map(|(user, matched_by)| UserSearchResult {
+            is_follow: follows.contains(&user.pubkey),
+            user: user.into(),
+            matched_by,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_nip05_identifier_requires_name_and_domain() {
+        assert!(is_nip05_identifier("alice@example.com"));
+        assert!(is_nip05_identifier("_@example.com"));
+        assert!(!is_nip05_identifier("alice"));
+        assert!(!is_nip05_identifier("@example.com"));
+        assert!(!is_nip05_identifier("alice@"));
+        assert!(!is_nip05_identifier("alice@example@com"));
+        assert!(!is_nip05_identifier("alice smith@example.com"));
+    }
+
+    #[test]
+    fn match_field_prefers_prefix_over_substring_over_fuzzy() {
+        assert_eq!(match_field("Alice", "ali"), Some(UserSearchMatch::Prefix));
+        assert_eq!(
+            match_field("Malice", "ali"),
+            Some(UserSearchMatch::Substring)
+        );
+        assert_eq!(
+            match_field("Anna Lisa", "ali"),
+            Some(UserSearchMatch::Fuzzy)
+        );
+        assert_eq!(match_field("Bob", "ali"), None);
+        // Characters must appear in order
+        assert_eq!(match_field("Lia", "ali"), None);
+    }
+
+    #[test]
+    fn rank_matches_orders_by_match_then_follows_and_dedupes() {
+        let keys: Vec<PublicKey> = (0..4).map(|_| Keys::generate().public_key()).collect();
+        let follows = HashSet::from([keys[2]]);
+        let matches = vec![
+            (keys[0], UserSearchMatch::Fuzzy),
+            (keys[1], UserSearchMatch::Prefix),
+            (keys[2], UserSearchMatch::Prefix),
+            (keys[3], UserSearchMatch::Relay),
+            (keys[1], UserSearchMatch::Relay),
+            (keys[3], UserSearchMatch::Nip05),
+        ];
+
+        let ranked = rank_matches(matches, |pubkey| *pubkey, &follows);
+        assert_eq!(
+            ranked,
+            vec![
+                (keys[3], UserSearchMatch::Nip05),
+                (keys[2], UserSearchMatch::Prefix),
+                (keys[1], UserSearchMatch::Prefix),
+                (keys[0], UserSearchMatch::Fuzzy),
+            ]
+        );
+    }
+
+    #[test]
+    fn rank_matches_keeps_at_most_the_search_limit() {
+        let matches = (0..USER_SEARCH_LIMIT + 10)
+            .map(|_| (Keys::generate().public_key(), UserSearchMatch::Substring))
+            .collect();
+        let ranked = rank_matches(matches, |pubkey| *pubkey, &HashSet::new());
+        assert_eq!(ranked.len(), USER_SEARCH_LIMIT);
+    }
+
+    fn relay(url: &str) -> RelayUrl {
+        RelayUrl::parse(url).unwrap()
+    }
+
+    #[test]
+    fn write_relays_skips_read_only_relays() {
+        let relay_list = EventBuilder::relay_list([
+            (relay("wss://write.example.com"), Some(RelayMetadata::Write)),
+            (relay("wss://read.example.com"), Some(RelayMetadata::Read)),
+            (relay("wss://both.example.com"), None),
+        ])
+        .sign_with_keys(&Keys::generate())
+        .unwrap();
+        assert_eq!(
+            write_relays(&relay_list),
+            vec![
+                relay("wss://write.example.com"),
+                relay("wss://both.example.com")
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_relays_keeps_first_occurrence_order() {
+        let merged = merge_relays(
+            &[relay("wss://a.example.com"), relay("wss://b.example.com")],
+            &[relay("wss://b.example.com"), relay("wss://c.example.com")],
+        );
+        assert_eq!(
+            merged,
+            vec![
+                relay("wss://a.example.com"),
+                relay("wss://b.example.com"),
+                relay("wss://c.example.com")
+            ]
+        );
+    }
+}
@@ -20,6 +20,30 @@ pub fn hex_pubkey_from_npub(npub: &str) -> Result<String, ApiError> {
     Ok(pubkey.to_hex())
 }
 
+/// Parses a user reference into a public key and the relay hints it carries.
+///
+/// Accepts hex, `npub`, `nprofile` and `nevent` (using its author) identifiers, with or
+/// without a `nostr:` prefix. Only `nprofile` and `nevent` carry relay hints.
+pub(crate) fn parse_pubkey_with_hints(input: &str) -> Result<(PublicKey, Vec<RelayUrl>), ApiError> {
+    let input = input.trim();
+    let input = input.strip_prefix("nostr:").unwrap_or(input);
+    if input.starts_with("nprofile1") || input.starts_with("nevent1") {
+        return match Nip19::from_bech32(input)? {
+            Nip19::Profile(profile) => Ok((profile.public_key, profile.relays)),
+            Nip19::Event(event) => {
+                let author = event.author.ok_or_else(|| ApiError::Other {
+                    message: "nevent does not include an author".to_string(),
+                })?;
+                Ok((author, event.relays))
+            }
+            _ => Err(ApiError::InvalidKey {
+                message: format!("Unsupported user reference: {input}"),
+            }),
+        };
+    }
+    Ok((PublicKey::parse(input)?, Vec::new()))
+}
+
 #[frb]
 pub fn relay_url_from_string(url: String) -> Result<RelayUrl, ApiError> {
     RelayUrl::parse(&url).map_err(ApiError::from)
@@ -57,3 +81,71 @@ pub fn group_id_from_string(group_id: &str) -> Result<GroupId, ApiError> {
     let bytes = ::hex::decode(group_id)?;
     Ok(GroupId::from_slice(&bytes))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relay(url: &str) -> RelayUrl {
+        RelayUrl::parse(url).unwrap()
+    }
+
+    #[test]
+    fn parses_hex_and_npub_without_hints() {
+        let pubkey = Keys::generate().public_key();
+        let npub = pubkey.to_bech32().unwrap();
+
+        assert_eq!(
+            parse_pubkey_with_hints(&pubkey.to_hex()).unwrap(),
+            (pubkey, Vec::new())
+        );
+        assert_eq!(
+            parse_pubkey_with_hints(&npub).unwrap(),
+            (pubkey, Vec::new())
+        );
+        assert_eq!(
+            parse_pubkey_with_hints(&format!("  nostr:{npub} ")).unwrap(),
+            (pubkey, Vec::new())
+        );
+    }
+
+    #[test]
+    fn parses_nprofile_relay_hints() {
+        let pubkey = Keys::generate().public_key();
+        let relays = vec![relay("wss://relay.one"), relay("wss://relay.two")];
+        let nprofile = Nip19Profile::new(pubkey, relays.clone())
+            .to_bech32()
+            .unwrap();
+
+        assert_eq!(
+            parse_pubkey_with_hints(&nprofile).unwrap(),
+            (pubkey, relays.clone())
+        );
+        assert_eq!(
+            parse_pubkey_with_hints(&format!("nostr:{nprofile}")).unwrap(),
+            (pubkey, relays)
+        );
+    }
+
+    #[test]
+    fn parses_nevent_author_and_relay_hints() {
+        let author = Keys::generate().public_key();
+        let relays = vec![relay("wss://relay.one")];
+        let nevent = Nip19Event::new(EventId::all_zeros())
+            .author(author)
+            .relays(relays.clone())
+            .to_bech32()
+            .unwrap();
+
+        assert_eq!(parse_pubkey_with_hints(&nevent).unwrap(), (author, relays));
+    }
+
+    #[test]
+    fn rejects_nevent_without_author_and_invalid_input() {
+        let nevent = Nip19Event::new(EventId::all_zeros()).to_bech32().unwrap();
+
+        assert!(parse_pubkey_with_hints(&nevent).is_err());
+        assert!(parse_pubkey_with_hints("not a key").is_err());
+        assert!(parse_pubkey_with_hints("nprofile1invalid").is_err());
+    }
+}
@@ -0,0 +1,388 @@
+//! Safety numbers and credential verification for group members.
+//!
+//! A member's fingerprint is derived from the identity in their MLS leaf credential
+//! (their Nostr public key) and the signature key bound to that leaf. Two members who
+//! compare their safety numbers out of band can therefore detect a substituted leaf.
+//!
+//! The verified flag is persisted per contact. Leaf signature keys differ between
+//! groups, so the fingerprint last seen for a contact is stored per group. A fingerprint
+//! that differs from the stored one resets the flag and is reported as
+//! [`VerificationState::KeyChanged`]. Fingerprints are rechecked in the background, so a
+//! leaf rotated by a commit is flagged without the safety number being opened.
+
+use crate::api::{error::ApiError, group_id_from_string};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use mdk_core::prelude::GroupId;
+use nostr_sdk::hashes::{Hash, sha256::Hash as Sha256Hash};
+use nostr_sdk::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use whitenoise::{Account as WhitenoiseAccount, Whitenoise};
+
+/// Version tag mixed into every fingerprint digest and QR payload.
+const FINGERPRINT_VERSION: u8 = 1;
+
+/// Number of decimal digits contributed by each member to a safety number.
+const DIGITS_PER_MEMBER: usize = 30;
+
+/// How often group members' fingerprints are rechecked in the background
+const KEY_CHANGE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+static KEY_CHANGE_MONITOR_STARTED: AtomicBool = AtomicBool::new(false);
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationState {
+    // Unverified: The member's safety number has never been confirmed
+    Unverified,
+    // Verified: The member's safety number was confirmed and still matches
+    Verified,
+    // KeyChanged: The member was verified but their credential has changed since
+    KeyChanged,
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MemberFingerprint {
+    pub member_pubkey: String,
+    /// Hex digest of the member's leaf credential and signature key
+    pub fingerprint: String,
+    /// 60 digits in groups of five, identical on both members' devices
+    pub safety_number: String,
+    /// Payload to render as a QR code and scan from the other member's device
+    pub qr_payload: String,
+    pub verification_state: VerificationState,
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+/// Hashes the leaf credential identity and signature key of a single member.
+fn leaf_digest(pubkey: &PublicKey, signature_key: &[u8]) -> Sha256Hash {
+    let mut input = Vec::with_capacity(1 + 32 + signature_key.len());
+    input.push(FINGERPRINT_VERSION);
+    input.extend_from_slice(&pubkey.to_bytes());
+    input.extend_from_slice(signature_key);
+    Sha256Hash::hash(&input)
+}
+
+/// Renders a digest as decimal digits, five digits for every five bytes.
+fn digest_digits(digest: &Sha256Hash) -> String {
+    digest
+        .as_byte_array()
+        .chunks_exact(5)
+        .take(DIGITS_PER_MEMBER / 5)
+        .map(|chunk| {
+            let value = chunk.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+            format!("{:05}", value % 100_000)
+        })
+        .collect()
+}
+
+/// Builds the safety number and QR payload shared by two members.
+///
+/// Both halves are ordered by public key so that each side computes the same values.
+fn safety_number(
+    own: (&PublicKey, &Sha256Hash),
+    member: (&PublicKey, &Sha256Hash),
+) -> (String, String) {
+    let (first, second) = if own.0 <= member.0 {
+        (own, member)
+    } else {
+        (member, own)
+    };
+    let digits = format!("{}{}", digest_digits(first.1), digest_digits(second.1));
+    let safety_number = digits
+        .as_bytes()
+        .chunks(5)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let qr_payload = format!(
+        "whitenoise:fp:{}:{}:{}:{}:{}",
+        FINGERPRINT_VERSION,
+        first.0.to_hex(),
+        first.1,
+        second.0.to_hex(),
+        second.1,
+    );
+    (safety_number, qr_payload)
+}
+
+/// Computes the fingerprint of `member_pubkey` in `group_id` as seen by `account`.
+async fn compute_fingerprint(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group_id: &GroupId,
+    member_pubkey: &PublicKey,
+) -> Result<(String, String, String), ApiError> {
+    let own_signature_key = whitenoise
+        .group_member_signature_key(account, group_id, &account.pubkey)
+        .await?;
+    let member_signature_key = whitenoise
+        .group_member_signature_key(account, group_id, member_pubkey)
+        .await?;
+
+    let own_digest = leaf_digest(&account.pubkey, &own_signature_key);
+    let member_digest = leaf_digest(member_pubkey, &member_signature_key);
+    let (safety_number, qr_payload) = safety_number(
+        (&account.pubkey, &own_digest),
+        (member_pubkey, &member_digest),
+    );
+    Ok((member_digest.to_string(), safety_number, qr_payload))
+}
+
+/// Stores `fingerprint` as the member's current fingerprint in the group.
+///
+/// If it replaces a different fingerprint and the contact was verified, the verified
+/// flag is reset and the key change is flagged.
+async fn record_fingerprint(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group_id: &GroupId,
+    member_pubkey: &PublicKey,
+    fingerprint: &str,
+) -> Result<(), ApiError> {
+    let previous = whitenoise
+        .find_group_member_fingerprint(account, group_id, member_pubkey)
+        .await?;
+    if previous.as_deref() == Some(fingerprint) {
+        return Ok(());
+    }
+    whitenoise
+        .save_group_member_fingerprint(account, group_id, member_pubkey, fingerprint)
+        .await?;
+    if previous.is_none() {
+        return Ok(());
+    }
+    let was_verified = whitenoise
+        .find_contact_verification(account, member_pubkey)
+        .await?
+        .is_some_and(|stored| stored.verified);
+    if was_verified {
+        whitenoise
+            .save_contact_verification(account, member_pubkey, false, true)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Recomputes the fingerprints of all members in the account's groups.
+async fn check_key_changes(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+) -> Result<(), ApiError> {
+    for group in whitenoise.groups(account, true).await? {
+        let group_id = group.mls_group_id;
+        for member_pubkey in whitenoise.group_members(account, &group_id).await? {
+            if member_pubkey == account.pubkey {
+                continue;
+            }
+            // A member whose leaf cannot be read right now is checked on the next tick
+            let Ok((fingerprint, _, _)) =
+                compute_fingerprint(whitenoise, account, &group_id, &member_pubkey).await
+            else {
+                continue;
+            };
+            record_fingerprint(whitenoise, account, &group_id, &member_pubkey, &fingerprint)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Starts the background task that flags members whose credential changed.
+///
+/// Called once Whitenoise is initialized; later calls are no-ops.
+pub(crate) fn spawn_key_change_monitor() {
+    if KEY_CHANGE_MONITOR_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(KEY_CHANGE_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Ok(whitenoise) = Whitenoise::get_instance() else {
+                continue;
+            };
+            let Ok(accounts) = whitenoise.all_accounts().await else {
+                continue;
+            };
+            for account in accounts {
+                // A failed check is retried on the next tick
+                let _ = check_key_changes(whitenoise, &account).await;
+            }
+        }
+    });
+}
+
+/// Returns the safety number of a group member and their persisted verification state.
+///
+/// If the member's fingerprint in this group changed since it was last seen, the stored
+/// flag is reset and the member is reported as [`VerificationState::KeyChanged`] until
+/// verified again.
+#[frb]
+pub async fn member_fingerprint(
+    account_pubkey: String,
+    group_id: String,
+    member_pubkey: String,
+) -> Result<MemberFingerprint, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let member_pubkey = PublicKey::parse(&member_pubkey)?;
+
+    let (fingerprint, safety_number, qr_payload) =
+        compute_fingerprint(whitenoise, &account, &group_id, &member_pubkey).await?;
+
+    record_fingerprint(
+        whitenoise,
+        &account,
+        &group_id,
+        &member_pubkey,
+        &fingerprint,
+    )
+    .await?;
+
+    let stored = whitenoise
+        .find_contact_verification(&account, &member_pubkey)
+        .await?;
+    let (verification_state, verified_at) = match stored {
+        Some(stored) if stored.verified => (VerificationState::Verified, stored.verified_at),
+        Some(stored) if stored.key_changed => (VerificationState::KeyChanged, None),
+        _ => (VerificationState::Unverified, None),
+    };
+
+    Ok(MemberFingerprint {
+        member_pubkey: member_pubkey.to_hex(),
+        fingerprint,
+        safety_number,
+        qr_payload,
+        verification_state,
+        verified_at,
+    })
+}
+
+/// Marks a contact as verified or unverified against their current fingerprint in the
+/// group.
+///
+/// Clears any pending key-change flag for the contact.
+#[frb]
+pub async fn set_member_verified(
+    account_pubkey: String,
+    group_id: String,
+    member_pubkey: String,
+    verified: bool,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let member_pubkey = PublicKey::parse(&member_pubkey)?;
+
+    let (fingerprint, _, _) =
+        compute_fingerprint(whitenoise, &account, &group_id, &member_pubkey).await?;
+    record_fingerprint(
+        whitenoise,
+        &account,
+        &group_id,
+        &member_pubkey,
+        &fingerprint,
+    )
+    .await?;
+    whitenoise
+        .save_contact_verification(&account, &member_pubkey, verified, false)
+        .await?;
+    Ok(())
+}
+
+/// Checks a scanned QR payload against the member's current fingerprint.
+///
+/// Returns `true` and marks the member as verified when the payload matches.
+#[frb]
+pub async fn verify_member_qr_payload(
+    account_pubkey: String,
+    group_id: String,
+    member_pubkey: String,
+    scanned_payload: String,
+) -> Result<bool, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let member_pubkey = PublicKey::parse(&member_pubkey)?;
+
+    let (fingerprint, _, qr_payload) =
+        compute_fingerprint(whitenoise, &account, &group_id, &member_pubkey).await?;
+    if scanned_payload.trim() != qr_payload {
+        return Ok(false);
+    }
+    record_fingerprint(
+        whitenoise,
+        &account,
+        &group_id,
+        &member_pubkey,
+        &fingerprint,
+    )
+    .await?;
+    whitenoise
+        .save_contact_verification(&account, &member_pubkey, true, false)
+        .await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> (PublicKey, PublicKey) {
+        (Keys::generate().public_key(), Keys::generate().public_key())
+    }
+
+    #[test]
+    fn both_members_compute_the_same_safety_number() {
+        let (alice, bob) = keys();
+        let alice_digest = leaf_digest(&alice, b"alice signature key");
+        let bob_digest = leaf_digest(&bob, b"bob signature key");
+
+        let seen_by_alice = safety_number((&alice, &alice_digest), (&bob, &bob_digest));
+        let seen_by_bob = safety_number((&bob, &bob_digest), (&alice, &alice_digest));
+        assert_eq!(seen_by_alice, seen_by_bob);
+    }
+
+    #[test]
+    fn safety_number_is_sixty_digits_in_groups_of_five() {
+        let (alice, bob) = keys();
+        let (safety_number, _) = safety_number(
+            (&alice, &leaf_digest(&alice, b"a")),
+            (&bob, &leaf_digest(&bob, b"b")),
+        );
+        let groups: Vec<&str> = safety_number.split(' ').collect();
+        assert_eq!(groups.len(), 12);
+        assert!(
+            groups
+                .iter()
+                .all(|group| group.len() == 5 && group.bytes().all(|b| b.is_ascii_digit()))
+        );
+    }
+
+    #[test]
+    fn changed_signature_key_changes_the_digest() {
+        let (alice, _) = keys();
+        assert_eq!(leaf_digest(&alice, b"key"), leaf_digest(&alice, b"key"));
+        assert_ne!(
+            leaf_digest(&alice, b"key"),
+            leaf_digest(&alice, b"rotated key")
+        );
+    }
+
+    #[test]
+    fn digest_digits_renders_five_digits_per_five_bytes() {
+        let digest = Sha256Hash::from_byte_array([0xff; 32]);
+        // 0xff_ffff_ffff = 1_099_511_627_775
+        assert_eq!(digest_digits(&digest), "27775".repeat(6));
+
+        let digest = Sha256Hash::from_byte_array([0; 32]);
+        assert_eq!(digest_digits(&digest), "0".repeat(DIGITS_PER_MEMBER));
+    }
+}
@@ -1,9 +1,18 @@
-use crate::api::{error::ApiError, utils::group_id_to_string};
+use crate::api::{
+    error::ApiError, groups::GroupType, metadata::FlutterMetadata, utils::group_id_to_string,
+};
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::welcome_types::Welcome as WhitenoiseWelcome;
 use mdk_core::prelude::welcome_types::WelcomeState as WhitenoiseWelcomeState;
 use nostr_sdk::prelude::*;
-use whitenoise::Whitenoise;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use whitenoise::{
+    Account, GroupType as WhitenoiseGroupType, UserSyncMode,
+    WelcomePolicy as WhitenoiseWelcomePolicy, WelcomeSenderPolicy as WhitenoiseWelcomeSenderPolicy,
+    Whitenoise,
+};
 
 /// Converts a GroupId to a hex string representation.
 ///
@@ -33,7 +42,7 @@ pub struct Welcome {
 }
 
 #[frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WelcomeState {
     // Pending: The welcome has been sent but not yet accepted or declined
     Pending,
@@ -45,6 +54,61 @@ pub enum WelcomeState {
     Ignored,
 }
 
+/// Who may add the account to a group without the welcome landing in the requests bucket
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WelcomeSenderPolicy {
+    // Anyone: Welcomes from any pubkey are shown as pending
+    Anyone,
+    // Follows: Only welcomes from pubkeys the account follows are shown as pending
+    Follows,
+    // FollowsOfFollows: Welcomes from follows and the people they follow are shown as pending
+    FollowsOfFollows,
+}
+
+/// Anti-spam policy for incoming welcomes, stored in the app settings
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct WelcomePolicy {
+    pub sender_policy: WelcomeSenderPolicy,
+    /// Welcomes to groups with more members than this go to the requests bucket
+    pub max_member_count: Option<u32>,
+    /// Accept welcomes from pubkeys the account follows as soon as they are processed
+    pub auto_accept_from_follows: bool,
+}
+
+impl From<WhitenoiseWelcomePolicy> for WelcomePolicy {
+    fn from(policy: WhitenoiseWelcomePolicy) -> Self {
+        Self {
+            sender_policy: match policy.sender_policy {
+                WhitenoiseWelcomeSenderPolicy::Anyone => WelcomeSenderPolicy::Anyone,
+                WhitenoiseWelcomeSenderPolicy::Follows => WelcomeSenderPolicy::Follows,
+                WhitenoiseWelcomeSenderPolicy::FollowsOfFollows => {
+                    WelcomeSenderPolicy::FollowsOfFollows
+                }
+            },
+            max_member_count: policy.max_member_count,
+            auto_accept_from_follows: policy.auto_accept_from_follows,
+        }
+    }
+}
+
+impl From<WelcomePolicy> for WhitenoiseWelcomePolicy {
+    fn from(policy: WelcomePolicy) -> Self {
+        Self {
+            sender_policy: match policy.sender_policy {
+                WelcomeSenderPolicy::Anyone => WhitenoiseWelcomeSenderPolicy::Anyone,
+                WelcomeSenderPolicy::Follows => WhitenoiseWelcomeSenderPolicy::Follows,
+                WelcomeSenderPolicy::FollowsOfFollows => {
+                    WhitenoiseWelcomeSenderPolicy::FollowsOfFollows
+                }
+            },
+            max_member_count: policy.max_member_count,
+            auto_accept_from_follows: policy.auto_accept_from_follows,
+        }
+    }
+}
+
 impl From<WhitenoiseWelcomeState> for WelcomeState {
     fn from(state: WhitenoiseWelcomeState) -> Self {
         match state {
@@ -100,14 +164,133 @@ impl From<&WhitenoiseWelcome> for Welcome {
     }
 }
 
+/// Everything the invite screen shows before the user accepts or declines a welcome.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct WelcomePreview {
+    pub welcome: Welcome,
+    pub welcomer_metadata: FlutterMetadata,
+    pub is_following_welcomer: bool,
+    /// Local path of the decrypted group image, if the group has one
+    pub group_image_path: Option<String>,
+    /// Members listed in the ratchet tree carried by the welcome
+    pub member_pubkeys: Vec<String>,
+    pub group_type: GroupType,
+}
+
+/// Returns whether a welcome invites to a direct message group, using the group type
+/// in the welcome's group data.
+pub(crate) async fn is_direct_message_welcome(
+    whitenoise: &Whitenoise,
+    account: &Account,
+    welcome: &WhitenoiseWelcome,
+) -> Result<bool, ApiError> {
+    let group_type = whitenoise.welcome_group_type(account, welcome).await?;
+    Ok(group_type == WhitenoiseGroupType::DirectMessage)
+}
+
+#[frb]
+pub async fn welcome_preview(
+    pubkey: String,
+    welcome_event_id: String,
+) -> Result<WelcomePreview, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let welcome = whitenoise
+        .find_welcome_by_event_id(&pubkey, welcome_event_id)
+        .await?;
+
+    let welcomer = whitenoise
+        .find_or_create_user_by_pubkey(&welcome.welcomer, UserSyncMode::Blocking)
+        .await?;
+    let is_following_welcomer = whitenoise
+        .follows(&account)
+        .await?
+        .iter()
+        .any(|user| user.pubkey == welcome.welcomer);
+    let group_image_path = whitenoise
+        .welcome_group_image_path(&account, &welcome)
+        .await?;
+    let member_pubkeys = whitenoise
+        .welcome_member_pubkeys(&account, &welcome)
+        .await?;
+    let group_type: GroupType = whitenoise
+        .welcome_group_type(&account, &welcome)
+        .await?
+        .into();
+
+    Ok(WelcomePreview {
+        welcome: (&welcome).into(),
+        welcomer_metadata: welcomer.metadata.into(),
+        is_following_welcomer,
+        group_image_path: group_image_path.map(|p| p.to_string_lossy().to_string()),
+        member_pubkeys: member_pubkeys.iter().map(|pk| pk.to_hex()).collect(),
+        group_type,
+    })
+}
+
+/// Splits the account's pending welcomes into those allowed by the welcome policy and
+/// those that belong in the requests bucket.
+async fn partition_pending_welcomes(
+    whitenoise: &Whitenoise,
+    pubkey: &PublicKey,
+) -> Result<(Vec<WhitenoiseWelcome>, Vec<WhitenoiseWelcome>), ApiError> {
+    let policy = whitenoise.app_settings().await?.welcome_policy;
+    let welcomes = whitenoise.pending_welcomes(pubkey).await?;
+
+    let trusted: Option<HashSet<PublicKey>> = match policy.sender_policy {
+        WhitenoiseWelcomeSenderPolicy::Anyone => None,
+        WhitenoiseWelcomeSenderPolicy::Follows => {
+            let account = whitenoise.find_account_by_pubkey(pubkey).await?;
+            let follows = whitenoise.follows(&account).await?;
+            Some(follows.into_iter().map(|user| user.pubkey).collect())
+        }
+        WhitenoiseWelcomeSenderPolicy::FollowsOfFollows => {
+            let account = whitenoise.find_account_by_pubkey(pubkey).await?;
+            let mut trusted: HashSet<PublicKey> = whitenoise
+                .follows(&account)
+                .await?
+                .into_iter()
+                .map(|user| user.pubkey)
+                .collect();
+            trusted.extend(whitenoise.follows_of_follows(&account).await?);
+            Some(trusted)
+        }
+    };
+
+    Ok(welcomes.into_iter().partition(|welcome| {
+        let sender_allowed = trusted
+            .as_ref()
+            .is_none_or(|trusted| trusted.contains(&welcome.welcomer));
+        let size_allowed = policy
+            .max_member_count
+            .is_none_or(|max| welcome.member_count <= max);
+        sender_allowed && size_allowed
+    }))
+}
+
+/// Returns pending welcomes that match the account's welcome policy.
+///
+/// Welcomes that don't match are only available through [`welcome_requests`], which
+/// also keeps them out of notifications built from this list.
 #[frb]
 pub async fn pending_welcomes(pubkey: String) -> Result<Vec<Welcome>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
-    let welcomes = whitenoise.pending_welcomes(&pubkey).await?;
+    let (welcomes, _) = partition_pending_welcomes(whitenoise, &pubkey).await?;
     Ok(welcomes.into_iter().map(|w| w.into()).collect())
 }
 
+/// Returns pending welcomes that don't match the account's welcome policy.
+#[frb]
+pub async fn welcome_requests(pubkey: String) -> Result<Vec<Welcome>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let (_, requests) = partition_pending_welcomes(whitenoise, &pubkey).await?;
+    Ok(requests.into_iter().map(|w| w.into()).collect())
+}
+
 #[frb]
 pub async fn find_welcome_by_event_id(
     pubkey: String,
@@ -140,3 +323,316 @@ pub async fn decline_welcome(pubkey: String, welcome_event_id: String) -> Result
         .await
         .map_err(ApiError::from)
 }
+
+/// Returns all welcomes of the account, newest first, optionally limited to one state.
+#[frb]
+pub async fn all_welcomes(
+    pubkey: String,
+    state_filter: Option<WelcomeState>,
+) -> Result<Vec<Welcome>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let mut welcomes: Vec<Welcome> = whitenoise
+        .all_welcomes(&pubkey)
+        .await?
+        .into_iter()
+        .map(Welcome::from)
+        .filter(|w| state_filter.as_ref().is_none_or(|state| w.state == *state))
+        .collect();
+    welcomes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(welcomes)
+}
+
+/// Hides a welcome without declining it, so no leave is published to the group.
+#[frb]
+pub async fn ignore_welcome(pubkey: String, welcome_event_id: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    whitenoise
+        .ignore_welcome(&pubkey, welcome_event_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+/// Outcome of [`block_welcomer`]
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct BlockWelcomerResult {
+    /// Number of pending welcomes from the welcomer that were declined
+    pub declined: u32,
+    /// Pending welcomes that could not be declined and stay pending, with their errors
+    pub failed: Vec<WelcomeActionResult>,
+}
+
+/// Blocks a welcomer for the account.
+///
+/// Every pending welcome from `welcomer_pubkey` is declined, and welcomes received from
+/// them later are ignored as soon as they are processed. A welcome that fails to
+/// decline does not stop the others.
+#[frb]
+pub async fn block_welcomer(
+    pubkey: String,
+    welcomer_pubkey: String,
+) -> Result<BlockWelcomerResult, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let welcomer_pubkey = PublicKey::parse(&welcomer_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    // Persist the block first so welcomes arriving meanwhile are already ignored
+    whitenoise
+        .block_welcomer(&account, &welcomer_pubkey)
+        .await?;
+
+    let mut result = BlockWelcomerResult {
+        declined: 0,
+        failed: Vec::new(),
+    };
+    for welcome in whitenoise.pending_welcomes(&pubkey).await? {
+        if welcome.welcomer != welcomer_pubkey {
+            continue;
+        }
+        let welcome_event_id = welcome.id.to_string();
+        match whitenoise
+            .decline_welcome(&pubkey, welcome_event_id.clone())
+            .await
+        {
+            Ok(()) => result.declined += 1,
+            Err(e) => result.failed.push(WelcomeActionResult {
+                welcome_event_id,
+                success: false,
+                error_message: Some(e.to_string()),
+            }),
+        }
+    }
+    Ok(result)
+}
+
+#[frb]
+pub async fn unblock_welcomer(pubkey: String, welcomer_pubkey: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let welcomer_pubkey = PublicKey::parse(&welcomer_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    whitenoise
+        .unblock_welcomer(&account, &welcomer_pubkey)
+        .await
+        .map_err(ApiError::from)
+}
+
+#[frb]
+pub async fn blocked_welcomers(pubkey: String) -> Result<Vec<String>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let blocked = whitenoise.blocked_welcomers(&account).await?;
+    Ok(blocked.into_iter().map(|pk| pk.to_hex()).collect())
+}
+
+/// Outcome of a batch welcome action for a single welcome
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct WelcomeActionResult {
+    pub welcome_event_id: String,
+    pub success: bool,
+    pub error_message: Option<String>,
+}
+
+/// Runs `accept` or `decline` for every distinct welcome id and reports each outcome
+/// in the order the ids were first given.
+///
+/// Core processes the welcomes together and publishes the resulting events in one batch
+/// instead of one relay round-trip per welcome.
+async fn batch_welcome_action(
+    pubkey: String,
+    welcome_event_ids: Vec<String>,
+    accept: bool,
+) -> Result<Vec<WelcomeActionResult>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+
+    let mut seen = HashSet::new();
+    let welcome_event_ids: Vec<String> = welcome_event_ids
+        .into_iter()
+        .filter(|id| seen.insert(id.clone()))
+        .collect();
+    let outcomes = if accept {
+        whitenoise
+            .accept_welcomes(&pubkey, &welcome_event_ids)
+            .await?
+    } else {
+        whitenoise
+            .decline_welcomes(&pubkey, &welcome_event_ids)
+            .await?
+    };
+    Ok(welcome_event_ids
+        .into_iter()
+        .zip(outcomes)
+        .map(|(welcome_event_id, outcome)| {
+            let error_message = outcome.err().map(|e| e.to_string());
+            WelcomeActionResult {
+                welcome_event_id,
+                success: error_message.is_none(),
+                error_message,
+            }
+        })
+        .collect())
+}
+
+/// Accepts several welcomes at once; a failure for one id does not stop the others.
+#[frb]
+pub async fn accept_welcomes(
+    pubkey: String,
+    welcome_event_ids: Vec<String>,
+) -> Result<Vec<WelcomeActionResult>, ApiError> {
+    batch_welcome_action(pubkey, welcome_event_ids, true).await
+}
+
+/// Declines several welcomes at once; a failure for one id does not stop the others.
+#[frb]
+pub async fn decline_welcomes(
+    pubkey: String,
+    welcome_event_ids: Vec<String>,
+) -> Result<Vec<WelcomeActionResult>, ApiError> {
+    batch_welcome_action(pubkey, welcome_event_ids, false).await
+}
+
+/// How often pending welcomes are checked against the auto-accept rule
+const WELCOME_AUTO_ACCEPT_INTERVAL: Duration = Duration::from_secs(10);
+
+static WELCOME_AUTO_ACCEPT_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether a pending welcome is accepted without asking under `policy`.
+///
+/// Only welcomes from followed pubkeys qualify, and only if the group is not larger
+/// than the policy allows.
+fn should_auto_accept(
+    policy: &WhitenoiseWelcomePolicy,
+    welcomer: &PublicKey,
+    member_count: u32,
+    follows: &HashSet<PublicKey>,
+) -> bool {
+    policy.auto_accept_from_follows
+        && follows.contains(welcomer)
+        && policy
+            .max_member_count
+            .is_none_or(|max| member_count <= max)
+}
+
+/// Accepts the account's pending welcomes that match the auto-accept rule.
+async fn auto_accept_welcomes(whitenoise: &Whitenoise, account: &Account) -> Result<(), ApiError> {
+    let policy = whitenoise.app_settings().await?.welcome_policy;
+    if !policy.auto_accept_from_follows {
+        return Ok(());
+    }
+    let follows: HashSet<PublicKey> = whitenoise
+        .follows(account)
+        .await?
+        .into_iter()
+        .map(|user| user.pubkey)
+        .collect();
+    let welcome_event_ids: Vec<String> = whitenoise
+        .pending_welcomes(&account.pubkey)
+        .await?
+        .into_iter()
+        .filter(|w| should_auto_accept(&policy, &w.welcomer, w.member_count, &follows))
+        .map(|w| w.id.to_string())
+        .collect();
+    if !welcome_event_ids.is_empty() {
+        // Welcomes that fail to accept stay pending and are retried on the next tick
+        whitenoise
+            .accept_welcomes(&account.pubkey, &welcome_event_ids)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Starts the background task that applies the auto-accept rule to processed welcomes.
+///
+/// Called once Whitenoise is initialized; later calls are no-ops.
+pub(crate) fn spawn_welcome_auto_accept() {
+    if WELCOME_AUTO_ACCEPT_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(WELCOME_AUTO_ACCEPT_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Ok(whitenoise) = Whitenoise::get_instance() else {
+                continue;
+            };
+            let Ok(accounts) = whitenoise.all_accounts().await else {
+                continue;
+            };
+            for account in accounts {
+                let _ = auto_accept_welcomes(whitenoise, &account).await;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(
+        auto_accept_from_follows: bool,
+        max_member_count: Option<u32>,
+    ) -> WhitenoiseWelcomePolicy {
+        WhitenoiseWelcomePolicy {
+            sender_policy: WhitenoiseWelcomeSenderPolicy::Anyone,
+            max_member_count,
+            auto_accept_from_follows,
+        }
+    }
+
+    #[test]
+    fn auto_accepts_only_welcomes_from_follows() {
+        let followed = Keys::generate().public_key();
+        let stranger = Keys::generate().public_key();
+        let follows = HashSet::from([followed]);
+
+        assert!(should_auto_accept(
+            &policy(true, None),
+            &followed,
+            3,
+            &follows
+        ));
+        assert!(!should_auto_accept(
+            &policy(true, None),
+            &stranger,
+            3,
+            &follows
+        ));
+    }
+
+    #[test]
+    fn auto_accept_is_off_unless_enabled() {
+        let followed = Keys::generate().public_key();
+        let follows = HashSet::from([followed]);
+        assert!(!should_auto_accept(
+            &policy(false, None),
+            &followed,
+            3,
+            &follows
+        ));
+    }
+
+    #[test]
+    fn auto_accept_respects_the_member_limit() {
+        let followed = Keys::generate().public_key();
+        let follows = HashSet::from([followed]);
+        assert!(should_auto_accept(
+            &policy(true, Some(3)),
+            &followed,
+            3,
+            &follows
+        ));
+        assert!(!should_auto_accept(
+            &policy(true, Some(3)),
+            &followed,
+            4,
+            &follows
+        ));
+    }
+}
@@ -41,7 +41,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -481785978;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1718174739;
 
 // Section: executor
 
@@ -88,6 +88,47 @@ fn wire__crate__api__welcomes__accept_welcome_impl(
         },
     )
 }
+fn wire__crate__api__welcomes__accept_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "accept_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::accept_welcomes(
+                            api_pubkey,
+                            api_welcome_event_ids,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__accounts__account_follows_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -234,6 +275,43 @@ fn wire__crate__api__accounts__account_metadata_impl(
         },
     )
 }
+fn wire__crate__api__relays__account_relay_health_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "account_relay_health",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relays::account_relay_health(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__accounts__account_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -258,13 +336,17 @@ fn wire__crate__api__accounts__account_relays_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
             let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_include_info = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::accounts::account_relays(api_pubkey, api_relay_type)
-                                .await?;
+                        let output_ok = crate::api::accounts::account_relays(
+                            api_pubkey,
+                            api_relay_type,
+                            api_include_info,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -395,6 +477,46 @@ fn wire__crate__api__groups__add_members_to_group_impl(
         },
     )
 }
+fn wire__crate__api__welcomes__all_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "all_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_state_filter =
+                <Option<crate::api::welcomes::WelcomeState>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::welcomes::all_welcomes(api_pubkey, api_state_filter)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__error__api_error_error_type_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -463,7 +585,7 @@ fn wire__crate__api__error__api_error_message_text_impl(
         },
     )
 }
-fn wire__crate__api__groups__create_group_impl(
+fn wire__crate__api__invites__approve_join_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -471,7 +593,7 @@ fn wire__crate__api__groups__create_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_group",
+            debug_name: "approve_join_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -485,23 +607,17 @@ fn wire__crate__api__groups__create_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_creator_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_admin_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_group_name = <String>::sse_decode(&mut deserializer);
-            let api_group_description = <String>::sse_decode(&mut deserializer);
-            let api_group_type = <crate::api::groups::GroupType>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::create_group(
-                            api_creator_pubkey,
-                            api_member_pubkeys,
-                            api_admin_pubkeys,
-                            api_group_name,
-                            api_group_description,
-                            api_group_type,
+                        let output_ok = crate::api::invites::approve_join_request(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_request_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -512,7 +628,7 @@ fn wire__crate__api__groups__create_group_impl(
         },
     )
 }
-fn wire__crate__api__accounts__create_identity_impl(
+fn wire__crate__api__welcomes__block_welcomer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -520,7 +636,7 @@ fn wire__crate__api__accounts__create_identity_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_identity",
+            debug_name: "block_welcomer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -534,11 +650,15 @@ fn wire__crate__api__accounts__create_identity_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcomer_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::create_identity().await?;
+                        let output_ok =
+                            crate::api::welcomes::block_welcomer(api_pubkey, api_welcomer_pubkey)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -547,15 +667,15 @@ fn wire__crate__api__accounts__create_identity_impl(
         },
     )
 }
-fn wire__crate__api__create_whitenoise_config_impl(
+fn wire__crate__api__welcomes__blocked_welcomers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_whitenoise_config",
+            debug_name: "blocked_welcomers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -569,22 +689,21 @@ fn wire__crate__api__create_whitenoise_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_logs_dir = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::create_whitenoise_config(
-                        api_data_dir,
-                        api_logs_dir,
-                    ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::blocked_welcomers(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__welcomes__decline_welcome_impl(
+fn wire__crate__api__groups__create_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -592,7 +711,7 @@ fn wire__crate__api__welcomes__decline_welcome_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decline_welcome",
+            debug_name: "create_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -606,15 +725,25 @@ fn wire__crate__api__welcomes__decline_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
+            let api_creator_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_admin_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_group_name = <String>::sse_decode(&mut deserializer);
+            let api_group_description = <String>::sse_decode(&mut deserializer);
+            let api_group_type = <crate::api::groups::GroupType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::welcomes::decline_welcome(api_pubkey, api_welcome_event_id)
-                                .await?;
+                        let output_ok = crate::api::groups::create_group(
+                            api_creator_pubkey,
+                            api_member_pubkeys,
+                            api_admin_pubkeys,
+                            api_group_name,
+                            api_group_description,
+                            api_group_type,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -623,7 +752,7 @@ fn wire__crate__api__welcomes__decline_welcome_impl(
         },
     )
 }
-fn wire__crate__api__accounts__delete_account_key_package_impl(
+fn wire__crate__api__accounts__create_identity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -631,7 +760,7 @@ fn wire__crate__api__accounts__delete_account_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_account_key_package",
+            debug_name: "create_identity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -645,17 +774,11 @@ fn wire__crate__api__accounts__delete_account_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_key_package_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::delete_account_key_package(
-                            api_account_pubkey,
-                            api_key_package_id,
-                        )
-                        .await?;
+                        let output_ok = crate::api::accounts::create_identity().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -664,7 +787,7 @@ fn wire__crate__api__accounts__delete_account_key_package_impl(
         },
     )
 }
-fn wire__crate__api__accounts__delete_account_key_packages_impl(
+fn wire__crate__api__invites__create_invite_link_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -672,7 +795,7 @@ fn wire__crate__api__accounts__delete_account_key_packages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_account_key_packages",
+            debug_name: "create_invite_link",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -687,13 +810,21 @@ fn wire__crate__api__accounts__delete_account_key_packages_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_expires_at =
+                <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(&mut deserializer);
+            let api_max_uses = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::accounts::delete_account_key_packages(api_account_pubkey)
-                                .await?;
+                        let output_ok = crate::api::invites::create_invite_link(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_expires_at,
+                            api_max_uses,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -702,15 +833,15 @@ fn wire__crate__api__accounts__delete_account_key_packages_impl(
         },
     )
 }
-fn wire__crate__api__delete_all_data_impl(
+fn wire__crate__api__create_whitenoise_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_all_data",
+            debug_name: "create_whitenoise_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -724,20 +855,24 @@ fn wire__crate__api__delete_all_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_logs_dir = <String>::sse_decode(&mut deserializer);
+            let api_proxy_url = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::ApiError>(
-                    (move || async move {
-                        let output_ok = crate::api::delete_all_data().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::create_whitenoise_config(
+                        api_data_dir,
+                        api_logs_dir,
+                        api_proxy_url,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__media_files__download_chat_media_impl(
+fn wire__crate__api__invites__decline_join_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -745,7 +880,7 @@ fn wire__crate__api__media_files__download_chat_media_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_chat_media",
+            debug_name: "decline_join_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -761,15 +896,15 @@ fn wire__crate__api__media_files__download_chat_media_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             let api_group_id = <String>::sse_decode(&mut deserializer);
-            let api_original_file_hash = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::media_files::download_chat_media(
+                        let output_ok = crate::api::invites::decline_join_request(
                             api_account_pubkey,
                             api_group_id,
-                            api_original_file_hash,
+                            api_request_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -780,7 +915,7 @@ fn wire__crate__api__media_files__download_chat_media_impl(
         },
     )
 }
-fn wire__crate__api__relays__ensure_all_subscriptions_impl(
+fn wire__crate__api__welcomes__decline_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -788,7 +923,7 @@ fn wire__crate__api__relays__ensure_all_subscriptions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ensure_all_subscriptions",
+            debug_name: "decline_welcome",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -802,11 +937,15 @@ fn wire__crate__api__relays__ensure_all_subscriptions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::relays::ensure_all_subscriptions().await?;
+                        let output_ok =
+                            crate::api::welcomes::decline_welcome(api_pubkey, api_welcome_event_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -815,7 +954,7 @@ fn wire__crate__api__relays__ensure_all_subscriptions_impl(
         },
     )
 }
-fn wire__crate__api__accounts__export_account_nsec_impl(
+fn wire__crate__api__welcomes__decline_welcomes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -823,7 +962,7 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_account_nsec",
+            debug_name: "decline_welcomes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -838,12 +977,16 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::accounts::export_account_nsec(api_pubkey).await?;
+                        let output_ok = crate::api::welcomes::decline_welcomes(
+                            api_pubkey,
+                            api_welcome_event_ids,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -852,7 +995,7 @@ fn wire__crate__api__accounts__export_account_nsec_impl(
         },
     )
 }
-fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+fn wire__crate__api__accounts__delete_account_key_package_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -860,7 +1003,7 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_aggregated_messages_for_group",
+            debug_name: "delete_account_key_package",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -874,15 +1017,15 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_key_package_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::messages::fetch_aggregated_messages_for_group(
-                            api_pubkey,
-                            api_group_id,
+                        let output_ok = crate::api::accounts::delete_account_key_package(
+                            api_account_pubkey,
+                            api_key_package_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -893,7 +1036,7 @@ fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
         },
     )
 }
-fn wire__crate__api__welcomes__find_welcome_by_event_id_impl(
+fn wire__crate__api__accounts__delete_account_key_packages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -901,7 +1044,7 @@ fn wire__crate__api__welcomes__find_welcome_by_event_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_welcome_by_event_id",
+            debug_name: "delete_account_key_packages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -915,17 +1058,14 @@ fn wire__crate__api__welcomes__find_welcome_by_event_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::welcomes::find_welcome_by_event_id(
-                            api_pubkey,
-                            api_welcome_event_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::accounts::delete_account_key_packages(api_account_pubkey)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -934,7 +1074,7 @@ fn wire__crate__api__welcomes__find_welcome_by_event_id_impl(
         },
     )
 }
-fn wire__crate__api__accounts__follow_user_impl(
+fn wire__crate__api__delete_all_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -942,7 +1082,7 @@ fn wire__crate__api__accounts__follow_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "follow_user",
+            debug_name: "delete_all_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -956,17 +1096,11 @@ fn wire__crate__api__accounts__follow_user_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_user_to_follow_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::follow_user(
-                            api_account_pubkey,
-                            api_user_to_follow_pubkey,
-                        )
-                        .await?;
+                        let output_ok = crate::api::delete_all_data().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -975,7 +1109,7 @@ fn wire__crate__api__accounts__follow_user_impl(
         },
     )
 }
-fn wire__crate__api__accounts__get_account_impl(
+fn wire__crate__api__media_files__download_chat_media_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -983,7 +1117,7 @@ fn wire__crate__api__accounts__get_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_account",
+            debug_name: "download_chat_media",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -997,12 +1131,21 @@ fn wire__crate__api__accounts__get_account_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_original_file_hash = <String>::sse_decode(&mut deserializer);
+            let api_user_initiated = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::get_account(api_pubkey).await?;
+                        let output_ok = crate::api::media_files::download_chat_media(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_original_file_hash,
+                            api_user_initiated,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1011,7 +1154,7 @@ fn wire__crate__api__accounts__get_account_impl(
         },
     )
 }
-fn wire__crate__api__relays__get_account_relay_statuses_impl(
+fn wire__crate__api__relays__ensure_all_subscriptions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1019,7 +1162,7 @@ fn wire__crate__api__relays__get_account_relay_statuses_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_account_relay_statuses",
+            debug_name: "ensure_all_subscriptions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1033,13 +1176,11 @@ fn wire__crate__api__relays__get_account_relay_statuses_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::relays::get_account_relay_statuses(api_pubkey).await?;
+                        let output_ok = crate::api::relays::ensure_all_subscriptions().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1048,7 +1189,7 @@ fn wire__crate__api__relays__get_account_relay_statuses_impl(
         },
     )
 }
-fn wire__crate__api__accounts__get_accounts_impl(
+fn wire__crate__api__accounts__export_account_nsec_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1056,7 +1197,7 @@ fn wire__crate__api__accounts__get_accounts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_accounts",
+            debug_name: "export_account_nsec",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1070,11 +1211,13 @@ fn wire__crate__api__accounts__get_accounts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::get_accounts().await?;
+                        let output_ok =
+                            crate::api::accounts::export_account_nsec(api_pubkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1083,7 +1226,7 @@ fn wire__crate__api__accounts__get_accounts_impl(
         },
     )
 }
-fn wire__crate__api__get_app_settings_impl(
+fn wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1091,7 +1234,7 @@ fn wire__crate__api__get_app_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_app_settings",
+            debug_name: "fetch_aggregated_messages_for_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1105,11 +1248,17 @@ fn wire__crate__api__get_app_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::get_app_settings().await?;
+                        let output_ok = crate::api::messages::fetch_aggregated_messages_for_group(
+                            api_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1118,40 +1267,7 @@ fn wire__crate__api__get_app_settings_impl(
         },
     )
 }
-fn wire__crate__api__utils__get_default_blossom_server_url_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_default_blossom_server_url",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::utils::get_default_blossom_server_url())?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__groups__get_group_impl(
+fn wire__crate__api__groups__find_or_create_dm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1159,7 +1275,7 @@ fn wire__crate__api__groups__get_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group",
+            debug_name: "find_or_create_dm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1174,13 +1290,16 @@ fn wire__crate__api__groups__get_group_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::groups::get_group(api_account_pubkey, api_group_id).await?;
+                        let output_ok = crate::api::groups::find_or_create_dm(
+                            api_account_pubkey,
+                            api_peer_pubkey,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1189,7 +1308,7 @@ fn wire__crate__api__groups__get_group_impl(
         },
     )
 }
-fn wire__crate__api__groups__get_group_image_path_impl(
+fn wire__crate__api__welcomes__find_welcome_by_event_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1197,7 +1316,7 @@ fn wire__crate__api__groups__get_group_image_path_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_image_path",
+            debug_name: "find_welcome_by_event_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1211,15 +1330,15 @@ fn wire__crate__api__groups__get_group_image_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::get_group_image_path(
-                            api_account_pubkey,
-                            api_group_id,
+                        let output_ok = crate::api::welcomes::find_welcome_by_event_id(
+                            api_pubkey,
+                            api_welcome_event_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1230,7 +1349,7 @@ fn wire__crate__api__groups__get_group_image_path_impl(
         },
     )
 }
-fn wire__crate__api__groups__get_group_information_impl(
+fn wire__crate__api__accounts__follow_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1238,7 +1357,7 @@ fn wire__crate__api__groups__get_group_information_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_information",
+            debug_name: "follow_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1253,14 +1372,14 @@ fn wire__crate__api__groups__get_group_information_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_user_to_follow_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::get_group_information(
+                        let output_ok = crate::api::accounts::follow_user(
                             api_account_pubkey,
-                            api_group_id,
+                            api_user_to_follow_pubkey,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1271,7 +1390,7 @@ fn wire__crate__api__groups__get_group_information_impl(
         },
     )
 }
-fn wire__crate__api__groups__get_groups_informations_impl(
+fn wire__crate__api__accounts__get_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1279,7 +1398,7 @@ fn wire__crate__api__groups__get_groups_informations_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_groups_informations",
+            debug_name: "get_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1293,17 +1412,12 @@ fn wire__crate__api__groups__get_groups_informations_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::get_groups_informations(
-                            api_account_pubkey,
-                            api_group_ids,
-                        )
-                        .await?;
+                        let output_ok = crate::api::accounts::get_account(api_pubkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1312,7 +1426,7 @@ fn wire__crate__api__groups__get_groups_informations_impl(
         },
     )
 }
-fn wire__crate__api__users__get_user_impl(
+fn wire__crate__api__relays__get_account_relay_statuses_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1320,7 +1434,7 @@ fn wire__crate__api__users__get_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_user",
+            debug_name: "get_account_relay_statuses",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1335,13 +1449,12 @@ fn wire__crate__api__users__get_user_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_blocking_data_sync = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::users::get_user(api_pubkey, api_blocking_data_sync).await?;
+                            crate::api::relays::get_account_relay_statuses(api_pubkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1350,7 +1463,7 @@ fn wire__crate__api__users__get_user_impl(
         },
     )
 }
-fn wire__crate__api__groups__group_admins_impl(
+fn wire__crate__api__accounts__get_accounts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1358,7 +1471,7 @@ fn wire__crate__api__groups__group_admins_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_admins",
+            debug_name: "get_accounts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1372,14 +1485,11 @@ fn wire__crate__api__groups__group_admins_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::groups::group_admins(api_pubkey, api_group_id).await?;
+                        let output_ok = crate::api::accounts::get_accounts().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1388,7 +1498,7 @@ fn wire__crate__api__groups__group_admins_impl(
         },
     )
 }
-fn wire__crate__api__groups__group_group_type_impl(
+fn wire__crate__api__get_app_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1396,7 +1506,7 @@ fn wire__crate__api__groups__group_group_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_group_type",
+            debug_name: "get_app_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1410,15 +1520,11 @@ fn wire__crate__api__groups__group_group_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::groups::Group::group_type(&api_that, api_account_pubkey)
-                                .await?;
+                        let output_ok = crate::api::get_app_settings().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1427,7 +1533,7 @@ fn wire__crate__api__groups__group_group_type_impl(
         },
     )
 }
-fn wire__crate__api__utils__group_id_from_string_impl(
+fn wire__crate__api__utils__get_default_blossom_server_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1435,7 +1541,7 @@ fn wire__crate__api__utils__group_id_from_string_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_id_from_string",
+            debug_name: "get_default_blossom_server_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1449,26 +1555,26 @@ fn wire__crate__api__utils__group_id_from_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::ApiError>((move || {
-                    let output_ok = crate::api::utils::group_id_from_string(&api_group_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::utils::get_default_blossom_server_url())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__utils__group_id_to_string_impl(
+fn wire__crate__api__groups__get_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_id_to_string",
+            debug_name: "get_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1482,38 +1588,23 @@ fn wire__crate__api__utils__group_id_to_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
-            >>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_group_id_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_group_id,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_group_id_guard = Some(api_group_id.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_group_id_guard = api_group_id_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::group_id_to_string(
-                        &*api_group_id_guard,
-                    ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::groups::get_group(api_account_pubkey, api_group_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__groups__group_is_direct_message_type_impl(
+fn wire__crate__api__groups__get_group_image_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1521,7 +1612,7 @@ fn wire__crate__api__groups__group_is_direct_message_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_is_direct_message_type",
+            debug_name: "get_group_image_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1535,15 +1626,15 @@ fn wire__crate__api__groups__group_is_direct_message_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::Group::is_direct_message_type(
-                            &api_that,
+                        let output_ok = crate::api::groups::get_group_image_path(
                             api_account_pubkey,
+                            api_group_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1554,7 +1645,7 @@ fn wire__crate__api__groups__group_is_direct_message_type_impl(
         },
     )
 }
-fn wire__crate__api__groups__group_is_group_type_impl(
+fn wire__crate__api__groups__get_group_information_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1562,7 +1653,7 @@ fn wire__crate__api__groups__group_is_group_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_is_group_type",
+            debug_name: "get_group_information",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1576,24 +1667,26 @@ fn wire__crate__api__groups__group_is_group_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::groups::Group::is_group_type(&api_that, api_account_pubkey)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+                        let output_ok = crate::api::groups::get_group_information(
+                            api_account_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__groups__group_members_impl(
+fn wire__crate__api__groups__get_groups_informations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1601,7 +1694,7 @@ fn wire__crate__api__groups__group_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_members",
+            debug_name: "get_groups_informations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1615,14 +1708,17 @@ fn wire__crate__api__groups__group_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::groups::group_members(api_pubkey, api_group_id).await?;
+                        let output_ok = crate::api::groups::get_groups_informations(
+                            api_account_pubkey,
+                            api_group_ids,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1631,7 +1727,7 @@ fn wire__crate__api__groups__group_members_impl(
         },
     )
 }
-fn wire__crate__api__groups__group_update_group_data_impl(
+fn wire__crate__api__proxy__get_proxy_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1639,7 +1735,7 @@ fn wire__crate__api__groups__group_update_group_data_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_update_group_data",
+            debug_name: "get_proxy_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1653,20 +1749,11 @@ fn wire__crate__api__groups__group_update_group_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_data =
-                <crate::api::groups::FlutterGroupDataUpdate>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::Group::update_group_data(
-                            &api_that,
-                            api_account_pubkey,
-                            api_group_data,
-                        )
-                        .await?;
+                        let output_ok = crate::api::proxy::get_proxy_settings().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1675,37 +1762,7 @@ fn wire__crate__api__groups__group_update_group_data_impl(
         },
     )
 }
-fn wire__crate__api__utils__hex_pubkey_from_npub_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "hex_pubkey_from_npub",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_npub = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, crate::api::error::ApiError>((move || {
-                let output_ok = crate::api::utils::hex_pubkey_from_npub(&api_npub)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__initialize_whitenoise_impl(
+fn wire__crate__api__users__get_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1713,7 +1770,7 @@ fn wire__crate__api__initialize_whitenoise_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_whitenoise",
+            debug_name: "get_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1727,12 +1784,14 @@ fn wire__crate__api__initialize_whitenoise_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <crate::api::WhitenoiseConfig>::sse_decode(&mut deserializer);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::initialize_whitenoise(api_config).await?;
+                        let output_ok =
+                            crate::api::users::get_user(api_pubkey, api_sync_policy).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1741,7 +1800,7 @@ fn wire__crate__api__initialize_whitenoise_impl(
         },
     )
 }
-fn wire__crate__api__accounts__login_impl(
+fn wire__crate__api__users__get_users_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1749,7 +1808,7 @@ fn wire__crate__api__accounts__login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "get_users",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1763,13 +1822,14 @@ fn wire__crate__api__accounts__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_nsec_or_hex_privkey = <String>::sse_decode(&mut deserializer);
+            let api_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::accounts::login(api_nsec_or_hex_privkey).await?;
+                            crate::api::users::get_users(api_pubkeys, api_sync_policy).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1778,7 +1838,7 @@ fn wire__crate__api__accounts__login_impl(
         },
     )
 }
-fn wire__crate__api__accounts__logout_impl(
+fn wire__crate__api__get_welcome_policy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1786,7 +1846,7 @@ fn wire__crate__api__accounts__logout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout",
+            debug_name: "get_welcome_policy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1800,12 +1860,11 @@ fn wire__crate__api__accounts__logout_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::logout(api_pubkey).await?;
+                        let output_ok = crate::api::get_welcome_policy().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1814,37 +1873,7 @@ fn wire__crate__api__accounts__logout_impl(
         },
     )
 }
-fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "npub_from_hex_pubkey",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_hex_pubkey = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, crate::api::error::ApiError>((move || {
-                let output_ok = crate::api::utils::npub_from_hex_pubkey(&api_hex_pubkey)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__welcomes__pending_welcomes_impl(
+fn wire__crate__api__groups__group_admins_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1852,7 +1881,7 @@ fn wire__crate__api__welcomes__pending_welcomes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pending_welcomes",
+            debug_name: "group_admins",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1867,11 +1896,13 @@ fn wire__crate__api__welcomes__pending_welcomes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::welcomes::pending_welcomes(api_pubkey).await?;
+                        let output_ok =
+                            crate::api::groups::group_admins(api_pubkey, api_group_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1880,7 +1911,7 @@ fn wire__crate__api__welcomes__pending_welcomes_impl(
         },
     )
 }
-fn wire__crate__api__accounts__publish_account_key_package_impl(
+fn wire__crate__api__groups__group_group_type_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1888,7 +1919,7 @@ fn wire__crate__api__accounts__publish_account_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "publish_account_key_package",
+            debug_name: "group_group_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1902,13 +1933,14 @@ fn wire__crate__api__accounts__publish_account_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::accounts::publish_account_key_package(api_account_pubkey)
+                            crate::api::groups::Group::group_type(&api_that, api_account_pubkey)
                                 .await?;
                         Ok(output_ok)
                     })()
@@ -1918,7 +1950,7 @@ fn wire__crate__api__accounts__publish_account_key_package_impl(
         },
     )
 }
-fn wire__crate__api__relays__relay_type_inbox_impl(
+fn wire__crate__api__utils__group_id_from_string_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1926,7 +1958,7 @@ fn wire__crate__api__relays__relay_type_inbox_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_inbox",
+            debug_name: "group_id_from_string",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1940,17 +1972,18 @@ fn wire__crate__api__relays__relay_type_inbox_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_inbox())?;
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::utils::group_id_from_string(&api_group_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relays__relay_type_key_package_impl(
+fn wire__crate__api__utils__group_id_to_string_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1958,7 +1991,7 @@ fn wire__crate__api__relays__relay_type_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_key_package",
+            debug_name: "group_id_to_string",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1972,28 +2005,47 @@ fn wire__crate__api__relays__relay_type_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::relays::relay_type_key_package())?;
+                    let mut api_group_id_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_group_id,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_group_id_guard = Some(api_group_id.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_group_id_guard = api_group_id_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::group_id_to_string(
+                        &*api_group_id_guard,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relays__relay_type_nip65_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__groups__group_is_admin_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_type_nip65",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "group_is_admin",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2005,25 +2057,27 @@ fn wire__crate__api__relays__relay_type_nip65_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_nip65())?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::groups::Group::is_admin(
+                    &api_that, api_pubkey,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__utils__relay_url_from_string_impl(
+fn wire__crate__api__groups__group_is_direct_message_type_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "relay_url_from_string",
+            debug_name: "group_is_direct_message_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2037,18 +2091,26 @@ fn wire__crate__api__utils__relay_url_from_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::ApiError>((move || {
-                    let output_ok = crate::api::utils::relay_url_from_string(api_url)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::groups::Group::is_direct_message_type(
+                            &api_that,
+                            api_account_pubkey,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__accounts__remove_account_relay_impl(
+fn wire__crate__api__groups__group_is_group_type_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2056,7 +2118,7 @@ fn wire__crate__api__accounts__remove_account_relay_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_account_relay",
+            debug_name: "group_is_group_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2070,19 +2132,15 @@ fn wire__crate__api__accounts__remove_account_relay_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::remove_account_relay(
-                            api_pubkey,
-                            api_url,
-                            api_relay_type,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::groups::Group::is_group_type(&api_that, api_account_pubkey)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2091,7 +2149,7 @@ fn wire__crate__api__accounts__remove_account_relay_impl(
         },
     )
 }
-fn wire__crate__api__groups__remove_members_from_group_impl(
+fn wire__crate__api__groups__group_member_details_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2099,7 +2157,7 @@ fn wire__crate__api__groups__remove_members_from_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_members_from_group",
+            debug_name: "group_member_details",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2115,17 +2173,13 @@ fn wire__crate__api__groups__remove_members_from_group_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
             let api_group_id = <String>::sse_decode(&mut deserializer);
-            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::remove_members_from_group(
-                            api_pubkey,
-                            api_group_id,
-                            api_member_pubkeys,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::groups::group_member_details(api_pubkey, api_group_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2134,7 +2188,7 @@ fn wire__crate__api__groups__remove_members_from_group_impl(
         },
     )
 }
-fn wire__crate__api__messages__send_message_to_group_impl(
+fn wire__crate__api__groups__group_members_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2142,7 +2196,7 @@ fn wire__crate__api__messages__send_message_to_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message_to_group",
+            debug_name: "group_members",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2158,22 +2212,13 @@ fn wire__crate__api__messages__send_message_to_group_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
             let api_group_id = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_kind = <u16>::sse_decode(&mut deserializer);
-            let api_tags = <Option<Vec<Tag>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::messages::send_message_to_group(
-                            api_pubkey,
-                            api_group_id,
-                            api_message,
-                            api_kind,
-                            api_tags,
-                        )
-                        .await?;
-                        Ok(output_ok)
+                        let output_ok =
+                            crate::api::groups::group_members(api_pubkey, api_group_id).await?;
+                        Ok(output_ok)
                     })()
                     .await,
                 )
@@ -2181,7 +2226,7 @@ fn wire__crate__api__messages__send_message_to_group_impl(
         },
     )
 }
-fn wire__crate__api__utils__string_from_relay_url_impl(
+fn wire__crate__api__groups__group_permissions_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2189,7 +2234,7 @@ fn wire__crate__api__utils__string_from_relay_url_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "string_from_relay_url",
+            debug_name: "group_permissions_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2203,48 +2248,26 @@ fn wire__crate__api__utils__string_from_relay_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_url = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let mut api_relay_url_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_relay_url,
-                                0,
-                                false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => {
-                                api_relay_url_guard = Some(api_relay_url.lockable_decode_sync_ref())
-                            }
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_relay_url_guard = api_relay_url_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::string_from_relay_url(
-                        &*api_relay_url_guard,
-                    ))?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::groups::GroupPermissions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__utils__tag_from_vec_impl(
+fn wire__crate__api__groups__group_relay_health_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tag_from_vec",
+            debug_name: "group_relay_health",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2258,18 +2281,26 @@ fn wire__crate__api__utils__tag_from_vec_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_vec = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::ApiError>((move || {
-                    let output_ok = crate::api::utils::tag_from_vec(api_vec)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::groups::group_relay_health(
+                            api_account_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__accounts__unfollow_user_impl(
+fn wire__crate__api__groups__group_update_group_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2277,7 +2308,7 @@ fn wire__crate__api__accounts__unfollow_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unfollow_user",
+            debug_name: "group_update_group_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2291,15 +2322,18 @@ fn wire__crate__api__accounts__unfollow_user_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::groups::Group>::sse_decode(&mut deserializer);
             let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_user_to_unfollow_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_data =
+                <crate::api::groups::FlutterGroupDataUpdate>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::unfollow_user(
+                        let output_ok = crate::api::groups::Group::update_group_data(
+                            &api_that,
                             api_account_pubkey,
-                            api_user_to_unfollow_pubkey,
+                            api_group_data,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2310,7 +2344,37 @@ fn wire__crate__api__accounts__unfollow_user_impl(
         },
     )
 }
-fn wire__crate__api__accounts__update_account_metadata_impl(
+fn wire__crate__api__utils__hex_pubkey_from_npub_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hex_pubkey_from_npub",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_npub = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::utils::hex_pubkey_from_npub(&api_npub)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__welcomes__ignore_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2318,7 +2382,7 @@ fn wire__crate__api__accounts__update_account_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_account_metadata",
+            debug_name: "ignore_welcome",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2333,17 +2397,14 @@ fn wire__crate__api__accounts__update_account_metadata_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_metadata =
-                <crate::api::metadata::FlutterMetadata>::sse_decode(&mut deserializer);
+            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::update_account_metadata(
-                            api_pubkey,
-                            &api_metadata,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::welcomes::ignore_welcome(api_pubkey, api_welcome_event_id)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2352,7 +2413,7 @@ fn wire__crate__api__accounts__update_account_metadata_impl(
         },
     )
 }
-fn wire__crate__api__update_theme_mode_impl(
+fn wire__crate__api__initialize_whitenoise_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2360,7 +2421,7 @@ fn wire__crate__api__update_theme_mode_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_theme_mode",
+            debug_name: "initialize_whitenoise",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2374,12 +2435,12 @@ fn wire__crate__api__update_theme_mode_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_theme_mode = <ThemeMode>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::WhitenoiseConfig>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::update_theme_mode(api_theme_mode).await?;
+                        let output_ok = crate::api::initialize_whitenoise(api_config).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2388,7 +2449,7 @@ fn wire__crate__api__update_theme_mode_impl(
         },
     )
 }
-fn wire__crate__api__accounts__upload_account_profile_picture_impl(
+fn wire__crate__api__network__is_low_bandwidth_mode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2396,7 +2457,7 @@ fn wire__crate__api__accounts__upload_account_profile_picture_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_account_profile_picture",
+            debug_name: "is_low_bandwidth_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2410,21 +2471,11 @@ fn wire__crate__api__accounts__upload_account_profile_picture_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_server_url = <String>::sse_decode(&mut deserializer);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_image_type = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::accounts::upload_account_profile_picture(
-                            api_pubkey,
-                            api_server_url,
-                            api_file_path,
-                            api_image_type,
-                        )
-                        .await?;
+                        let output_ok = crate::api::network::is_low_bandwidth_mode().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2433,7 +2484,7 @@ fn wire__crate__api__accounts__upload_account_profile_picture_impl(
         },
     )
 }
-fn wire__crate__api__media_files__upload_chat_media_impl(
+fn wire__crate__api__network__is_network_paused_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2441,7 +2492,7 @@ fn wire__crate__api__media_files__upload_chat_media_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_chat_media",
+            debug_name: "is_network_paused",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2455,19 +2506,11 @@ fn wire__crate__api__media_files__upload_chat_media_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::media_files::upload_chat_media(
-                            api_account_pubkey,
-                            api_group_id,
-                            api_file_path,
-                        )
-                        .await?;
+                        let output_ok = crate::api::network::is_network_paused().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2476,7 +2519,7 @@ fn wire__crate__api__media_files__upload_chat_media_impl(
         },
     )
 }
-fn wire__crate__api__groups__upload_group_image_impl(
+fn wire__crate__api__accounts__login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2484,7 +2527,7 @@ fn wire__crate__api__groups__upload_group_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_group_image",
+            debug_name: "login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2498,21 +2541,13 @@ fn wire__crate__api__groups__upload_group_image_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_group_id = <String>::sse_decode(&mut deserializer);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_server_url = <String>::sse_decode(&mut deserializer);
+            let api_nsec_or_hex_privkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::groups::upload_group_image(
-                            api_account_pubkey,
-                            api_group_id,
-                            api_file_path,
-                            api_server_url,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::accounts::login(api_nsec_or_hex_privkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2521,7 +2556,7 @@ fn wire__crate__api__groups__upload_group_image_impl(
         },
     )
 }
-fn wire__crate__api__users__user_has_key_package_impl(
+fn wire__crate__api__accounts__logout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2529,7 +2564,7 @@ fn wire__crate__api__users__user_has_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "user_has_key_package",
+            debug_name: "logout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2544,16 +2579,11 @@ fn wire__crate__api__users__user_has_key_package_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_blocking_data_sync = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::users::user_has_key_package(
-                            api_pubkey,
-                            api_blocking_data_sync,
-                        )
-                        .await?;
+                        let output_ok = crate::api::accounts::logout(api_pubkey).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2562,7 +2592,7 @@ fn wire__crate__api__users__user_has_key_package_impl(
         },
     )
 }
-fn wire__crate__api__users__user_metadata_impl(
+fn wire__crate__api__verification__member_fingerprint_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2570,7 +2600,7 @@ fn wire__crate__api__users__user_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "user_metadata",
+            debug_name: "member_fingerprint",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2584,15 +2614,19 @@ fn wire__crate__api__users__user_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_blocking_data_sync = <bool>::sse_decode(&mut deserializer);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_member_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::users::user_metadata(api_pubkey, api_blocking_data_sync)
-                                .await?;
+                        let output_ok = crate::api::verification::member_fingerprint(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_member_pubkey,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2601,7 +2635,7 @@ fn wire__crate__api__users__user_metadata_impl(
         },
     )
 }
-fn wire__crate__api__users__user_relays_impl(
+fn wire__crate__api__messages__moderate_delete_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2609,7 +2643,7 @@ fn wire__crate__api__users__user_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "user_relays",
+            debug_name: "moderate_delete_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2624,16 +2658,16 @@ fn wire__crate__api__users__user_relays_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_pubkey = <String>::sse_decode(&mut deserializer);
-            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
-            let api_blocking_data_sync = <bool>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::users::user_relays(
+                        let output_ok = crate::api::messages::moderate_delete_message(
                             api_pubkey,
-                            api_relay_type,
-                            api_blocking_data_sync,
+                            api_group_id,
+                            api_message_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2644,1713 +2678,5016 @@ fn wire__crate__api__users__user_relays_impl(
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>
-);
-
-// Section: dart2rust
-
-impl SseDecode for AppSettings {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
-}
-
-impl SseDecode for GroupId {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__utils__npub_from_hex_pubkey_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "npub_from_hex_pubkey",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hex_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::utils::npub_from_hex_pubkey(&api_hex_pubkey)?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
+fn wire__crate__api__network__pause_network_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_network",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::network::pause_network().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invites__pending_join_requests_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_join_requests",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::invites::pending_join_requests(
+                            api_account_pubkey,
+                            api_group_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__pending_relay_auth_requests_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_relay_auth_requests",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relays::pending_relay_auth_requests(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__pending_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::pending_welcomes(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__publish_account_key_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_account_key_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::accounts::publish_account_key_package(api_account_pubkey)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__purge_expired_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "purge_expired_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::messages::purge_expired_messages(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_auth_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_auth_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relays::relay_auth_policy(api_pubkey, api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_health_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_health",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::relay_health(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::relay_info(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_inbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_inbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_inbox())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_key_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_key_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::relays::relay_type_key_package())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__relay_type_nip65_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_type_nip65",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::relays::relay_type_nip65())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__relay_url_from_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relay_url_from_string",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::utils::relay_url_from_string(api_url)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__remove_account_relay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_account_relay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::remove_account_relay(
+                            api_pubkey,
+                            api_url,
+                            api_relay_type,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__groups__remove_members_from_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_members_from_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::groups::remove_members_from_group(
+                            api_pubkey,
+                            api_group_id,
+                            api_member_pubkeys,
+                            api_reason,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invites__request_join_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "request_join",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_invite = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::invites::request_join(api_account_pubkey, api_invite)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__respond_to_relay_auth_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "respond_to_relay_auth",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_approve = <bool>::sse_decode(&mut deserializer);
+            let api_remember = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::respond_to_relay_auth(
+                            api_pubkey,
+                            api_url,
+                            api_approve,
+                            api_remember,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network__resume_network_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_network",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::network::resume_network().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__search_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_search_relays = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::users::search_users(
+                            api_account_pubkey,
+                            api_query,
+                            api_search_relays,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__send_message_to_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_to_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_kind = <u16>::sse_decode(&mut deserializer);
+            let api_tags = <Option<Vec<Tag>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::messages::send_message_to_group(
+                            api_pubkey,
+                            api_group_id,
+                            api_message,
+                            api_kind,
+                            api_tags,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__set_account_relays_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_account_relays",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::set_account_relays(
+                            api_pubkey,
+                            api_relay_type,
+                            api_urls,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__verification__set_member_verified_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_member_verified",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_member_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_verified = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::verification::set_member_verified(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_member_pubkey,
+                            api_verified,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__set_relay_auth_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_relay_auth_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::relays::RelayAuthPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::set_relay_auth_policy(
+                            api_pubkey, api_url, api_policy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__string_from_relay_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "string_from_relay_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_relay_url = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_relay_url_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_relay_url,
+                                0,
+                                false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => {
+                                api_relay_url_guard = Some(api_relay_url.lockable_decode_sync_ref())
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_relay_url_guard = api_relay_url_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(crate::api::utils::string_from_relay_url(
+                        &*api_relay_url_guard,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__utils__tag_from_vec_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tag_from_vec",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_vec = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::utils::tag_from_vec(api_vec)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__unblock_welcomer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unblock_welcomer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcomer_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::welcomes::unblock_welcomer(api_pubkey, api_welcomer_pubkey)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__unfollow_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unfollow_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_user_to_unfollow_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::unfollow_user(
+                            api_account_pubkey,
+                            api_user_to_unfollow_pubkey,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__update_account_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_account_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_metadata =
+                <crate::api::metadata::FlutterMetadata>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::update_account_metadata(
+                            api_pubkey,
+                            &api_metadata,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network__update_low_bandwidth_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_low_bandwidth_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::network::update_low_bandwidth_mode(api_enabled).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy__update_proxy_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_proxy_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy_settings =
+                <Option<crate::api::proxy::ProxySettings>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::proxy::update_proxy_settings(api_proxy_settings).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__update_theme_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_theme_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_theme_mode = <ThemeMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::update_theme_mode(api_theme_mode).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__update_welcome_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_welcome_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_welcome_policy =
+                <crate::api::welcomes::WelcomePolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::update_welcome_policy(api_welcome_policy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__accounts__upload_account_profile_picture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_account_profile_picture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_server_url = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_image_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::accounts::upload_account_profile_picture(
+                            api_pubkey,
+                            api_server_url,
+                            api_file_path,
+                            api_image_type,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media_files__upload_chat_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_chat_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::media_files::upload_chat_media(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_file_path,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__groups__upload_group_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_group_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::groups::upload_group_image(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_file_path,
+                            api_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__user_has_key_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "user_has_key_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::users::user_has_key_package(api_pubkey, api_sync_policy)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__user_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "user_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::users::user_metadata(api_pubkey, api_sync_policy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__user_relays_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "user_relays",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::users::user_relays(
+                            api_pubkey,
+                            api_relay_type,
+                            api_sync_policy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relays__validate_relay_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_relay_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_relay_type = <RelayType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::relays::validate_relay_url(
+                            api_pubkey,
+                            api_url,
+                            api_relay_type,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__verification__verify_member_qr_payload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_member_qr_payload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            let api_member_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_scanned_payload = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::verification::verify_member_qr_payload(
+                            api_account_pubkey,
+                            api_group_id,
+                            api_member_pubkey,
+                            api_scanned_payload,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__verify_user_nip05_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_user_nip05",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::users::verify_user_nip05(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__groups__watch_group_relay_alerts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_group_relay_alerts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_account_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::groups::GroupRelayAlert,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::groups::watch_group_relay_alerts(
+                            api_account_pubkey,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__users__watch_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_sync_policy = <crate::api::users::SyncPolicy>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::users::User,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::users::watch_users(api_pubkeys, api_sync_policy, api_sink)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__welcome_preview_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "welcome_preview",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::welcomes::welcome_preview(api_pubkey, api_welcome_event_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__welcome_requests_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "welcome_requests",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::welcome_requests(api_pubkey).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for AppSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for GroupId {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for RelayType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RelayUrl {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Tag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for ThemeMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for chrono::DateTime<chrono::Utc> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
+            chrono::DateTime::from_timestamp_micros(inner)
+                .expect("invalid or out-of-range datetime")
+                .naive_utc(),
+            chrono::Utc,
+        );
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, crate::api::metadata::MetadataValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner =
+            <Vec<(String, crate::api::metadata::MetadataValue)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::groups::GroupRelayAlert,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::users::User, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::accounts::Account {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_lastSyncedAt =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::accounts::Account {
+            pubkey: var_pubkey,
+            last_synced_at: var_lastSyncedAt,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::ApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::Whitenoise {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::InvalidKey {
+                    message: var_message,
+                };
+            }
+            2 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::NostrUrl {
+                    message: var_message,
+                };
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::NostrTag {
+                    message: var_message,
+                };
+            }
+            4 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::NostrEvent {
+                    message: var_message,
+                };
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::NostrParse {
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::NostrHex {
+                    message: var_message,
+                };
+            }
+            7 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::PermissionDenied {
+                    message: var_message,
+                };
+            }
+            8 => {
+                let mut var_reason =
+                    <crate::api::relays::InvalidRelayUrlReason>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::InvalidRelayUrl {
+                    reason: var_reason,
+                    message: var_message,
+                };
+            }
+            9 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::Other {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::welcomes::BlockWelcomerResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_declined = <u32>::sse_decode(deserializer);
+        let mut var_failed =
+            <Vec<crate::api::welcomes::WelcomeActionResult>>::sse_decode(deserializer);
+        return crate::api::welcomes::BlockWelcomerResult {
+            declined: var_declined,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::messages::ChatMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_isReply = <bool>::sse_decode(deserializer);
+        let mut var_replyToId = <Option<String>>::sse_decode(deserializer);
+        let mut var_isDeleted = <bool>::sse_decode(deserializer);
+        let mut var_contentTokens =
+            <Vec<crate::api::messages::SerializableToken>>::sse_decode(deserializer);
+        let mut var_reactions = <crate::api::messages::ReactionSummary>::sse_decode(deserializer);
+        let mut var_mediaAttachments =
+            <Vec<crate::api::media_files::MediaFile>>::sse_decode(deserializer);
+        let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_systemEvent =
+            <Option<crate::api::messages::SystemEvent>>::sse_decode(deserializer);
+        return crate::api::messages::ChatMessage {
+            id: var_id,
+            pubkey: var_pubkey,
+            content: var_content,
+            created_at: var_createdAt,
+            tags: var_tags,
+            is_reply: var_isReply,
+            reply_to_id: var_replyToId,
+            is_deleted: var_isDeleted,
+            content_tokens: var_contentTokens,
+            reactions: var_reactions,
+            media_attachments: var_mediaAttachments,
+            kind: var_kind,
+            system_event: var_systemEvent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messages::EmojiReaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        let mut var_users = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::messages::EmojiReaction {
+            emoji: var_emoji,
+            count: var_count,
+            users: var_users,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::media_files::FileMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_originalFilename = <Option<String>>::sse_decode(deserializer);
+        let mut var_dimensions = <Option<String>>::sse_decode(deserializer);
+        let mut var_blurhash = <Option<String>>::sse_decode(deserializer);
+        return crate::api::media_files::FileMetadata {
+            original_filename: var_originalFilename,
+            dimensions: var_dimensions,
+            blurhash: var_blurhash,
+        };
+    }
+}
+
+impl SseDecode for crate::api::accounts::FlutterEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        return crate::api::accounts::FlutterEvent {
+            id: var_id,
+            pubkey: var_pubkey,
+            created_at: var_createdAt,
+            kind: var_kind,
+            tags: var_tags,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::FlutterGroupDataUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_relays = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_admins = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_imageKey = <Option<[u8; 32]>>::sse_decode(deserializer);
+        let mut var_imageHash = <Option<[u8; 32]>>::sse_decode(deserializer);
+        let mut var_imageNonce = <Option<[u8; 12]>>::sse_decode(deserializer);
+        let mut var_disappearingMessagesSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_permissions =
+            <Option<crate::api::groups::GroupPermissions>>::sse_decode(deserializer);
+        let mut var_replaceUnhealthyRelays = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::groups::FlutterGroupDataUpdate {
+            name: var_name,
+            description: var_description,
+            relays: var_relays,
+            admins: var_admins,
+            image_key: var_imageKey,
+            image_hash: var_imageHash,
+            image_nonce: var_imageNonce,
+            disappearing_messages_secs: var_disappearingMessagesSecs,
+            permissions: var_permissions,
+            replace_unhealthy_relays: var_replaceUnhealthyRelays,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::FlutterMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_about = <Option<String>>::sse_decode(deserializer);
+        let mut var_picture = <Option<String>>::sse_decode(deserializer);
+        let mut var_banner = <Option<String>>::sse_decode(deserializer);
+        let mut var_website = <Option<String>>::sse_decode(deserializer);
+        let mut var_nip05 = <Option<String>>::sse_decode(deserializer);
+        let mut var_lud06 = <Option<String>>::sse_decode(deserializer);
+        let mut var_lud16 = <Option<String>>::sse_decode(deserializer);
+        let mut var_custom =
+            <std::collections::HashMap<String, crate::api::metadata::MetadataValue>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::metadata::FlutterMetadata {
+            name: var_name,
+            display_name: var_displayName,
+            about: var_about,
+            picture: var_picture,
+            banner: var_banner,
+            website: var_website,
+            nip05: var_nip05,
+            lud06: var_lud06,
+            lud16: var_lud16,
+            custom: var_custom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::Group {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
+        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_imageHash = <Option<[u8; 32]>>::sse_decode(deserializer);
+        let mut var_imageKey = <Option<[u8; 32]>>::sse_decode(deserializer);
+        let mut var_adminPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_lastMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastMessageAt =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_state = <crate::api::groups::GroupState>::sse_decode(deserializer);
+        let mut var_disappearingMessagesSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_permissions = <crate::api::groups::GroupPermissions>::sse_decode(deserializer);
+        return crate::api::groups::Group {
+            mls_group_id: var_mlsGroupId,
+            nostr_group_id: var_nostrGroupId,
+            name: var_name,
+            description: var_description,
+            image_hash: var_imageHash,
+            image_key: var_imageKey,
+            admin_pubkeys: var_adminPubkeys,
+            last_message_id: var_lastMessageId,
+            last_message_at: var_lastMessageAt,
+            epoch: var_epoch,
+            state: var_state,
+            disappearing_messages_secs: var_disappearingMessagesSecs,
+            permissions: var_permissions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupInformation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
+        let mut var_groupType = <crate::api::groups::GroupType>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::groups::GroupInformation {
+            mls_group_id: var_mlsGroupId,
+            group_type: var_groupType,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupMember {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_metadata = <crate::api::metadata::FlutterMetadata>::sse_decode(deserializer);
+        let mut var_isAdmin = <bool>::sse_decode(deserializer);
+        let mut var_isSelf = <bool>::sse_decode(deserializer);
+        let mut var_addedAtEpoch = <Option<u64>>::sse_decode(deserializer);
+        let mut var_addedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_leafIndex = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::groups::GroupMember {
+            pubkey: var_pubkey,
+            metadata: var_metadata,
+            is_admin: var_isAdmin,
+            is_self: var_isSelf,
+            added_at_epoch: var_addedAtEpoch,
+            added_by: var_addedBy,
+            leaf_index: var_leafIndex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupPermissions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_adminsOnlyPost = <bool>::sse_decode(deserializer);
+        let mut var_adminsOnlyAddMembers = <bool>::sse_decode(deserializer);
+        let mut var_slowModeSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::groups::GroupPermissions {
+            admins_only_post: var_adminsOnlyPost,
+            admins_only_add_members: var_adminsOnlyAddMembers,
+            slow_mode_secs: var_slowModeSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupRelayAlert {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_groupId = <String>::sse_decode(deserializer);
+        let mut var_groupName = <String>::sse_decode(deserializer);
+        let mut var_allUnhealthySince = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::groups::GroupRelayAlert {
+            group_id: var_groupId,
+            group_name: var_groupName,
+            all_unhealthy_since: var_allUnhealthySince,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupRelayHealth {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_relays = <Vec<crate::api::relays::RelayHealth>>::sse_decode(deserializer);
+        let mut var_allUnhealthySince =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        return crate::api::groups::GroupRelayHealth {
+            relays: var_relays,
+            all_unhealthy_since: var_allUnhealthySince,
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::groups::GroupState::Active,
+            1 => crate::api::groups::GroupState::Inactive,
+            2 => crate::api::groups::GroupState::Pending,
+            _ => unreachable!("Invalid variant for GroupState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::groups::GroupType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::groups::GroupType::DirectMessage,
+            1 => crate::api::groups::GroupType::Group,
+            _ => unreachable!("Invalid variant for GroupType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::relays::InvalidRelayUrlReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::relays::InvalidRelayUrlReason::Malformed,
+            1 => crate::api::relays::InvalidRelayUrlReason::BadScheme,
+            2 => crate::api::relays::InvalidRelayUrlReason::PrivateAddress,
+            3 => crate::api::relays::InvalidRelayUrlReason::OnionWithoutProxy,
+            4 => crate::api::relays::InvalidRelayUrlReason::Duplicate,
+            _ => unreachable!("Invalid variant for InvalidRelayUrlReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::invites::InviteLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <String>::sse_decode(deserializer);
+        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
+        let mut var_expiresAt = <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        let mut var_maxUses = <Option<u32>>::sse_decode(deserializer);
+        let mut var_uses = <u32>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::invites::InviteLink {
+            token: var_token,
+            mls_group_id: var_mlsGroupId,
+            expires_at: var_expiresAt,
+            max_uses: var_maxUses,
+            uses: var_uses,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invites::JoinRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
+        let mut var_requesterPubkey = <String>::sse_decode(deserializer);
+        let mut var_requesterMetadata =
+            <crate::api::metadata::FlutterMetadata>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::invites::JoinRequest {
+            id: var_id,
+            mls_group_id: var_mlsGroupId,
+            requester_pubkey: var_requesterPubkey,
+            requester_metadata: var_requesterMetadata,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for Vec<Tag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Tag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::accounts::Account> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::accounts::Account>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messages::ChatMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messages::ChatMessage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messages::EmojiReaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messages::EmojiReaction>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::accounts::FlutterEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::accounts::FlutterEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::groups::Group> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::groups::Group>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::groups::GroupInformation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::groups::GroupInformation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::groups::GroupMember> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::groups::GroupMember>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::invites::JoinRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::invites::JoinRequest>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media_files::MediaFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media_files::MediaFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::metadata::MetadataValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metadata::MetadataValue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, crate::api::metadata::MetadataValue)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, crate::api::metadata::MetadataValue)>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::relays::Relay> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::relays::Relay>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::relays::RelayConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::relays::RelayConnectionInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::relays::RelayHealth> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::relays::RelayHealth>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::relays::RelayWarning> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::relays::RelayWarning>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messages::SerializableToken> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messages::SerializableToken>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::users::User> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::users::User>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messages::UserReaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messages::UserReaction>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::users::UserSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::users::UserSearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::welcomes::Welcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::welcomes::Welcome>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::welcomes::WelcomeActionResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::welcomes::WelcomeActionResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::media_files::MediaFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
+        let mut var_accountPubkey = <String>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_originalFileHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_encryptedFileHash = <String>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_mediaType = <String>::sse_decode(deserializer);
+        let mut var_blossomUrl = <String>::sse_decode(deserializer);
+        let mut var_nostrKey = <String>::sse_decode(deserializer);
+        let mut var_fileMetadata =
+            <Option<crate::api::media_files::FileMetadata>>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::media_files::MediaFile {
+            id: var_id,
+            mls_group_id: var_mlsGroupId,
+            account_pubkey: var_accountPubkey,
+            file_path: var_filePath,
+            original_file_hash: var_originalFileHash,
+            encrypted_file_hash: var_encryptedFileHash,
+            mime_type: var_mimeType,
+            media_type: var_mediaType,
+            blossom_url: var_blossomUrl,
+            nostr_key: var_nostrKey,
+            file_metadata: var_fileMetadata,
+            created_at: var_createdAt,
+        };
     }
 }
 
-impl SseDecode for RelayUrl {
+impl SseDecode for crate::api::verification::MemberFingerprint {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_memberPubkey = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_safetyNumber = <String>::sse_decode(deserializer);
+        let mut var_qrPayload = <String>::sse_decode(deserializer);
+        let mut var_verificationState =
+            <crate::api::verification::VerificationState>::sse_decode(deserializer);
+        let mut var_verifiedAt = <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        return crate::api::verification::MemberFingerprint {
+            member_pubkey: var_memberPubkey,
+            fingerprint: var_fingerprint,
+            safety_number: var_safetyNumber,
+            qr_payload: var_qrPayload,
+            verification_state: var_verificationState,
+            verified_at: var_verifiedAt,
+        };
     }
 }
 
-impl SseDecode for Tag {
+impl SseDecode for crate::api::messages::MessageWithTokens {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_content = <Option<String>>::sse_decode(deserializer);
+        let mut var_tokens =
+            <Vec<crate::api::messages::SerializableToken>>::sse_decode(deserializer);
+        return crate::api::messages::MessageWithTokens {
+            id: var_id,
+            pubkey: var_pubkey,
+            kind: var_kind,
+            created_at: var_createdAt,
+            content: var_content,
+            tokens: var_tokens,
+        };
     }
 }
 
-impl SseDecode for ThemeMode {
+impl SseDecode for crate::api::metadata::MetadataValue {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::metadata::MetadataValue::Null;
+            }
+            1 => {
+                let mut var_field0 = <bool>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::Bool(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::Integer(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::UnsignedInteger(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::Float(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::String(var_field0);
+            }
+            6 => {
+                let mut var_field0 =
+                    <Vec<crate::api::metadata::MetadataValue>>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::Array(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <std::collections::HashMap<
+                    String,
+                    crate::api::metadata::MetadataValue,
+                >>::sse_decode(deserializer);
+                return crate::api::metadata::MetadataValue::Object(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for chrono::DateTime<chrono::Utc> {
+impl SseDecode for crate::api::users::Nip05Status {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i64>::sse_decode(deserializer);
-        return chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-            chrono::DateTime::from_timestamp_micros(inner)
-                .expect("invalid or out-of-range datetime")
-                .naive_utc(),
-            chrono::Utc,
-        );
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::users::Nip05Status::Unverified,
+            1 => crate::api::users::Nip05Status::Verified,
+            2 => crate::api::users::Nip05Status::Mismatch,
+            3 => crate::api::users::Nip05Status::Unreachable,
+            _ => unreachable!("Invalid variant for Nip05Status: {}", inner),
+        };
     }
 }
 
-impl SseDecode for std::collections::HashMap<String, String> {
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AppSettings>>
-{
+impl SseDecode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<chrono::DateTime<chrono::Utc>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GroupId>> {
+impl SseDecode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayType>>
-{
+impl SseDecode for Option<crate::api::media_files::FileMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media_files::FileMetadata>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RelayUrl>>
-{
+impl SseDecode for Option<crate::api::accounts::FlutterEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::accounts::FlutterEvent>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Tag>> {
+impl SseDecode for Option<crate::api::groups::GroupPermissions> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::groups::GroupPermissions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ThemeMode>>
-{
+impl SseDecode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for Option<crate::api::proxy::ProxySettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::proxy::ProxySettings>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::accounts::Account {
+impl SseDecode for Option<crate::api::relays::RelayInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        let mut var_lastSyncedAt =
-            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::accounts::Account {
-            pubkey: var_pubkey,
-            last_synced_at: var_lastSyncedAt,
-            created_at: var_createdAt,
-            updated_at: var_updatedAt,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::relays::RelayInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::messages::SystemEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::messages::SystemEvent>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::welcomes::WelcomeState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::welcomes::WelcomeState>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<Tag>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<Tag>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::error::ApiError {
+impl SseDecode for Option<[u8; 12]> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::Whitenoise {
-                    message: var_message,
-                };
-            }
-            1 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::InvalidKey {
-                    message: var_message,
-                };
-            }
-            2 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::NostrUrl {
-                    message: var_message,
-                };
-            }
-            3 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::NostrTag {
-                    message: var_message,
-                };
-            }
-            4 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::NostrEvent {
-                    message: var_message,
-                };
-            }
-            5 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::NostrParse {
-                    message: var_message,
-                };
-            }
-            6 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::NostrHex {
-                    message: var_message,
-                };
-            }
-            7 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::error::ApiError::Other {
-                    message: var_message,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<[u8; 12]>::sse_decode(deserializer));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for Option<[u8; 32]> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<[u8; 32]>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::messages::ChatMessage {
+impl SseDecode for crate::api::proxy::ProxySettings {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_isReply = <bool>::sse_decode(deserializer);
-        let mut var_replyToId = <Option<String>>::sse_decode(deserializer);
-        let mut var_isDeleted = <bool>::sse_decode(deserializer);
-        let mut var_contentTokens =
-            <Vec<crate::api::messages::SerializableToken>>::sse_decode(deserializer);
-        let mut var_reactions = <crate::api::messages::ReactionSummary>::sse_decode(deserializer);
-        let mut var_mediaAttachments =
-            <Vec<crate::api::media_files::MediaFile>>::sse_decode(deserializer);
-        let mut var_kind = <u16>::sse_decode(deserializer);
-        return crate::api::messages::ChatMessage {
-            id: var_id,
-            pubkey: var_pubkey,
-            content: var_content,
-            created_at: var_createdAt,
-            tags: var_tags,
-            is_reply: var_isReply,
-            reply_to_id: var_replyToId,
-            is_deleted: var_isDeleted,
-            content_tokens: var_contentTokens,
-            reactions: var_reactions,
-            media_attachments: var_mediaAttachments,
-            kind: var_kind,
+        let mut var_proxyUrl = <String>::sse_decode(deserializer);
+        let mut var_bypassRelays = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::proxy::ProxySettings {
+            proxy_url: var_proxyUrl,
+            bypass_relays: var_bypassRelays,
         };
     }
 }
 
-impl SseDecode for crate::api::messages::EmojiReaction {
+impl SseDecode for crate::api::messages::ReactionSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_emoji = <String>::sse_decode(deserializer);
-        let mut var_count = <u64>::sse_decode(deserializer);
-        let mut var_users = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::messages::EmojiReaction {
-            emoji: var_emoji,
-            count: var_count,
-            users: var_users,
+        let mut var_byEmoji = <Vec<crate::api::messages::EmojiReaction>>::sse_decode(deserializer);
+        let mut var_userReactions =
+            <Vec<crate::api::messages::UserReaction>>::sse_decode(deserializer);
+        return crate::api::messages::ReactionSummary {
+            by_emoji: var_byEmoji,
+            user_reactions: var_userReactions,
         };
     }
 }
 
-impl SseDecode for crate::api::media_files::FileMetadata {
+impl SseDecode for (String, crate::api::metadata::MetadataValue) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_originalFilename = <Option<String>>::sse_decode(deserializer);
-        let mut var_dimensions = <Option<String>>::sse_decode(deserializer);
-        let mut var_blurhash = <Option<String>>::sse_decode(deserializer);
-        return crate::api::media_files::FileMetadata {
-            original_filename: var_originalFilename,
-            dimensions: var_dimensions,
-            blurhash: var_blurhash,
-        };
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <crate::api::metadata::MetadataValue>::sse_decode(deserializer);
+        return (var_field0, var_field1);
     }
 }
 
-impl SseDecode for crate::api::accounts::FlutterEvent {
+impl SseDecode for crate::api::relays::Relay {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
         let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_kind = <u16>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        return crate::api::accounts::FlutterEvent {
-            id: var_id,
-            pubkey: var_pubkey,
+        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_info = <Option<crate::api::relays::RelayInfo>>::sse_decode(deserializer);
+        return crate::api::relays::Relay {
+            url: var_url,
             created_at: var_createdAt,
-            kind: var_kind,
-            tags: var_tags,
-            content: var_content,
+            updated_at: var_updatedAt,
+            info: var_info,
         };
     }
 }
 
-impl SseDecode for crate::api::groups::FlutterGroupDataUpdate {
+impl SseDecode for crate::api::relays::RelayAuthPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        let mut var_relays = <Option<Vec<String>>>::sse_decode(deserializer);
-        let mut var_admins = <Option<Vec<String>>>::sse_decode(deserializer);
-        let mut var_imageKey = <Option<[u8; 32]>>::sse_decode(deserializer);
-        let mut var_imageHash = <Option<[u8; 32]>>::sse_decode(deserializer);
-        let mut var_imageNonce = <Option<[u8; 12]>>::sse_decode(deserializer);
-        return crate::api::groups::FlutterGroupDataUpdate {
-            name: var_name,
-            description: var_description,
-            relays: var_relays,
-            admins: var_admins,
-            image_key: var_imageKey,
-            image_hash: var_imageHash,
-            image_nonce: var_imageNonce,
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::relays::RelayAuthPolicy::Always,
+            1 => crate::api::relays::RelayAuthPolicy::Ask,
+            2 => crate::api::relays::RelayAuthPolicy::Never,
+            _ => unreachable!("Invalid variant for RelayAuthPolicy: {}", inner),
         };
     }
 }
 
-impl SseDecode for crate::api::metadata::FlutterMetadata {
+impl SseDecode for crate::api::relays::RelayAuthState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
-        let mut var_about = <Option<String>>::sse_decode(deserializer);
-        let mut var_picture = <Option<String>>::sse_decode(deserializer);
-        let mut var_banner = <Option<String>>::sse_decode(deserializer);
-        let mut var_website = <Option<String>>::sse_decode(deserializer);
-        let mut var_nip05 = <Option<String>>::sse_decode(deserializer);
-        let mut var_lud06 = <Option<String>>::sse_decode(deserializer);
-        let mut var_lud16 = <Option<String>>::sse_decode(deserializer);
-        let mut var_custom = <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        return crate::api::metadata::FlutterMetadata {
-            name: var_name,
-            display_name: var_displayName,
-            about: var_about,
-            picture: var_picture,
-            banner: var_banner,
-            website: var_website,
-            nip05: var_nip05,
-            lud06: var_lud06,
-            lud16: var_lud16,
-            custom: var_custom,
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::relays::RelayAuthState::NotRequired,
+            1 => crate::api::relays::RelayAuthState::Required,
+            2 => crate::api::relays::RelayAuthState::Authenticated,
+            3 => crate::api::relays::RelayAuthState::Failed,
+            _ => unreachable!("Invalid variant for RelayAuthState: {}", inner),
         };
     }
 }
 
-impl SseDecode for crate::api::groups::Group {
+impl SseDecode for crate::api::relays::RelayConnectionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
-        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_description = <String>::sse_decode(deserializer);
-        let mut var_imageHash = <Option<[u8; 32]>>::sse_decode(deserializer);
-        let mut var_imageKey = <Option<[u8; 32]>>::sse_decode(deserializer);
-        let mut var_adminPubkeys = <Vec<String>>::sse_decode(deserializer);
-        let mut var_lastMessageId = <Option<String>>::sse_decode(deserializer);
-        let mut var_lastMessageAt =
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_status = <crate::api::relays::RelayStatus>::sse_decode(deserializer);
+        let mut var_lastConnectedAt =
             <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
-        let mut var_epoch = <u64>::sse_decode(deserializer);
-        let mut var_state = <crate::api::groups::GroupState>::sse_decode(deserializer);
-        return crate::api::groups::Group {
-            mls_group_id: var_mlsGroupId,
-            nostr_group_id: var_nostrGroupId,
-            name: var_name,
-            description: var_description,
-            image_hash: var_imageHash,
-            image_key: var_imageKey,
-            admin_pubkeys: var_adminPubkeys,
-            last_message_id: var_lastMessageId,
-            last_message_at: var_lastMessageAt,
-            epoch: var_epoch,
-            state: var_state,
+        let mut var_failedAttempts = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_latencyMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_authState = <crate::api::relays::RelayAuthState>::sse_decode(deserializer);
+        return crate::api::relays::RelayConnectionInfo {
+            url: var_url,
+            status: var_status,
+            last_connected_at: var_lastConnectedAt,
+            failed_attempts: var_failedAttempts,
+            last_error: var_lastError,
+            latency_ms: var_latencyMs,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            auth_state: var_authState,
         };
     }
 }
 
-impl SseDecode for crate::api::groups::GroupInformation {
+impl SseDecode for crate::api::relays::RelayHealth {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
-        let mut var_groupType = <crate::api::groups::GroupType>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::groups::GroupInformation {
-            mls_group_id: var_mlsGroupId,
-            group_type: var_groupType,
-            created_at: var_createdAt,
-            updated_at: var_updatedAt,
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_successRate = <f64>::sse_decode(deserializer);
+        let mut var_latencyMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isHealthy = <bool>::sse_decode(deserializer);
+        let mut var_unhealthySince =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        return crate::api::relays::RelayHealth {
+            url: var_url,
+            score: var_score,
+            success_rate: var_successRate,
+            latency_ms: var_latencyMs,
+            is_healthy: var_isHealthy,
+            unhealthy_since: var_unhealthySince,
         };
     }
 }
 
-impl SseDecode for crate::api::groups::GroupState {
+impl SseDecode for crate::api::relays::RelayInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::groups::GroupState::Active,
-            1 => crate::api::groups::GroupState::Inactive,
-            2 => crate::api::groups::GroupState::Pending,
-            _ => unreachable!("Invalid variant for GroupState: {}", inner),
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_software = <Option<String>>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_supportedNips = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_maxMessageLength = <Option<i64>>::sse_decode(deserializer);
+        let mut var_authRequired = <bool>::sse_decode(deserializer);
+        let mut var_paymentRequired = <bool>::sse_decode(deserializer);
+        let mut var_restrictedWrites = <bool>::sse_decode(deserializer);
+        let mut var_icon = <Option<String>>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<crate::api::relays::RelayWarning>>::sse_decode(deserializer);
+        let mut var_fetchedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::relays::RelayInfo {
+            url: var_url,
+            name: var_name,
+            description: var_description,
+            software: var_software,
+            version: var_version,
+            supported_nips: var_supportedNips,
+            max_message_length: var_maxMessageLength,
+            auth_required: var_authRequired,
+            payment_required: var_paymentRequired,
+            restricted_writes: var_restrictedWrites,
+            icon: var_icon,
+            warnings: var_warnings,
+            fetched_at: var_fetchedAt,
         };
     }
 }
 
-impl SseDecode for crate::api::groups::GroupType {
+impl SseDecode for crate::api::accounts::RelayListChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removed = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::accounts::RelayListChange {
+            added: var_added,
+            removed: var_removed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::relays::RelayStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
-            0 => crate::api::groups::GroupType::DirectMessage,
-            1 => crate::api::groups::GroupType::Group,
-            _ => unreachable!("Invalid variant for GroupType: {}", inner),
+            0 => crate::api::relays::RelayStatus::Initialized,
+            1 => crate::api::relays::RelayStatus::Pending,
+            2 => crate::api::relays::RelayStatus::Connecting,
+            3 => crate::api::relays::RelayStatus::Connected,
+            4 => crate::api::relays::RelayStatus::Disconnected,
+            5 => crate::api::relays::RelayStatus::Terminated,
+            6 => crate::api::relays::RelayStatus::Banned,
+            7 => crate::api::relays::RelayStatus::Sleeping,
+            _ => unreachable!("Invalid variant for RelayStatus: {}", inner),
         };
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for crate::api::relays::RelayWarning {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::relays::RelayWarning::AuthRequired,
+            1 => crate::api::relays::RelayWarning::PaymentRequired,
+            2 => crate::api::relays::RelayWarning::RestrictedWrites,
+            3 => crate::api::relays::RelayWarning::MessageLengthTooSmall,
+            _ => unreachable!("Invalid variant for RelayWarning: {}", inner),
+        };
     }
 }
 
-impl SseDecode for i64 {
+impl SseDecode for crate::api::messages::SerializableToken {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+        let mut var_tokenType = <String>::sse_decode(deserializer);
+        let mut var_content = <Option<String>>::sse_decode(deserializer);
+        return crate::api::messages::SerializableToken {
+            token_type: var_tokenType,
+            content: var_content,
+        };
     }
 }
 
-impl SseDecode for Vec<Tag> {
+impl SseDecode for crate::api::users::SyncPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Tag>::sse_decode(deserializer));
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::users::SyncPolicy::CacheOnly;
+            }
+            1 => {
+                let mut var_maxAgeSecs = <u64>::sse_decode(deserializer);
+                return crate::api::users::SyncPolicy::StaleWhileRevalidate {
+                    max_age_secs: var_maxAgeSecs,
+                };
+            }
+            2 => {
+                return crate::api::users::SyncPolicy::ForceRefresh;
+            }
+            3 => {
+                let mut var_timeoutMs = <u64>::sse_decode(deserializer);
+                return crate::api::users::SyncPolicy::RefreshWithTimeout {
+                    timeout_ms: var_timeoutMs,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for crate::api::messages::SystemEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_changedBy = <String>::sse_decode(deserializer);
+                let mut var_durationSecs = <Option<u64>>::sse_decode(deserializer);
+                return crate::api::messages::SystemEvent::DisappearingMessagesChanged {
+                    changed_by: var_changedBy,
+                    duration_secs: var_durationSecs,
+                };
+            }
+            1 => {
+                let mut var_removedBy = <String>::sse_decode(deserializer);
+                let mut var_members = <Vec<String>>::sse_decode(deserializer);
+                let mut var_reason = <Option<String>>::sse_decode(deserializer);
+                return crate::api::messages::SystemEvent::MembersRemoved {
+                    removed_by: var_removedBy,
+                    members: var_members,
+                    reason: var_reason,
+                };
+            }
+            2 => {
+                let mut var_changedBy = <String>::sse_decode(deserializer);
+                let mut var_permissions =
+                    <crate::api::groups::GroupPermissions>::sse_decode(deserializer);
+                return crate::api::messages::SystemEvent::PermissionsChanged {
+                    changed_by: var_changedBy,
+                    permissions: var_permissions,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::accounts::Account> {
+impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::accounts::Account>::sse_decode(deserializer));
-        }
-        return ans_;
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::ChatMessage> {
+impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::ChatMessage>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::EmojiReaction> {
+impl SseDecode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::EmojiReaction>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::accounts::FlutterEvent> {
+impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::accounts::FlutterEvent>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        deserializer.cursor.read_u8().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::groups::Group> {
+impl SseDecode for [u8; 12] {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::groups::Group>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
     }
 }
 
-impl SseDecode for Vec<crate::api::groups::GroupInformation> {
+impl SseDecode for [u8; 32] {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::groups::GroupInformation>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
     }
 }
 
-impl SseDecode for Vec<crate::api::media_files::MediaFile> {
+impl SseDecode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::media_files::MediaFile>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for crate::api::groups::UploadGroupImageResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_encryptedHash = <[u8; 32]>::sse_decode(deserializer);
+        let mut var_imageKey = <[u8; 32]>::sse_decode(deserializer);
+        let mut var_imageNonce = <[u8; 12]>::sse_decode(deserializer);
+        return crate::api::groups::UploadGroupImageResult {
+            encrypted_hash: var_encryptedHash,
+            image_key: var_imageKey,
+            image_nonce: var_imageNonce,
+        };
     }
 }
 
-impl SseDecode for Vec<(String, String)> {
+impl SseDecode for crate::api::users::User {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_metadata = <crate::api::metadata::FlutterMetadata>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        let mut var_lastFetchedAt =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        let mut var_fetchedFromRelays = <Vec<String>>::sse_decode(deserializer);
+        let mut var_nip05Status = <crate::api::users::Nip05Status>::sse_decode(deserializer);
+        let mut var_nip05CheckedAt =
+            <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
+        return crate::api::users::User {
+            pubkey: var_pubkey,
+            metadata: var_metadata,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+            last_fetched_at: var_lastFetchedAt,
+            fetched_from_relays: var_fetchedFromRelays,
+            nip05_status: var_nip05Status,
+            nip05_checked_at: var_nip05CheckedAt,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::relays::Relay> {
+impl SseDecode for crate::api::messages::UserReaction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::relays::Relay>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_user = <String>::sse_decode(deserializer);
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
+        return crate::api::messages::UserReaction {
+            user: var_user,
+            emoji: var_emoji,
+            created_at: var_createdAt,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::SerializableToken> {
+impl SseDecode for crate::api::users::UserSearchMatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::SerializableToken>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::users::UserSearchMatch::Pubkey,
+            1 => crate::api::users::UserSearchMatch::Nip05,
+            2 => crate::api::users::UserSearchMatch::Prefix,
+            3 => crate::api::users::UserSearchMatch::Substring,
+            4 => crate::api::users::UserSearchMatch::Fuzzy,
+            5 => crate::api::users::UserSearchMatch::Relay,
+            _ => unreachable!("Invalid variant for UserSearchMatch: {}", inner),
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::users::User> {
+impl SseDecode for crate::api::users::UserSearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::users::User>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_user = <crate::api::users::User>::sse_decode(deserializer);
+        let mut var_matchedBy = <crate::api::users::UserSearchMatch>::sse_decode(deserializer);
+        let mut var_isFollow = <bool>::sse_decode(deserializer);
+        return crate::api::users::UserSearchResult {
+            user: var_user,
+            matched_by: var_matchedBy,
+            is_follow: var_isFollow,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::messages::UserReaction> {
+impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::messages::UserReaction>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
     }
 }
 
-impl SseDecode for Vec<crate::api::welcomes::Welcome> {
+impl SseDecode for crate::api::verification::VerificationState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::welcomes::Welcome>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::verification::VerificationState::Unverified,
+            1 => crate::api::verification::VerificationState::Verified,
+            2 => crate::api::verification::VerificationState::KeyChanged,
+            _ => unreachable!("Invalid variant for VerificationState: {}", inner),
+        };
     }
 }
 
-impl SseDecode for crate::api::media_files::MediaFile {
+impl SseDecode for crate::api::welcomes::Welcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_id = <String>::sse_decode(deserializer);
         let mut var_mlsGroupId = <String>::sse_decode(deserializer);
-        let mut var_accountPubkey = <String>::sse_decode(deserializer);
-        let mut var_filePath = <String>::sse_decode(deserializer);
-        let mut var_originalFileHash = <Option<String>>::sse_decode(deserializer);
-        let mut var_encryptedFileHash = <String>::sse_decode(deserializer);
-        let mut var_mimeType = <String>::sse_decode(deserializer);
-        let mut var_mediaType = <String>::sse_decode(deserializer);
-        let mut var_blossomUrl = <String>::sse_decode(deserializer);
-        let mut var_nostrKey = <String>::sse_decode(deserializer);
-        let mut var_fileMetadata =
-            <Option<crate::api::media_files::FileMetadata>>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::media_files::MediaFile {
+        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
+        let mut var_groupName = <String>::sse_decode(deserializer);
+        let mut var_groupDescription = <String>::sse_decode(deserializer);
+        let mut var_groupAdminPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_groupRelays = <Vec<String>>::sse_decode(deserializer);
+        let mut var_welcomer = <String>::sse_decode(deserializer);
+        let mut var_memberCount = <u32>::sse_decode(deserializer);
+        let mut var_state = <crate::api::welcomes::WelcomeState>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        return crate::api::welcomes::Welcome {
             id: var_id,
             mls_group_id: var_mlsGroupId,
-            account_pubkey: var_accountPubkey,
-            file_path: var_filePath,
-            original_file_hash: var_originalFileHash,
-            encrypted_file_hash: var_encryptedFileHash,
-            mime_type: var_mimeType,
-            media_type: var_mediaType,
-            blossom_url: var_blossomUrl,
-            nostr_key: var_nostrKey,
-            file_metadata: var_fileMetadata,
+            nostr_group_id: var_nostrGroupId,
+            group_name: var_groupName,
+            group_description: var_groupDescription,
+            group_admin_pubkeys: var_groupAdminPubkeys,
+            group_relays: var_groupRelays,
+            welcomer: var_welcomer,
+            member_count: var_memberCount,
+            state: var_state,
             created_at: var_createdAt,
         };
     }
 }
 
-impl SseDecode for crate::api::messages::MessageWithTokens {
+impl SseDecode for crate::api::welcomes::WelcomeActionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_welcomeEventId = <String>::sse_decode(deserializer);
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        return crate::api::welcomes::WelcomeActionResult {
+            welcome_event_id: var_welcomeEventId,
+            success: var_success,
+            error_message: var_errorMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomePolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        let mut var_kind = <u16>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_content = <Option<String>>::sse_decode(deserializer);
-        let mut var_tokens =
-            <Vec<crate::api::messages::SerializableToken>>::sse_decode(deserializer);
-        return crate::api::messages::MessageWithTokens {
-            id: var_id,
-            pubkey: var_pubkey,
-            kind: var_kind,
-            created_at: var_createdAt,
-            content: var_content,
-            tokens: var_tokens,
+        let mut var_senderPolicy =
+            <crate::api::welcomes::WelcomeSenderPolicy>::sse_decode(deserializer);
+        let mut var_maxMemberCount = <Option<u32>>::sse_decode(deserializer);
+        let mut var_autoAcceptFromFollows = <bool>::sse_decode(deserializer);
+        return crate::api::welcomes::WelcomePolicy {
+            sender_policy: var_senderPolicy,
+            max_member_count: var_maxMemberCount,
+            auto_accept_from_follows: var_autoAcceptFromFollows,
         };
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseDecode for crate::api::welcomes::WelcomePreview {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        let mut var_welcome = <crate::api::welcomes::Welcome>::sse_decode(deserializer);
+        let mut var_welcomerMetadata =
+            <crate::api::metadata::FlutterMetadata>::sse_decode(deserializer);
+        let mut var_isFollowingWelcomer = <bool>::sse_decode(deserializer);
+        let mut var_groupImagePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_memberPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_groupType = <crate::api::groups::GroupType>::sse_decode(deserializer);
+        return crate::api::welcomes::WelcomePreview {
+            welcome: var_welcome,
+            welcomer_metadata: var_welcomerMetadata,
+            is_following_welcomer: var_isFollowingWelcomer,
+            group_image_path: var_groupImagePath,
+            member_pubkeys: var_memberPubkeys,
+            group_type: var_groupType,
+        };
     }
 }
 
-impl SseDecode for Option<chrono::DateTime<chrono::Utc>> {
+impl SseDecode for crate::api::welcomes::WelcomeSenderPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::DateTime<chrono::Utc>>::sse_decode(deserializer));
-        } else {
-            return None;
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::welcomes::WelcomeSenderPolicy::Anyone,
+            1 => crate::api::welcomes::WelcomeSenderPolicy::Follows,
+            2 => crate::api::welcomes::WelcomeSenderPolicy::FollowsOfFollows,
+            _ => unreachable!("Invalid variant for WelcomeSenderPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomeState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::welcomes::WelcomeState::Pending,
+            1 => crate::api::welcomes::WelcomeState::Accepted,
+            2 => crate::api::welcomes::WelcomeState::Declined,
+            3 => crate::api::welcomes::WelcomeState::Ignored,
+            _ => unreachable!("Invalid variant for WelcomeState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::WhitenoiseConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dataDir = <String>::sse_decode(deserializer);
+        let mut var_logsDir = <String>::sse_decode(deserializer);
+        let mut var_proxyUrl = <Option<String>>::sse_decode(deserializer);
+        return crate::api::WhitenoiseConfig {
+            data_dir: var_dataDir,
+            logs_dir: var_logsDir,
+            proxy_url: var_proxyUrl,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__welcomes__accept_welcome_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__welcomes__accept_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__accounts__account_follows_impl(port, ptr, rust_vec_len, data_len),
+        4 => {
+            wire__crate__api__accounts__account_key_package_impl(port, ptr, rust_vec_len, data_len)
+        }
+        5 => {
+            wire__crate__api__accounts__account_key_packages_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => wire__crate__api__accounts__account_metadata_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__relays__account_relay_health_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__accounts__account_relays_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__groups__active_groups_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__accounts__add_account_relay_impl(port, ptr, rust_vec_len, data_len),
+        11 => {
+            wire__crate__api__groups__add_members_to_group_impl(port, ptr, rust_vec_len, data_len)
+        }
+        12 => wire__crate__api__welcomes__all_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__error__api_error_error_type_impl(port, ptr, rust_vec_len, data_len),
+        14 => {
+            wire__crate__api__error__api_error_message_text_impl(port, ptr, rust_vec_len, data_len)
+        }
+        15 => {
+            wire__crate__api__invites__approve_join_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => wire__crate__api__welcomes__block_welcomer_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__welcomes__blocked_welcomers_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__groups__create_group_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__accounts__create_identity_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__invites__create_invite_link_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__create_whitenoise_config_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__invites__decline_join_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__welcomes__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__welcomes__decline_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__accounts__delete_account_key_package_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__accounts__delete_account_key_packages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__delete_all_data_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__media_files__download_chat_media_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__relays__ensure_all_subscriptions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => {
+            wire__crate__api__accounts__export_account_nsec_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__groups__find_or_create_dm_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__welcomes__find_welcome_by_event_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__accounts__follow_user_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__accounts__get_account_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__relays__get_account_relay_statuses_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__accounts__get_accounts_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__get_app_settings_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__utils__get_default_blossom_server_url_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__groups__get_group_impl(port, ptr, rust_vec_len, data_len),
+        41 => {
+            wire__crate__api__groups__get_group_image_path_impl(port, ptr, rust_vec_len, data_len)
         }
+        42 => {
+            wire__crate__api__groups__get_group_information_impl(port, ptr, rust_vec_len, data_len)
+        }
+        43 => wire__crate__api__groups__get_groups_informations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__proxy__get_proxy_settings_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__users__get_user_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__users__get_users_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__get_welcome_policy_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__groups__group_admins_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__groups__group_group_type_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__utils__group_id_from_string_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__utils__group_id_to_string_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__groups__group_is_direct_message_type_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__groups__group_is_group_type_impl(port, ptr, rust_vec_len, data_len),
+        55 => {
+            wire__crate__api__groups__group_member_details_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => wire__crate__api__groups__group_members_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__groups__group_permissions_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__groups__group_relay_health_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__groups__group_update_group_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__welcomes__ignore_welcome_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__initialize_whitenoise_impl(port, ptr, rust_vec_len, data_len),
+        63 => {
+            wire__crate__api__network__is_low_bandwidth_mode_impl(port, ptr, rust_vec_len, data_len)
+        }
+        64 => wire__crate__api__network__is_network_paused_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__accounts__login_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__accounts__logout_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__verification__member_fingerprint_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__api__messages__moderate_delete_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__network__pause_network_impl(port, ptr, rust_vec_len, data_len),
+        71 => {
+            wire__crate__api__invites__pending_join_requests_impl(port, ptr, rust_vec_len, data_len)
+        }
+        72 => wire__crate__api__relays__pending_relay_auth_requests_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__welcomes__pending_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__accounts__publish_account_key_package_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => wire__crate__api__messages__purge_expired_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__relays__relay_auth_policy_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__relays__relay_health_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__relays__relay_info_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__relays__relay_type_inbox_impl(port, ptr, rust_vec_len, data_len),
+        80 => {
+            wire__crate__api__relays__relay_type_key_package_impl(port, ptr, rust_vec_len, data_len)
+        }
+        81 => wire__crate__api__relays__relay_type_nip65_impl(port, ptr, rust_vec_len, data_len),
+        82 => {
+            wire__crate__api__utils__relay_url_from_string_impl(port, ptr, rust_vec_len, data_len)
+        }
+        83 => {
+            wire__crate__api__accounts__remove_account_relay_impl(port, ptr, rust_vec_len, data_len)
+        }
+        84 => wire__crate__api__groups__remove_members_from_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        85 => wire__crate__api__invites__request_join_impl(port, ptr, rust_vec_len, data_len),
+        86 => {
+            wire__crate__api__relays__respond_to_relay_auth_impl(port, ptr, rust_vec_len, data_len)
+        }
+        87 => wire__crate__api__network__resume_network_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__users__search_users_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__messages__send_message_to_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => {
+            wire__crate__api__accounts__set_account_relays_impl(port, ptr, rust_vec_len, data_len)
+        }
+        91 => wire__crate__api__verification__set_member_verified_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => {
+            wire__crate__api__relays__set_relay_auth_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        93 => {
+            wire__crate__api__utils__string_from_relay_url_impl(port, ptr, rust_vec_len, data_len)
+        }
+        94 => wire__crate__api__utils__tag_from_vec_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__welcomes__unblock_welcomer_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__accounts__unfollow_user_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__accounts__update_account_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__api__network__update_low_bandwidth_mode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => {
+            wire__crate__api__proxy__update_proxy_settings_impl(port, ptr, rust_vec_len, data_len)
+        }
+        100 => wire__crate__api__update_theme_mode_impl(port, ptr, rust_vec_len, data_len),
+        101 => wire__crate__api__update_welcome_policy_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__accounts__upload_account_profile_picture_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        103 => {
+            wire__crate__api__media_files__upload_chat_media_impl(port, ptr, rust_vec_len, data_len)
+        }
+        104 => wire__crate__api__groups__upload_group_image_impl(port, ptr, rust_vec_len, data_len),
+        105 => {
+            wire__crate__api__users__user_has_key_package_impl(port, ptr, rust_vec_len, data_len)
+        }
+        106 => wire__crate__api__users__user_metadata_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__users__user_relays_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__relays__validate_relay_url_impl(port, ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__verification__verify_member_qr_payload_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__users__verify_user_nip05_impl(port, ptr, rust_vec_len, data_len),
+        111 => wire__crate__api__groups__watch_group_relay_alerts_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__api__users__watch_users_impl(port, ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__welcomes__welcome_preview_impl(port, ptr, rust_vec_len, data_len),
+        114 => wire__crate__api__welcomes__welcome_requests_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        52 => wire__crate__api__groups__group_is_admin_impl(ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__utils__hex_pubkey_from_npub_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__utils__npub_from_hex_pubkey_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AppSettings> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AppSettings> {}
 
-impl SseDecode for Option<crate::api::media_files::FileMetadata> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::media_files::FileMetadata>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AppSettings>> for AppSettings {
+    fn into_into_dart(self) -> FrbWrapper<AppSettings> {
+        self.into()
     }
 }
 
-impl SseDecode for Option<crate::api::accounts::FlutterEvent> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::accounts::FlutterEvent>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GroupId> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GroupId> {}
 
-impl SseDecode for Option<Vec<Tag>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<Tag>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GroupId>> for GroupId {
+    fn into_into_dart(self) -> FrbWrapper<GroupId> {
+        self.into()
     }
 }
 
-impl SseDecode for Option<Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<String>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayType> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayType> {}
 
-impl SseDecode for Option<[u8; 12]> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<[u8; 12]>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayType>> for RelayType {
+    fn into_into_dart(self) -> FrbWrapper<RelayType> {
+        self.into()
     }
 }
 
-impl SseDecode for Option<[u8; 32]> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<[u8; 32]>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayUrl> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayUrl> {}
 
-impl SseDecode for crate::api::messages::ReactionSummary {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_byEmoji = <Vec<crate::api::messages::EmojiReaction>>::sse_decode(deserializer);
-        let mut var_userReactions =
-            <Vec<crate::api::messages::UserReaction>>::sse_decode(deserializer);
-        return crate::api::messages::ReactionSummary {
-            by_emoji: var_byEmoji,
-            user_reactions: var_userReactions,
-        };
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayUrl>> for RelayUrl {
+    fn into_into_dart(self) -> FrbWrapper<RelayUrl> {
+        self.into()
     }
 }
 
-impl SseDecode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Tag> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Tag> {}
 
-impl SseDecode for crate::api::relays::Relay {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_url = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::relays::Relay {
-            url: var_url,
-            created_at: var_createdAt,
-            updated_at: var_updatedAt,
-        };
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Tag>> for Tag {
+    fn into_into_dart(self) -> FrbWrapper<Tag> {
+        self.into()
     }
 }
 
-impl SseDecode for crate::api::messages::SerializableToken {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tokenType = <String>::sse_decode(deserializer);
-        let mut var_content = <Option<String>>::sse_decode(deserializer);
-        return crate::api::messages::SerializableToken {
-            token_type: var_tokenType,
-            content: var_content,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ThemeMode> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ThemeMode> {}
 
-impl SseDecode for u16 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ThemeMode>> for ThemeMode {
+    fn into_into_dart(self) -> FrbWrapper<ThemeMode> {
+        self.into()
     }
 }
 
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::accounts::Account {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pubkey.into_into_dart().into_dart(),
+            self.last_synced_at.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::accounts::Account {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::Account>
+    for crate::api::accounts::Account
+{
+    fn into_into_dart(self) -> crate::api::accounts::Account {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::ApiError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::ApiError::Whitenoise { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::InvalidKey { message } => {
+                [1.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::NostrUrl { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::NostrTag { message } => {
+                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::NostrEvent { message } => {
+                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::NostrParse { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::NostrHex { message } => {
+                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::PermissionDenied { message } => {
+                [7.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::InvalidRelayUrl { reason, message } => [
+                8.into_dart(),
+                reason.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::ApiError::Other { message } => {
+                [9.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for [u8; 12] {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::ApiError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::ApiError>
+    for crate::api::error::ApiError
+{
+    fn into_into_dart(self) -> crate::api::error::ApiError {
+        self
     }
 }
-
-impl SseDecode for [u8; 32] {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::BlockWelcomerResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.declined.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::BlockWelcomerResult
+{
 }
-
-impl SseDecode for crate::api::groups::UploadGroupImageResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_encryptedHash = <[u8; 32]>::sse_decode(deserializer);
-        let mut var_imageKey = <[u8; 32]>::sse_decode(deserializer);
-        let mut var_imageNonce = <[u8; 12]>::sse_decode(deserializer);
-        return crate::api::groups::UploadGroupImageResult {
-            encrypted_hash: var_encryptedHash,
-            image_key: var_imageKey,
-            image_nonce: var_imageNonce,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::BlockWelcomerResult>
+    for crate::api::welcomes::BlockWelcomerResult
+{
+    fn into_into_dart(self) -> crate::api::welcomes::BlockWelcomerResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::ChatMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.is_reply.into_into_dart().into_dart(),
+            self.reply_to_id.into_into_dart().into_dart(),
+            self.is_deleted.into_into_dart().into_dart(),
+            self.content_tokens.into_into_dart().into_dart(),
+            self.reactions.into_into_dart().into_dart(),
+            self.media_attachments.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.system_event.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::users::User {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        let mut var_metadata = <crate::api::metadata::FlutterMetadata>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        let mut var_updatedAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::users::User {
-            pubkey: var_pubkey,
-            metadata: var_metadata,
-            created_at: var_createdAt,
-            updated_at: var_updatedAt,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::ChatMessage
+{
 }
-
-impl SseDecode for crate::api::messages::UserReaction {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_user = <String>::sse_decode(deserializer);
-        let mut var_emoji = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::DateTime<chrono::Utc>>::sse_decode(deserializer);
-        return crate::api::messages::UserReaction {
-            user: var_user,
-            emoji: var_emoji,
-            created_at: var_createdAt,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ChatMessage>
+    for crate::api::messages::ChatMessage
+{
+    fn into_into_dart(self) -> crate::api::messages::ChatMessage {
+        self
     }
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::EmojiReaction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.emoji.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.users.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::welcomes::Welcome {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_mlsGroupId = <String>::sse_decode(deserializer);
-        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
-        let mut var_groupName = <String>::sse_decode(deserializer);
-        let mut var_groupDescription = <String>::sse_decode(deserializer);
-        let mut var_groupAdminPubkeys = <Vec<String>>::sse_decode(deserializer);
-        let mut var_groupRelays = <Vec<String>>::sse_decode(deserializer);
-        let mut var_welcomer = <String>::sse_decode(deserializer);
-        let mut var_memberCount = <u32>::sse_decode(deserializer);
-        let mut var_state = <crate::api::welcomes::WelcomeState>::sse_decode(deserializer);
-        let mut var_createdAt = <u64>::sse_decode(deserializer);
-        return crate::api::welcomes::Welcome {
-            id: var_id,
-            mls_group_id: var_mlsGroupId,
-            nostr_group_id: var_nostrGroupId,
-            group_name: var_groupName,
-            group_description: var_groupDescription,
-            group_admin_pubkeys: var_groupAdminPubkeys,
-            group_relays: var_groupRelays,
-            welcomer: var_welcomer,
-            member_count: var_memberCount,
-            state: var_state,
-            created_at: var_createdAt,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::EmojiReaction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::EmojiReaction>
+    for crate::api::messages::EmojiReaction
+{
+    fn into_into_dart(self) -> crate::api::messages::EmojiReaction {
+        self
     }
 }
-
-impl SseDecode for crate::api::welcomes::WelcomeState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::welcomes::WelcomeState::Pending,
-            1 => crate::api::welcomes::WelcomeState::Accepted,
-            2 => crate::api::welcomes::WelcomeState::Declined,
-            3 => crate::api::welcomes::WelcomeState::Ignored,
-            _ => unreachable!("Invalid variant for WelcomeState: {}", inner),
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media_files::FileMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.original_filename.into_into_dart().into_dart(),
+            self.dimensions.into_into_dart().into_dart(),
+            self.blurhash.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WhitenoiseConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_dataDir = <String>::sse_decode(deserializer);
-        let mut var_logsDir = <String>::sse_decode(deserializer);
-        return crate::api::WhitenoiseConfig {
-            data_dir: var_dataDir,
-            logs_dir: var_logsDir,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media_files::FileMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media_files::FileMetadata>
+    for crate::api::media_files::FileMetadata
+{
+    fn into_into_dart(self) -> crate::api::media_files::FileMetadata {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__welcomes__accept_welcome_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__accounts__account_follows_impl(port, ptr, rust_vec_len, data_len),
-        3 => {
-            wire__crate__api__accounts__account_key_package_impl(port, ptr, rust_vec_len, data_len)
-        }
-        4 => {
-            wire__crate__api__accounts__account_key_packages_impl(port, ptr, rust_vec_len, data_len)
-        }
-        5 => wire__crate__api__accounts__account_metadata_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__accounts__account_relays_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__groups__active_groups_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__accounts__add_account_relay_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__groups__add_members_to_group_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__error__api_error_error_type_impl(port, ptr, rust_vec_len, data_len),
-        11 => {
-            wire__crate__api__error__api_error_message_text_impl(port, ptr, rust_vec_len, data_len)
-        }
-        12 => wire__crate__api__groups__create_group_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__accounts__create_identity_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__create_whitenoise_config_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__welcomes__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__accounts__delete_account_key_package_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__api__accounts__delete_account_key_packages_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__api__delete_all_data_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__media_files__download_chat_media_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__relays__ensure_all_subscriptions_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        21 => {
-            wire__crate__api__accounts__export_account_nsec_impl(port, ptr, rust_vec_len, data_len)
-        }
-        22 => wire__crate__api__messages__fetch_aggregated_messages_for_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        23 => wire__crate__api__welcomes__find_welcome_by_event_id_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        24 => wire__crate__api__accounts__follow_user_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__accounts__get_account_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__relays__get_account_relay_statuses_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        27 => wire__crate__api__accounts__get_accounts_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__get_app_settings_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__utils__get_default_blossom_server_url_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        30 => wire__crate__api__groups__get_group_impl(port, ptr, rust_vec_len, data_len),
-        31 => {
-            wire__crate__api__groups__get_group_image_path_impl(port, ptr, rust_vec_len, data_len)
-        }
-        32 => {
-            wire__crate__api__groups__get_group_information_impl(port, ptr, rust_vec_len, data_len)
-        }
-        33 => wire__crate__api__groups__get_groups_informations_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        34 => wire__crate__api__users__get_user_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__groups__group_admins_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__groups__group_group_type_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__utils__group_id_from_string_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__utils__group_id_to_string_impl(port, ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__groups__group_is_direct_message_type_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        40 => wire__crate__api__groups__group_is_group_type_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__groups__group_members_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__groups__group_update_group_data_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        44 => wire__crate__api__initialize_whitenoise_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__accounts__login_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__accounts__logout_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__welcomes__pending_welcomes_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__accounts__publish_account_key_package_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        50 => wire__crate__api__relays__relay_type_inbox_impl(port, ptr, rust_vec_len, data_len),
-        51 => {
-            wire__crate__api__relays__relay_type_key_package_impl(port, ptr, rust_vec_len, data_len)
-        }
-        52 => wire__crate__api__relays__relay_type_nip65_impl(port, ptr, rust_vec_len, data_len),
-        53 => {
-            wire__crate__api__utils__relay_url_from_string_impl(port, ptr, rust_vec_len, data_len)
-        }
-        54 => {
-            wire__crate__api__accounts__remove_account_relay_impl(port, ptr, rust_vec_len, data_len)
-        }
-        55 => wire__crate__api__groups__remove_members_from_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        56 => wire__crate__api__messages__send_message_to_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        57 => {
-            wire__crate__api__utils__string_from_relay_url_impl(port, ptr, rust_vec_len, data_len)
-        }
-        58 => wire__crate__api__utils__tag_from_vec_impl(port, ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__accounts__unfollow_user_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__accounts__update_account_metadata_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        61 => wire__crate__api__update_theme_mode_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__accounts__upload_account_profile_picture_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        63 => {
-            wire__crate__api__media_files__upload_chat_media_impl(port, ptr, rust_vec_len, data_len)
-        }
-        64 => wire__crate__api__groups__upload_group_image_impl(port, ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__users__user_has_key_package_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__users__user_metadata_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__users__user_relays_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::accounts::FlutterEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        43 => wire__crate__api__utils__hex_pubkey_from_npub_impl(ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__utils__npub_from_hex_pubkey_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::accounts::FlutterEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::FlutterEvent>
+    for crate::api::accounts::FlutterEvent
+{
+    fn into_into_dart(self) -> crate::api::accounts::FlutterEvent {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AppSettings> {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::FlutterGroupDataUpdate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.relays.into_into_dart().into_dart(),
+            self.admins.into_into_dart().into_dart(),
+            self.image_key.into_into_dart().into_dart(),
+            self.image_hash.into_into_dart().into_dart(),
+            self.image_nonce.into_into_dart().into_dart(),
+            self.disappearing_messages_secs.into_into_dart().into_dart(),
+            self.permissions.into_into_dart().into_dart(),
+            self.replace_unhealthy_relays.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AppSettings> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AppSettings>> for AppSettings {
-    fn into_into_dart(self) -> FrbWrapper<AppSettings> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::FlutterGroupDataUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::FlutterGroupDataUpdate>
+    for crate::api::groups::FlutterGroupDataUpdate
+{
+    fn into_into_dart(self) -> crate::api::groups::FlutterGroupDataUpdate {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GroupId> {
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::FlutterMetadata {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.name.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.about.into_into_dart().into_dart(),
+            self.picture.into_into_dart().into_dart(),
+            self.banner.into_into_dart().into_dart(),
+            self.website.into_into_dart().into_dart(),
+            self.nip05.into_into_dart().into_dart(),
+            self.lud06.into_into_dart().into_dart(),
+            self.lud16.into_into_dart().into_dart(),
+            self.custom.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GroupId> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GroupId>> for GroupId {
-    fn into_into_dart(self) -> FrbWrapper<GroupId> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::FlutterMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::FlutterMetadata>
+    for crate::api::metadata::FlutterMetadata
+{
+    fn into_into_dart(self) -> crate::api::metadata::FlutterMetadata {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayType> {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::Group {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.nostr_group_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.image_hash.into_into_dart().into_dart(),
+            self.image_key.into_into_dart().into_dart(),
+            self.admin_pubkeys.into_into_dart().into_dart(),
+            self.last_message_id.into_into_dart().into_dart(),
+            self.last_message_at.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.disappearing_messages_secs.into_into_dart().into_dart(),
+            self.permissions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayType> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayType>> for RelayType {
-    fn into_into_dart(self) -> FrbWrapper<RelayType> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::Group {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::Group> for crate::api::groups::Group {
+    fn into_into_dart(self) -> crate::api::groups::Group {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<RelayUrl> {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupInformation {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.group_type.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RelayUrl> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RelayUrl>> for RelayUrl {
-    fn into_into_dart(self) -> FrbWrapper<RelayUrl> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupInformation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupInformation>
+    for crate::api::groups::GroupInformation
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupInformation {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Tag> {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupMember {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.pubkey.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.is_admin.into_into_dart().into_dart(),
+            self.is_self.into_into_dart().into_dart(),
+            self.added_at_epoch.into_into_dart().into_dart(),
+            self.added_by.into_into_dart().into_dart(),
+            self.leaf_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Tag> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Tag>> for Tag {
-    fn into_into_dart(self) -> FrbWrapper<Tag> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupMember
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupMember>
+    for crate::api::groups::GroupMember
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupMember {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ThemeMode> {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupPermissions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.admins_only_post.into_into_dart().into_dart(),
+            self.admins_only_add_members.into_into_dart().into_dart(),
+            self.slow_mode_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ThemeMode> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ThemeMode>> for ThemeMode {
-    fn into_into_dart(self) -> FrbWrapper<ThemeMode> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupPermissions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupPermissions>
+    for crate::api::groups::GroupPermissions
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupPermissions {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::accounts::Account {
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupRelayAlert {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.pubkey.into_into_dart().into_dart(),
-            self.last_synced_at.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.group_name.into_into_dart().into_dart(),
+            self.all_unhealthy_since.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupRelayAlert
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupRelayAlert>
+    for crate::api::groups::GroupRelayAlert
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupRelayAlert {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupRelayHealth {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.relays.into_into_dart().into_dart(),
+            self.all_unhealthy_since.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::accounts::Account {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::Account>
-    for crate::api::accounts::Account
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupRelayHealth
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupRelayHealth>
+    for crate::api::groups::GroupRelayHealth
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupRelayHealth {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Active => 0.into_dart(),
+            Self::Inactive => 1.into_dart(),
+            Self::Pending => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::groups::GroupState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupState>
+    for crate::api::groups::GroupState
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::DirectMessage => 0.into_dart(),
+            Self::Group => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::GroupType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupType>
+    for crate::api::groups::GroupType
+{
+    fn into_into_dart(self) -> crate::api::groups::GroupType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::relays::InvalidRelayUrlReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Malformed => 0.into_dart(),
+            Self::BadScheme => 1.into_dart(),
+            Self::PrivateAddress => 2.into_dart(),
+            Self::OnionWithoutProxy => 3.into_dart(),
+            Self::Duplicate => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::relays::InvalidRelayUrlReason
 {
-    fn into_into_dart(self) -> crate::api::accounts::Account {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::InvalidRelayUrlReason>
+    for crate::api::relays::InvalidRelayUrlReason
+{
+    fn into_into_dart(self) -> crate::api::relays::InvalidRelayUrlReason {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::error::ApiError {
+impl flutter_rust_bridge::IntoDart for crate::api::invites::InviteLink {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::error::ApiError::Whitenoise { message } => {
-                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::InvalidKey { message } => {
-                [1.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::NostrUrl { message } => {
-                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::NostrTag { message } => {
-                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::NostrEvent { message } => {
-                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::NostrParse { message } => {
-                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::NostrHex { message } => {
-                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::ApiError::Other { message } => {
-                [7.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.token.into_into_dart().into_dart(),
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.max_uses.into_into_dart().into_dart(),
+            self.uses.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::ApiError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::error::ApiError>
-    for crate::api::error::ApiError
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invites::InviteLink
 {
-    fn into_into_dart(self) -> crate::api::error::ApiError {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invites::InviteLink>
+    for crate::api::invites::InviteLink
+{
+    fn into_into_dart(self) -> crate::api::invites::InviteLink {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::ChatMessage {
+impl flutter_rust_bridge::IntoDart for crate::api::invites::JoinRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
-            self.pubkey.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.requester_pubkey.into_into_dart().into_dart(),
+            self.requester_metadata.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.is_reply.into_into_dart().into_dart(),
-            self.reply_to_id.into_into_dart().into_dart(),
-            self.is_deleted.into_into_dart().into_dart(),
-            self.content_tokens.into_into_dart().into_dart(),
-            self.reactions.into_into_dart().into_dart(),
-            self.media_attachments.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::ChatMessage
+    for crate::api::invites::JoinRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ChatMessage>
-    for crate::api::messages::ChatMessage
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invites::JoinRequest>
+    for crate::api::invites::JoinRequest
 {
-    fn into_into_dart(self) -> crate::api::messages::ChatMessage {
+    fn into_into_dart(self) -> crate::api::invites::JoinRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::EmojiReaction {
+impl flutter_rust_bridge::IntoDart for crate::api::media_files::MediaFile {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.emoji.into_into_dart().into_dart(),
-            self.count.into_into_dart().into_dart(),
-            self.users.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.account_pubkey.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.original_file_hash.into_into_dart().into_dart(),
+            self.encrypted_file_hash.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.media_type.into_into_dart().into_dart(),
+            self.blossom_url.into_into_dart().into_dart(),
+            self.nostr_key.into_into_dart().into_dart(),
+            self.file_metadata.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::EmojiReaction
+    for crate::api::media_files::MediaFile
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::EmojiReaction>
-    for crate::api::messages::EmojiReaction
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media_files::MediaFile>
+    for crate::api::media_files::MediaFile
 {
-    fn into_into_dart(self) -> crate::api::messages::EmojiReaction {
+    fn into_into_dart(self) -> crate::api::media_files::MediaFile {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media_files::FileMetadata {
+impl flutter_rust_bridge::IntoDart for crate::api::verification::MemberFingerprint {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.original_filename.into_into_dart().into_dart(),
-            self.dimensions.into_into_dart().into_dart(),
-            self.blurhash.into_into_dart().into_dart(),
+            self.member_pubkey.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+            self.safety_number.into_into_dart().into_dart(),
+            self.qr_payload.into_into_dart().into_dart(),
+            self.verification_state.into_into_dart().into_dart(),
+            self.verified_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media_files::FileMetadata
+    for crate::api::verification::MemberFingerprint
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media_files::FileMetadata>
-    for crate::api::media_files::FileMetadata
+impl flutter_rust_bridge::IntoIntoDart<crate::api::verification::MemberFingerprint>
+    for crate::api::verification::MemberFingerprint
 {
-    fn into_into_dart(self) -> crate::api::media_files::FileMetadata {
+    fn into_into_dart(self) -> crate::api::verification::MemberFingerprint {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::accounts::FlutterEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageWithTokens {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
             self.pubkey.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
             self.kind.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
             self.content.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::accounts::FlutterEvent
+    for crate::api::messages::MessageWithTokens
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::FlutterEvent>
-    for crate::api::accounts::FlutterEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageWithTokens>
+    for crate::api::messages::MessageWithTokens
 {
-    fn into_into_dart(self) -> crate::api::accounts::FlutterEvent {
+    fn into_into_dart(self) -> crate::api::messages::MessageWithTokens {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::FlutterGroupDataUpdate {
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::MetadataValue {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.relays.into_into_dart().into_dart(),
-            self.admins.into_into_dart().into_dart(),
-            self.image_key.into_into_dart().into_dart(),
-            self.image_hash.into_into_dart().into_dart(),
-            self.image_nonce.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            crate::api::metadata::MetadataValue::Null => [0.into_dart()].into_dart(),
+            crate::api::metadata::MetadataValue::Bool(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::Integer(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::UnsignedInteger(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::Float(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::String(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::Array(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::metadata::MetadataValue::Object(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::groups::FlutterGroupDataUpdate
+    for crate::api::metadata::MetadataValue
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::FlutterGroupDataUpdate>
-    for crate::api::groups::FlutterGroupDataUpdate
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::MetadataValue>
+    for crate::api::metadata::MetadataValue
 {
-    fn into_into_dart(self) -> crate::api::groups::FlutterGroupDataUpdate {
+    fn into_into_dart(self) -> crate::api::metadata::MetadataValue {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::metadata::FlutterMetadata {
+impl flutter_rust_bridge::IntoDart for crate::api::users::Nip05Status {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unverified => 0.into_dart(),
+            Self::Verified => 1.into_dart(),
+            Self::Mismatch => 2.into_dart(),
+            Self::Unreachable => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::users::Nip05Status
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::users::Nip05Status>
+    for crate::api::users::Nip05Status
+{
+    fn into_into_dart(self) -> crate::api::users::Nip05Status {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy::ProxySettings {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.name.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.about.into_into_dart().into_dart(),
-            self.picture.into_into_dart().into_dart(),
-            self.banner.into_into_dart().into_dart(),
-            self.website.into_into_dart().into_dart(),
-            self.nip05.into_into_dart().into_dart(),
-            self.lud06.into_into_dart().into_dart(),
-            self.lud16.into_into_dart().into_dart(),
-            self.custom.into_into_dart().into_dart(),
+            self.proxy_url.into_into_dart().into_dart(),
+            self.bypass_relays.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::metadata::FlutterMetadata
+    for crate::api::proxy::ProxySettings
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::FlutterMetadata>
-    for crate::api::metadata::FlutterMetadata
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy::ProxySettings>
+    for crate::api::proxy::ProxySettings
 {
-    fn into_into_dart(self) -> crate::api::metadata::FlutterMetadata {
+    fn into_into_dart(self) -> crate::api::proxy::ProxySettings {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::Group {
+impl flutter_rust_bridge::IntoDart for crate::api::messages::ReactionSummary {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.mls_group_id.into_into_dart().into_dart(),
-            self.nostr_group_id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.image_hash.into_into_dart().into_dart(),
-            self.image_key.into_into_dart().into_dart(),
-            self.admin_pubkeys.into_into_dart().into_dart(),
-            self.last_message_id.into_into_dart().into_dart(),
-            self.last_message_at.into_into_dart().into_dart(),
-            self.epoch.into_into_dart().into_dart(),
-            self.state.into_into_dart().into_dart(),
+            self.by_emoji.into_into_dart().into_dart(),
+            self.user_reactions.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::Group {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::Group> for crate::api::groups::Group {
-    fn into_into_dart(self) -> crate::api::groups::Group {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::ReactionSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ReactionSummary>
+    for crate::api::messages::ReactionSummary
+{
+    fn into_into_dart(self) -> crate::api::messages::ReactionSummary {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupInformation {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::Relay {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.mls_group_id.into_into_dart().into_dart(),
-            self.group_type.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
             self.updated_at.into_into_dart().into_dart(),
+            self.info.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::relays::Relay {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::Relay> for crate::api::relays::Relay {
+    fn into_into_dart(self) -> crate::api::relays::Relay {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayAuthPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Always => 0.into_dart(),
+            Self::Ask => 1.into_dart(),
+            Self::Never => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::groups::GroupInformation
+    for crate::api::relays::RelayAuthPolicy
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupInformation>
-    for crate::api::groups::GroupInformation
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayAuthPolicy>
+    for crate::api::relays::RelayAuthPolicy
 {
-    fn into_into_dart(self) -> crate::api::groups::GroupInformation {
+    fn into_into_dart(self) -> crate::api::relays::RelayAuthPolicy {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupState {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayAuthState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::Active => 0.into_dart(),
-            Self::Inactive => 1.into_dart(),
-            Self::Pending => 2.into_dart(),
+            Self::NotRequired => 0.into_dart(),
+            Self::Required => 1.into_dart(),
+            Self::Authenticated => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::groups::GroupState
+    for crate::api::relays::RelayAuthState
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupState>
-    for crate::api::groups::GroupState
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayAuthState>
+    for crate::api::relays::RelayAuthState
 {
-    fn into_into_dart(self) -> crate::api::groups::GroupState {
+    fn into_into_dart(self) -> crate::api::relays::RelayAuthState {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::groups::GroupType {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayConnectionInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::DirectMessage => 0.into_dart(),
-            Self::Group => 1.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.url.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.last_connected_at.into_into_dart().into_dart(),
+            self.failed_attempts.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.auth_state.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::groups::GroupType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::groups::GroupType>
-    for crate::api::groups::GroupType
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::relays::RelayConnectionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayConnectionInfo>
+    for crate::api::relays::RelayConnectionInfo
+{
+    fn into_into_dart(self) -> crate::api::relays::RelayConnectionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayHealth {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.success_rate.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.is_healthy.into_into_dart().into_dart(),
+            self.unhealthy_since.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::relays::RelayHealth
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayHealth>
+    for crate::api::relays::RelayHealth
 {
-    fn into_into_dart(self) -> crate::api::groups::GroupType {
+    fn into_into_dart(self) -> crate::api::relays::RelayHealth {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media_files::MediaFile {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.mls_group_id.into_into_dart().into_dart(),
-            self.account_pubkey.into_into_dart().into_dart(),
-            self.file_path.into_into_dart().into_dart(),
-            self.original_file_hash.into_into_dart().into_dart(),
-            self.encrypted_file_hash.into_into_dart().into_dart(),
-            self.mime_type.into_into_dart().into_dart(),
-            self.media_type.into_into_dart().into_dart(),
-            self.blossom_url.into_into_dart().into_dart(),
-            self.nostr_key.into_into_dart().into_dart(),
-            self.file_metadata.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.software.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.supported_nips.into_into_dart().into_dart(),
+            self.max_message_length.into_into_dart().into_dart(),
+            self.auth_required.into_into_dart().into_dart(),
+            self.payment_required.into_into_dart().into_dart(),
+            self.restricted_writes.into_into_dart().into_dart(),
+            self.icon.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+            self.fetched_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media_files::MediaFile
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media_files::MediaFile>
-    for crate::api::media_files::MediaFile
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::relays::RelayInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayInfo>
+    for crate::api::relays::RelayInfo
 {
-    fn into_into_dart(self) -> crate::api::media_files::MediaFile {
+    fn into_into_dart(self) -> crate::api::relays::RelayInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageWithTokens {
+impl flutter_rust_bridge::IntoDart for crate::api::accounts::RelayListChange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.pubkey.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.tokens.into_into_dart().into_dart(),
+            self.added.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::MessageWithTokens
+    for crate::api::accounts::RelayListChange
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageWithTokens>
-    for crate::api::messages::MessageWithTokens
+impl flutter_rust_bridge::IntoIntoDart<crate::api::accounts::RelayListChange>
+    for crate::api::accounts::RelayListChange
 {
-    fn into_into_dart(self) -> crate::api::messages::MessageWithTokens {
+    fn into_into_dart(self) -> crate::api::accounts::RelayListChange {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::messages::ReactionSummary {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayStatus {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.by_emoji.into_into_dart().into_dart(),
-            self.user_reactions.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Initialized => 0.into_dart(),
+            Self::Pending => 1.into_dart(),
+            Self::Connecting => 2.into_dart(),
+            Self::Connected => 3.into_dart(),
+            Self::Disconnected => 4.into_dart(),
+            Self::Terminated => 5.into_dart(),
+            Self::Banned => 6.into_dart(),
+            Self::Sleeping => 7.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::messages::ReactionSummary
+    for crate::api::relays::RelayStatus
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::ReactionSummary>
-    for crate::api::messages::ReactionSummary
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayStatus>
+    for crate::api::relays::RelayStatus
 {
-    fn into_into_dart(self) -> crate::api::messages::ReactionSummary {
+    fn into_into_dart(self) -> crate::api::relays::RelayStatus {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::relays::Relay {
+impl flutter_rust_bridge::IntoDart for crate::api::relays::RelayWarning {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.url.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::AuthRequired => 0.into_dart(),
+            Self::PaymentRequired => 1.into_dart(),
+            Self::RestrictedWrites => 2.into_dart(),
+            Self::MessageLengthTooSmall => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::relays::Relay {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::Relay> for crate::api::relays::Relay {
-    fn into_into_dart(self) -> crate::api::relays::Relay {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::relays::RelayWarning
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::relays::RelayWarning>
+    for crate::api::relays::RelayWarning
+{
+    fn into_into_dart(self) -> crate::api::relays::RelayWarning {
         self
     }
 }
@@ -4376,6 +7713,82 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::SerializableToken>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::users::SyncPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::users::SyncPolicy::CacheOnly => [0.into_dart()].into_dart(),
+            crate::api::users::SyncPolicy::StaleWhileRevalidate { max_age_secs } => {
+                [1.into_dart(), max_age_secs.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::users::SyncPolicy::ForceRefresh => [2.into_dart()].into_dart(),
+            crate::api::users::SyncPolicy::RefreshWithTimeout { timeout_ms } => {
+                [3.into_dart(), timeout_ms.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::users::SyncPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::users::SyncPolicy>
+    for crate::api::users::SyncPolicy
+{
+    fn into_into_dart(self) -> crate::api::users::SyncPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::SystemEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::messages::SystemEvent::DisappearingMessagesChanged {
+                changed_by,
+                duration_secs,
+            } => [
+                0.into_dart(),
+                changed_by.into_into_dart().into_dart(),
+                duration_secs.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::messages::SystemEvent::MembersRemoved {
+                removed_by,
+                members,
+                reason,
+            } => [
+                1.into_dart(),
+                removed_by.into_into_dart().into_dart(),
+                members.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::messages::SystemEvent::PermissionsChanged {
+                changed_by,
+                permissions,
+            } => [
+                2.into_dart(),
+                changed_by.into_into_dart().into_dart(),
+                permissions.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::SystemEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::SystemEvent>
+    for crate::api::messages::SystemEvent
+{
+    fn into_into_dart(self) -> crate::api::messages::SystemEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::groups::UploadGroupImageResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4405,6 +7818,10 @@ impl flutter_rust_bridge::IntoDart for crate::api::users::User {
             self.metadata.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
             self.updated_at.into_into_dart().into_dart(),
+            self.last_fetched_at.into_into_dart().into_dart(),
+            self.fetched_from_relays.into_into_dart().into_dart(),
+            self.nip05_status.into_into_dart().into_dart(),
+            self.nip05_checked_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4438,6 +7855,75 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::UserReaction>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::users::UserSearchMatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pubkey => 0.into_dart(),
+            Self::Nip05 => 1.into_dart(),
+            Self::Prefix => 2.into_dart(),
+            Self::Substring => 3.into_dart(),
+            Self::Fuzzy => 4.into_dart(),
+            Self::Relay => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::users::UserSearchMatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::users::UserSearchMatch>
+    for crate::api::users::UserSearchMatch
+{
+    fn into_into_dart(self) -> crate::api::users::UserSearchMatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::users::UserSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user.into_into_dart().into_dart(),
+            self.matched_by.into_into_dart().into_dart(),
+            self.is_follow.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::users::UserSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::users::UserSearchResult>
+    for crate::api::users::UserSearchResult
+{
+    fn into_into_dart(self) -> crate::api::users::UserSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::verification::VerificationState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unverified => 0.into_dart(),
+            Self::Verified => 1.into_dart(),
+            Self::KeyChanged => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::verification::VerificationState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::verification::VerificationState>
+    for crate::api::verification::VerificationState
+{
+    fn into_into_dart(self) -> crate::api::verification::VerificationState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::welcomes::Welcome {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4465,6 +7951,97 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::Welcome>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeActionResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.welcome_event_id.into_into_dart().into_dart(),
+            self.success.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeActionResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeActionResult>
+    for crate::api::welcomes::WelcomeActionResult
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeActionResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sender_policy.into_into_dart().into_dart(),
+            self.max_member_count.into_into_dart().into_dart(),
+            self.auto_accept_from_follows.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomePolicy>
+    for crate::api::welcomes::WelcomePolicy
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomePolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomePreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.welcome.into_into_dart().into_dart(),
+            self.welcomer_metadata.into_into_dart().into_dart(),
+            self.is_following_welcomer.into_into_dart().into_dart(),
+            self.group_image_path.into_into_dart().into_dart(),
+            self.member_pubkeys.into_into_dart().into_dart(),
+            self.group_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomePreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomePreview>
+    for crate::api::welcomes::WelcomePreview
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomePreview {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeSenderPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Anyone => 0.into_dart(),
+            Self::Follows => 1.into_dart(),
+            Self::FollowsOfFollows => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeSenderPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeSenderPolicy>
+    for crate::api::welcomes::WelcomeSenderPolicy
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeSenderPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -4493,6 +8070,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::WhitenoiseConfig {
         [
             self.data_dir.into_into_dart().into_dart(),
             self.logs_dir.into_into_dart().into_dart(),
+            self.proxy_url.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4506,6 +8084,13 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::WhitenoiseConfig>
     }
 }
 
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
 impl SseEncode for AppSettings {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4558,10 +8143,13 @@ impl SseEncode for chrono::DateTime<chrono::Utc> {
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for std::collections::HashMap<String, crate::api::metadata::MetadataValue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <Vec<(String, crate::api::metadata::MetadataValue)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
     }
 }
 
@@ -4627,6 +8215,27 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::groups::GroupRelayAlert,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::users::User, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4676,10 +8285,19 @@ impl SseEncode for crate::api::error::ApiError {
                 <i32>::sse_encode(6, serializer);
                 <String>::sse_encode(message, serializer);
             }
-            crate::api::error::ApiError::Other { message } => {
+            crate::api::error::ApiError::PermissionDenied { message } => {
                 <i32>::sse_encode(7, serializer);
                 <String>::sse_encode(message, serializer);
             }
+            crate::api::error::ApiError::InvalidRelayUrl { reason, message } => {
+                <i32>::sse_encode(8, serializer);
+                <crate::api::relays::InvalidRelayUrlReason>::sse_encode(reason, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::ApiError::Other { message } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(message, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -4687,6 +8305,14 @@ impl SseEncode for crate::api::error::ApiError {
     }
 }
 
+impl SseEncode for crate::api::welcomes::BlockWelcomerResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.declined, serializer);
+        <Vec<crate::api::welcomes::WelcomeActionResult>>::sse_encode(self.failed, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4709,6 +8335,7 @@ impl SseEncode for crate::api::messages::ChatMessage {
         <crate::api::messages::ReactionSummary>::sse_encode(self.reactions, serializer);
         <Vec<crate::api::media_files::MediaFile>>::sse_encode(self.media_attachments, serializer);
         <u16>::sse_encode(self.kind, serializer);
+        <Option<crate::api::messages::SystemEvent>>::sse_encode(self.system_event, serializer);
     }
 }
 
@@ -4721,6 +8348,13 @@ impl SseEncode for crate::api::messages::EmojiReaction {
     }
 }
 
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for crate::api::media_files::FileMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4752,6 +8386,9 @@ impl SseEncode for crate::api::groups::FlutterGroupDataUpdate {
         <Option<[u8; 32]>>::sse_encode(self.image_key, serializer);
         <Option<[u8; 32]>>::sse_encode(self.image_hash, serializer);
         <Option<[u8; 12]>>::sse_encode(self.image_nonce, serializer);
+        <Option<u64>>::sse_encode(self.disappearing_messages_secs, serializer);
+        <Option<crate::api::groups::GroupPermissions>>::sse_encode(self.permissions, serializer);
+        <Option<bool>>::sse_encode(self.replace_unhealthy_relays, serializer);
     }
 }
 
@@ -4767,7 +8404,10 @@ impl SseEncode for crate::api::metadata::FlutterMetadata {
         <Option<String>>::sse_encode(self.nip05, serializer);
         <Option<String>>::sse_encode(self.lud06, serializer);
         <Option<String>>::sse_encode(self.lud16, serializer);
-        <std::collections::HashMap<String, String>>::sse_encode(self.custom, serializer);
+        <std::collections::HashMap<String, crate::api::metadata::MetadataValue>>::sse_encode(
+            self.custom,
+            serializer,
+        );
     }
 }
 
@@ -4785,16 +8425,57 @@ impl SseEncode for crate::api::groups::Group {
         <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.last_message_at, serializer);
         <u64>::sse_encode(self.epoch, serializer);
         <crate::api::groups::GroupState>::sse_encode(self.state, serializer);
+        <Option<u64>>::sse_encode(self.disappearing_messages_secs, serializer);
+        <crate::api::groups::GroupPermissions>::sse_encode(self.permissions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::groups::GroupInformation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mls_group_id, serializer);
+        <crate::api::groups::GroupType>::sse_encode(self.group_type, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::groups::GroupMember {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.pubkey, serializer);
+        <crate::api::metadata::FlutterMetadata>::sse_encode(self.metadata, serializer);
+        <bool>::sse_encode(self.is_admin, serializer);
+        <bool>::sse_encode(self.is_self, serializer);
+        <Option<u64>>::sse_encode(self.added_at_epoch, serializer);
+        <Option<String>>::sse_encode(self.added_by, serializer);
+        <Option<u32>>::sse_encode(self.leaf_index, serializer);
+    }
+}
+
+impl SseEncode for crate::api::groups::GroupPermissions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.admins_only_post, serializer);
+        <bool>::sse_encode(self.admins_only_add_members, serializer);
+        <Option<u64>>::sse_encode(self.slow_mode_secs, serializer);
     }
 }
 
-impl SseEncode for crate::api::groups::GroupInformation {
+impl SseEncode for crate::api::groups::GroupRelayAlert {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.mls_group_id, serializer);
-        <crate::api::groups::GroupType>::sse_encode(self.group_type, serializer);
-        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
-        <chrono::DateTime<chrono::Utc>>::sse_encode(self.updated_at, serializer);
+        <String>::sse_encode(self.group_id, serializer);
+        <String>::sse_encode(self.group_name, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.all_unhealthy_since, serializer);
+    }
+}
+
+impl SseEncode for crate::api::groups::GroupRelayHealth {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::relays::RelayHealth>>::sse_encode(self.relays, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.all_unhealthy_since, serializer);
     }
 }
 
@@ -4845,6 +8526,48 @@ impl SseEncode for i64 {
     }
 }
 
+impl SseEncode for crate::api::relays::InvalidRelayUrlReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::relays::InvalidRelayUrlReason::Malformed => 0,
+                crate::api::relays::InvalidRelayUrlReason::BadScheme => 1,
+                crate::api::relays::InvalidRelayUrlReason::PrivateAddress => 2,
+                crate::api::relays::InvalidRelayUrlReason::OnionWithoutProxy => 3,
+                crate::api::relays::InvalidRelayUrlReason::Duplicate => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::invites::InviteLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.token, serializer);
+        <String>::sse_encode(self.mls_group_id, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.expires_at, serializer);
+        <Option<u32>>::sse_encode(self.max_uses, serializer);
+        <u32>::sse_encode(self.uses, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::invites::JoinRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.mls_group_id, serializer);
+        <String>::sse_encode(self.requester_pubkey, serializer);
+        <crate::api::metadata::FlutterMetadata>::sse_encode(self.requester_metadata, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+    }
+}
+
 impl SseEncode for Vec<Tag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4925,6 +8648,26 @@ impl SseEncode for Vec<crate::api::groups::GroupInformation> {
     }
 }
 
+impl SseEncode for Vec<crate::api::groups::GroupMember> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::groups::GroupMember>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::invites::JoinRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::invites::JoinRequest>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::media_files::MediaFile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4935,142 +8678,377 @@ impl SseEncode for Vec<crate::api::media_files::MediaFile> {
     }
 }
 
+impl SseEncode for Vec<crate::api::metadata::MetadataValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metadata::MetadataValue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u16>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<(String, crate::api::metadata::MetadataValue)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, crate::api::metadata::MetadataValue)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::relays::Relay> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::relays::Relay>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::relays::RelayConnectionInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::relays::RelayConnectionInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::relays::RelayHealth> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::relays::RelayHealth>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::relays::RelayWarning> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::relays::RelayWarning>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::messages::SerializableToken> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::messages::SerializableToken>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::users::User> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::users::User>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::messages::UserReaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::messages::UserReaction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::users::UserSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::users::UserSearchResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::welcomes::Welcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::welcomes::Welcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::welcomes::WelcomeActionResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::welcomes::WelcomeActionResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::media_files::MediaFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.mls_group_id, serializer);
+        <String>::sse_encode(self.account_pubkey, serializer);
+        <String>::sse_encode(self.file_path, serializer);
+        <Option<String>>::sse_encode(self.original_file_hash, serializer);
+        <String>::sse_encode(self.encrypted_file_hash, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.media_type, serializer);
+        <String>::sse_encode(self.blossom_url, serializer);
+        <String>::sse_encode(self.nostr_key, serializer);
+        <Option<crate::api::media_files::FileMetadata>>::sse_encode(self.file_metadata, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::verification::MemberFingerprint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.member_pubkey, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+        <String>::sse_encode(self.safety_number, serializer);
+        <String>::sse_encode(self.qr_payload, serializer);
+        <crate::api::verification::VerificationState>::sse_encode(
+            self.verification_state,
+            serializer,
+        );
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.verified_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::MessageWithTokens {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.pubkey, serializer);
+        <u16>::sse_encode(self.kind, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.content, serializer);
+        <Vec<crate::api::messages::SerializableToken>>::sse_encode(self.tokens, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metadata::MetadataValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::metadata::MetadataValue::Null => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::metadata::MetadataValue::Bool(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <bool>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::Integer(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::UnsignedInteger(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::Float(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::String(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::Array(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <Vec<crate::api::metadata::MetadataValue>>::sse_encode(field0, serializer);
+            }
+            crate::api::metadata::MetadataValue::Object(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <std::collections::HashMap<String, crate::api::metadata::MetadataValue>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::users::Nip05Status {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::users::Nip05Status::Unverified => 0,
+                crate::api::users::Nip05Status::Verified => 1,
+                crate::api::users::Nip05Status::Mismatch => 2,
+                crate::api::users::Nip05Status::Unreachable => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, String)> {
+impl SseEncode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, String)>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <chrono::DateTime<chrono::Utc>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::relays::Relay> {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::relays::Relay>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::messages::SerializableToken> {
+impl SseEncode for Option<crate::api::media_files::FileMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::messages::SerializableToken>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media_files::FileMetadata>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::users::User> {
+impl SseEncode for Option<crate::api::accounts::FlutterEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::users::User>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::accounts::FlutterEvent>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::messages::UserReaction> {
+impl SseEncode for Option<crate::api::groups::GroupPermissions> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::messages::UserReaction>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::groups::GroupPermissions>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::welcomes::Welcome> {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::welcomes::Welcome>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::media_files::MediaFile {
+impl SseEncode for Option<crate::api::proxy::ProxySettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.mls_group_id, serializer);
-        <String>::sse_encode(self.account_pubkey, serializer);
-        <String>::sse_encode(self.file_path, serializer);
-        <Option<String>>::sse_encode(self.original_file_hash, serializer);
-        <String>::sse_encode(self.encrypted_file_hash, serializer);
-        <String>::sse_encode(self.mime_type, serializer);
-        <String>::sse_encode(self.media_type, serializer);
-        <String>::sse_encode(self.blossom_url, serializer);
-        <String>::sse_encode(self.nostr_key, serializer);
-        <Option<crate::api::media_files::FileMetadata>>::sse_encode(self.file_metadata, serializer);
-        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::proxy::ProxySettings>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::messages::MessageWithTokens {
+impl SseEncode for Option<crate::api::relays::RelayInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.pubkey, serializer);
-        <u16>::sse_encode(self.kind, serializer);
-        <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
-        <Option<String>>::sse_encode(self.content, serializer);
-        <Vec<crate::api::messages::SerializableToken>>::sse_encode(self.tokens, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::relays::RelayInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<crate::api::messages::SystemEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <crate::api::messages::SystemEvent>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<chrono::DateTime<chrono::Utc>> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <chrono::DateTime<chrono::Utc>>::sse_encode(value, serializer);
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::media_files::FileMetadata> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::media_files::FileMetadata>::sse_encode(value, serializer);
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::accounts::FlutterEvent> {
+impl SseEncode for Option<crate::api::welcomes::WelcomeState> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::accounts::FlutterEvent>::sse_encode(value, serializer);
+            <crate::api::welcomes::WelcomeState>::sse_encode(value, serializer);
         }
     }
 }
@@ -5115,6 +9093,14 @@ impl SseEncode for Option<[u8; 32]> {
     }
 }
 
+impl SseEncode for crate::api::proxy::ProxySettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.proxy_url, serializer);
+        <Vec<String>>::sse_encode(self.bypass_relays, serializer);
+    }
+}
+
 impl SseEncode for crate::api::messages::ReactionSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5123,11 +9109,11 @@ impl SseEncode for crate::api::messages::ReactionSummary {
     }
 }
 
-impl SseEncode for (String, String) {
+impl SseEncode for (String, crate::api::metadata::MetadataValue) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.0, serializer);
-        <String>::sse_encode(self.1, serializer);
+        <crate::api::metadata::MetadataValue>::sse_encode(self.1, serializer);
     }
 }
 
@@ -5137,6 +9123,136 @@ impl SseEncode for crate::api::relays::Relay {
         <String>::sse_encode(self.url, serializer);
         <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
         <chrono::DateTime<chrono::Utc>>::sse_encode(self.updated_at, serializer);
+        <Option<crate::api::relays::RelayInfo>>::sse_encode(self.info, serializer);
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayAuthPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::relays::RelayAuthPolicy::Always => 0,
+                crate::api::relays::RelayAuthPolicy::Ask => 1,
+                crate::api::relays::RelayAuthPolicy::Never => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayAuthState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::relays::RelayAuthState::NotRequired => 0,
+                crate::api::relays::RelayAuthState::Required => 1,
+                crate::api::relays::RelayAuthState::Authenticated => 2,
+                crate::api::relays::RelayAuthState::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayConnectionInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <crate::api::relays::RelayStatus>::sse_encode(self.status, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.last_connected_at, serializer);
+        <u64>::sse_encode(self.failed_attempts, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <Option<u64>>::sse_encode(self.latency_ms, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <crate::api::relays::RelayAuthState>::sse_encode(self.auth_state, serializer);
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayHealth {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <f64>::sse_encode(self.success_rate, serializer);
+        <Option<u64>>::sse_encode(self.latency_ms, serializer);
+        <bool>::sse_encode(self.is_healthy, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.unhealthy_since, serializer);
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.software, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <Vec<u16>>::sse_encode(self.supported_nips, serializer);
+        <Option<i64>>::sse_encode(self.max_message_length, serializer);
+        <bool>::sse_encode(self.auth_required, serializer);
+        <bool>::sse_encode(self.payment_required, serializer);
+        <bool>::sse_encode(self.restricted_writes, serializer);
+        <Option<String>>::sse_encode(self.icon, serializer);
+        <Vec<crate::api::relays::RelayWarning>>::sse_encode(self.warnings, serializer);
+        <chrono::DateTime<chrono::Utc>>::sse_encode(self.fetched_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::accounts::RelayListChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.added, serializer);
+        <Vec<String>>::sse_encode(self.removed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::relays::RelayStatus::Initialized => 0,
+                crate::api::relays::RelayStatus::Pending => 1,
+                crate::api::relays::RelayStatus::Connecting => 2,
+                crate::api::relays::RelayStatus::Connected => 3,
+                crate::api::relays::RelayStatus::Disconnected => 4,
+                crate::api::relays::RelayStatus::Terminated => 5,
+                crate::api::relays::RelayStatus::Banned => 6,
+                crate::api::relays::RelayStatus::Sleeping => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::relays::RelayWarning {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::relays::RelayWarning::AuthRequired => 0,
+                crate::api::relays::RelayWarning::PaymentRequired => 1,
+                crate::api::relays::RelayWarning::RestrictedWrites => 2,
+                crate::api::relays::RelayWarning::MessageLengthTooSmall => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -5148,6 +9264,68 @@ impl SseEncode for crate::api::messages::SerializableToken {
     }
 }
 
+impl SseEncode for crate::api::users::SyncPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::users::SyncPolicy::CacheOnly => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::users::SyncPolicy::StaleWhileRevalidate { max_age_secs } => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(max_age_secs, serializer);
+            }
+            crate::api::users::SyncPolicy::ForceRefresh => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::users::SyncPolicy::RefreshWithTimeout { timeout_ms } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(timeout_ms, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::messages::SystemEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::messages::SystemEvent::DisappearingMessagesChanged {
+                changed_by,
+                duration_secs,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(changed_by, serializer);
+                <Option<u64>>::sse_encode(duration_secs, serializer);
+            }
+            crate::api::messages::SystemEvent::MembersRemoved {
+                removed_by,
+                members,
+                reason,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(removed_by, serializer);
+                <Vec<String>>::sse_encode(members, serializer);
+                <Option<String>>::sse_encode(reason, serializer);
+            }
+            crate::api::messages::SystemEvent::PermissionsChanged {
+                changed_by,
+                permissions,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(changed_by, serializer);
+                <crate::api::groups::GroupPermissions>::sse_encode(permissions, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5223,6 +9401,10 @@ impl SseEncode for crate::api::users::User {
         <crate::api::metadata::FlutterMetadata>::sse_encode(self.metadata, serializer);
         <chrono::DateTime<chrono::Utc>>::sse_encode(self.created_at, serializer);
         <chrono::DateTime<chrono::Utc>>::sse_encode(self.updated_at, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.last_fetched_at, serializer);
+        <Vec<String>>::sse_encode(self.fetched_from_relays, serializer);
+        <crate::api::users::Nip05Status>::sse_encode(self.nip05_status, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.nip05_checked_at, serializer);
     }
 }
 
@@ -5235,6 +9417,35 @@ impl SseEncode for crate::api::messages::UserReaction {
     }
 }
 
+impl SseEncode for crate::api::users::UserSearchMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::users::UserSearchMatch::Pubkey => 0,
+                crate::api::users::UserSearchMatch::Nip05 => 1,
+                crate::api::users::UserSearchMatch::Prefix => 2,
+                crate::api::users::UserSearchMatch::Substring => 3,
+                crate::api::users::UserSearchMatch::Fuzzy => 4,
+                crate::api::users::UserSearchMatch::Relay => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::users::UserSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::users::User>::sse_encode(self.user, serializer);
+        <crate::api::users::UserSearchMatch>::sse_encode(self.matched_by, serializer);
+        <bool>::sse_encode(self.is_follow, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5245,6 +9456,23 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::verification::VerificationState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::verification::VerificationState::Unverified => 0,
+                crate::api::verification::VerificationState::Verified => 1,
+                crate::api::verification::VerificationState::KeyChanged => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::welcomes::Welcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5262,6 +9490,53 @@ impl SseEncode for crate::api::welcomes::Welcome {
     }
 }
 
+impl SseEncode for crate::api::welcomes::WelcomeActionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.welcome_event_id, serializer);
+        <bool>::sse_encode(self.success, serializer);
+        <Option<String>>::sse_encode(self.error_message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::welcomes::WelcomeSenderPolicy>::sse_encode(self.sender_policy, serializer);
+        <Option<u32>>::sse_encode(self.max_member_count, serializer);
+        <bool>::sse_encode(self.auto_accept_from_follows, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::welcomes::Welcome>::sse_encode(self.welcome, serializer);
+        <crate::api::metadata::FlutterMetadata>::sse_encode(self.welcomer_metadata, serializer);
+        <bool>::sse_encode(self.is_following_welcomer, serializer);
+        <Option<String>>::sse_encode(self.group_image_path, serializer);
+        <Vec<String>>::sse_encode(self.member_pubkeys, serializer);
+        <crate::api::groups::GroupType>::sse_encode(self.group_type, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomeSenderPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::welcomes::WelcomeSenderPolicy::Anyone => 0,
+                crate::api::welcomes::WelcomeSenderPolicy::Follows => 1,
+                crate::api::welcomes::WelcomeSenderPolicy::FollowsOfFollows => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::welcomes::WelcomeState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5285,6 +9560,7 @@ impl SseEncode for crate::api::WhitenoiseConfig {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.data_dir, serializer);
         <String>::sse_encode(self.logs_dir, serializer);
+        <Option<String>>::sse_encode(self.proxy_url, serializer);
     }
 }
 
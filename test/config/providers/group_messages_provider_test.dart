@@ -5,6 +5,7 @@ import 'package:whitenoise/config/providers/group_messages_provider.dart';
 import 'package:whitenoise/config/providers/user_profile_provider.dart';
 import 'package:whitenoise/domain/models/user_profile.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy;
 import 'package:whitenoise/utils/localization_extensions.dart';
 import 'package:whitenoise/utils/pubkey_formatter.dart';
 
@@ -17,7 +18,10 @@ class MockUserProfileNotifier extends UserProfileNotifier {
     : super(wnApiGetUserFn: null, getUserProfileFromMetadataFn: null);
 
   @override
-  Future<UserProfile> getUserProfile(String pubkey, {bool blockingDataSync = true}) async {
+  Future<UserProfile> getUserProfile(
+    String pubkey, {
+    SyncPolicy? syncPolicy,
+  }) async {
     return _userProfiles[pubkey] ??
         UserProfile(
           publicKey: pubkey,
@@ -29,6 +29,10 @@ void main() {
       lastMessageAt: DateTime.fromMillisecondsSinceEpoch(1234567890000),
       epoch: BigInt.from(1),
       state: GroupState.active,
+      permissions: const GroupPermissions(
+        adminsOnlyPost: false,
+        adminsOnlyAddMembers: false,
+      ),
     );
 
     final testGroup2 = Group(
@@ -39,6 +43,10 @@ void main() {
       adminPubkeys: ['test_pubkey_123'],
       epoch: BigInt.from(1),
       state: GroupState.active,
+      permissions: const GroupPermissions(
+        adminsOnlyPost: false,
+        adminsOnlyAddMembers: false,
+      ),
     );
 
     final testGroup3 = Group(
@@ -49,6 +57,10 @@ void main() {
       adminPubkeys: ['other_admin_123'],
       epoch: BigInt.from(1),
       state: GroupState.inactive,
+      permissions: const GroupPermissions(
+        adminsOnlyPost: false,
+        adminsOnlyAddMembers: false,
+      ),
     );
 
     final testGroups = [testGroup1, testGroup2, testGroup3];
@@ -525,6 +537,10 @@ void main() {
           adminPubkeys: ['test_pubkey_123'],
           epoch: BigInt.from(1),
           state: GroupState.active,
+          permissions: const GroupPermissions(
+            adminsOnlyPost: false,
+            adminsOnlyAddMembers: false,
+          ),
         );
 
         final updatedGroups = [...testGroups, newGroup];
@@ -577,6 +593,10 @@ void main() {
               adminPubkeys: ['test_active_pubkey_123'],
               epoch: BigInt.from(1234567),
               state: GroupState.active,
+              permissions: const GroupPermissions(
+                adminsOnlyPost: false,
+                adminsOnlyAddMembers: false,
+              ),
             );
           }
 
@@ -592,6 +612,10 @@ void main() {
                   adminPubkeys: ['test_active_pubkey_123'],
                   epoch: BigInt.from(1),
                   state: GroupState.active,
+                  permissions: const GroupPermissions(
+                    adminsOnlyPost: false,
+                    adminsOnlyAddMembers: false,
+                  ),
                 ),
               ];
             }
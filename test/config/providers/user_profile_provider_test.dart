@@ -23,6 +23,7 @@ UserProfile mockUserProfileFromMetadata({
 class MockWnUsersApi {
   final Map<String, User> _users = {};
   Exception? _throwError;
+  SyncPolicy? lastSyncPolicy;
 
   void addUser(String hexPubkey, User user) {
     _users[hexPubkey] = user;
@@ -36,7 +37,8 @@ class MockWnUsersApi {
     _throwError = null;
   }
 
-  Future<User> getUser({required String pubkey, required bool blockingDataSync}) async {
+  Future<User> getUser({required String pubkey, required SyncPolicy syncPolicy}) async {
+    lastSyncPolicy = syncPolicy;
     if (_throwError != null) {
       throw _throwError!;
     }
@@ -70,6 +72,8 @@ void main() {
       metadata: testMetadata,
       createdAt: DateTime.fromMillisecondsSinceEpoch(1234567890000),
       updatedAt: DateTime.fromMillisecondsSinceEpoch(1234567891000),
+      fetchedFromRelays: const [],
+      nip05Status: Nip05Status.unverified,
     );
 
     ProviderContainer createContainer() {
@@ -123,6 +127,23 @@ void main() {
           });
         });
 
+        test('refreshes in the background by default', () async {
+          final notifier = container.read(userProfileProvider.notifier);
+          await notifier.getUserProfile(testNpubPubkey);
+
+          expect(mockWnUsersApi.lastSyncPolicy, isA<SyncPolicy_StaleWhileRevalidate>());
+        });
+
+        test('passes an explicit sync policy through', () async {
+          final notifier = container.read(userProfileProvider.notifier);
+          await notifier.getUserProfile(
+            testNpubPubkey,
+            syncPolicy: const SyncPolicy.forceRefresh(),
+          );
+
+          expect(mockWnUsersApi.lastSyncPolicy, isA<SyncPolicy_ForceRefresh>());
+        });
+
         group('with hex pubkey', () {
           test('returns expected user profile data', () async {
             final notifier = container.read(userProfileProvider.notifier);
@@ -151,6 +172,8 @@ void main() {
             metadata: metadataWithoutDisplayName,
             createdAt: DateTime.fromMillisecondsSinceEpoch(1234567890000),
             updatedAt: DateTime.fromMillisecondsSinceEpoch(1234567891000),
+            fetchedFromRelays: const [],
+            nip05Status: Nip05Status.unverified,
           );
           mockWnUsersApi.addUser(testHexPubkey, userWithoutName);
         });
@@ -6,6 +6,7 @@ import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/media_files.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy;
 import 'package:whitenoise/src/rust/api/welcomes.dart';
 
 import '../../test_helpers.dart';
@@ -716,6 +717,10 @@ void main() {
           adminPubkeys: [],
           epoch: BigInt.from(1),
           state: GroupState.active,
+          permissions: const GroupPermissions(
+            adminsOnlyPost: false,
+            adminsOnlyAddMembers: false,
+          ),
         );
       }
 
@@ -728,7 +733,7 @@ void main() {
 
       Future<FlutterMetadata> mockGetUserMetadata({
         required String pubkey,
-        bool blockingDataSync = false,
+        SyncPolicy? syncPolicy,
       }) async {
         // Return different names based on pubkey
         if (pubkey == 'other-user') {
@@ -1097,6 +1102,10 @@ void main() {
             adminPubkeys: [],
             epoch: BigInt.from(1),
             state: GroupState.active,
+            permissions: const GroupPermissions(
+              adminsOnlyPost: false,
+              adminsOnlyAddMembers: false,
+            ),
           );
         }
 
@@ -1109,7 +1118,7 @@ void main() {
 
         Future<FlutterMetadata> mockGetGroupUserMetadata({
           required String pubkey,
-          bool blockingDataSync = false,
+          SyncPolicy? syncPolicy,
         }) async {
           // Return different names based on pubkey
           if (pubkey == 'other-user') {
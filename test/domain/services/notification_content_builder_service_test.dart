@@ -4,6 +4,7 @@ import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/media_files.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/src/rust/api/users.dart' show SyncPolicy;
 import 'package:whitenoise/src/rust/api/welcomes.dart';
 
 import '../../test_helpers.dart';
@@ -46,6 +47,10 @@ Future<Group> _mockGetGroup({
     adminPubkeys: [],
     epoch: BigInt.from(1),
     state: GroupState.active,
+    permissions: const GroupPermissions(
+      adminsOnlyPost: false,
+      adminsOnlyAddMembers: false,
+    ),
   );
 }
 
@@ -58,7 +63,7 @@ Future<List<String>> _mockGetGroupMembers({
 
 Future<FlutterMetadata> _mockGetUserMetadata({
   required String pubkey,
-  bool blockingDataSync = false,
+  SyncPolicy? syncPolicy,
 }) async {
   final Map<String, String> names = {
     'other-member-pubkey': 'Alice',
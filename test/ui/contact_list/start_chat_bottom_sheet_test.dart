@@ -239,6 +239,8 @@ void main() {
                       metadata: const FlutterMetadata(custom: {}),
                       createdAt: DateTime.now(),
                       updatedAt: DateTime.now(),
+                      fetchedFromRelays: const [],
+                      nip05Status: Nip05Status.unverified,
                     ),
                   ]),
                 ),
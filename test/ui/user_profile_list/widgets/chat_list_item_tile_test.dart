@@ -106,6 +106,10 @@ void main() {
         lastMessageAt: DateTime(2025, 1, 2),
         epoch: BigInt.zero,
         state: GroupState.active,
+        permissions: const GroupPermissions(
+          adminsOnlyPost: false,
+          adminsOnlyAddMembers: false,
+        ),
       );
 
       baseOverrides = [